@@ -10,5 +10,19 @@ fn main() {
         panic!("i128 support was not detected!");
     }
 
+    if ac.probe_path("std::convert::TryFrom") {
+        println!("cargo:rustc-cfg=has_try_from");
+    }
+
+    // `Vec::new` is a `const fn` as of Rust 1.39.
+    if ac.probe_rustc_version(1, 39) {
+        println!("cargo:rustc-cfg=has_const_vec_new");
+    }
+
+    // Const generics are stable as of Rust 1.51.
+    if ac.probe_rustc_version(1, 51) {
+        println!("cargo:rustc-cfg=has_const_generics");
+    }
+
     autocfg::rerun_path(file!());
 }
@@ -4,7 +4,7 @@ set -ex
 
 echo Testing num-bigint on rustc ${TRAVIS_RUST_VERSION}
 
-FEATURES="serde"
+FEATURES="serde base-encodings"
 if [[ "$TRAVIS_RUST_VERSION" =~ ^(nightly|beta|stable|1.31.0|1.26.0|1.22.0)$ ]]; then
   FEATURES="$FEATURES rand"
 fi
@@ -14,6 +14,9 @@ fi
 if [[ "$TRAVIS_RUST_VERSION" =~ ^(nightly|beta|stable|1.31.0)$ ]]; then
   FEATURES="$FEATURES quickcheck quickcheck_macros"
 fi
+if [[ "$TRAVIS_RUST_VERSION" =~ ^(nightly|beta|stable)$ ]]; then
+  FEATURES="$FEATURES rkyv diesel sqlx serde_json pyo3 ibig rug"
+fi
 
 # num-bigint should build and test everywhere.
 cargo build --verbose
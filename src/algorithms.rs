@@ -6,7 +6,7 @@ use std::mem;
 use traits;
 use traits::{One, Zero};
 
-use biguint::BigUint;
+use biguint::{trailing_zeros, BigUint};
 
 use bigint::BigInt;
 use bigint::Sign;
@@ -28,7 +28,7 @@ fn adc(a: BigDigit, b: BigDigit, acc: &mut DoubleBigDigit) -> BigDigit {
 
 // Subtract with borrow:
 #[inline]
-fn sbb(a: BigDigit, b: BigDigit, acc: &mut SignedDoubleBigDigit) -> BigDigit {
+pub fn sbb(a: BigDigit, b: BigDigit, acc: &mut SignedDoubleBigDigit) -> BigDigit {
     *acc += SignedDoubleBigDigit::from(a);
     *acc -= SignedDoubleBigDigit::from(b);
     let lo = *acc as BigDigit;
@@ -69,6 +69,11 @@ fn div_wide(hi: BigDigit, lo: BigDigit, divisor: BigDigit) -> (BigDigit, BigDigi
 }
 
 pub fn div_rem_digit(mut a: BigUint, b: BigDigit) -> (BigUint, BigDigit) {
+    if b.is_power_of_two() {
+        let rem = a.data.first().map_or(0, |&d| d & (b - 1));
+        return (a >> b.trailing_zeros() as usize, rem);
+    }
+
     let mut rem = 0;
 
     for d in a.data.iter_mut().rev() {
@@ -81,6 +86,10 @@ pub fn div_rem_digit(mut a: BigUint, b: BigDigit) -> (BigUint, BigDigit) {
 }
 
 pub fn rem_digit(a: &BigUint, b: BigDigit) -> BigDigit {
+    if b.is_power_of_two() {
+        return a.data.first().map_or(0, |&d| d & (b - 1));
+    }
+
     let mut rem: DoubleBigDigit = 0;
     for &digit in a.data.iter().rev() {
         rem = (rem << big_digit::BITS) + DoubleBigDigit::from(digit);
@@ -494,6 +503,20 @@ pub fn mul3(x: &[BigDigit], y: &[BigDigit]) -> BigUint {
     prod.normalized()
 }
 
+/// Fused multiply-add: returns `acc + x * y`, accumulating the product directly into the
+/// storage of `acc` instead of building it separately.
+pub fn mul_add3(x: &[BigDigit], y: &[BigDigit], mut acc: BigUint) -> BigUint {
+    if x.is_empty() || y.is_empty() {
+        return acc;
+    }
+
+    let len = cmp::max(acc.data.len(), x.len() + y.len()) + 1;
+    acc.data.resize(len, 0);
+
+    mac3(&mut acc.data[..], x, y);
+    acc.normalized()
+}
+
 pub fn scalar_mul(a: &mut [BigDigit], b: BigDigit) -> BigDigit {
     let mut carry = 0;
     for a in a.iter_mut() {
@@ -502,6 +525,23 @@ pub fn scalar_mul(a: &mut [BigDigit], b: BigDigit) -> BigDigit {
     carry as BigDigit
 }
 
+/// Returns `k` if `d` is `2^k`.
+fn power_of_two_shift(d: &BigUint) -> Option<usize> {
+    d.data.split_last().and_then(|(hi, lo)| {
+        if hi.is_power_of_two() && lo.iter().all(Zero::is_zero) {
+            Some(lo.len() * big_digit::BITS + hi.trailing_zeros() as usize)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the low `shift` bits of `u`, copying only the digits that hold them.
+fn low_bits(u: &BigUint, shift: usize) -> BigUint {
+    let len = cmp::min(shift / big_digit::BITS + 1, u.data.len());
+    BigUint::new(u.data[..len].to_vec()).into_low_bits(shift as u64)
+}
+
 pub fn div_rem(mut u: BigUint, mut d: BigUint) -> (BigUint, BigUint) {
     if d.is_zero() {
         panic!()
@@ -509,6 +549,11 @@ pub fn div_rem(mut u: BigUint, mut d: BigUint) -> (BigUint, BigUint) {
     if u.is_zero() {
         return (Zero::zero(), Zero::zero());
     }
+    if let Some(shift) = power_of_two_shift(&d) {
+        let q = &u >> shift;
+        u.keep_low_bits(shift as u64);
+        return (q, u);
+    }
 
     if d.data.len() == 1 {
         if d.data == [1] {
@@ -555,6 +600,9 @@ pub fn div_rem_ref(u: &BigUint, d: &BigUint) -> (BigUint, BigUint) {
     if u.is_zero() {
         return (Zero::zero(), Zero::zero());
     }
+    if let Some(shift) = power_of_two_shift(d) {
+        return (u >> shift, low_bits(u, shift));
+    }
 
     if d.data.len() == 1 {
         if d.data == [1] {
@@ -590,6 +638,103 @@ pub fn div_rem_ref(u: &BigUint, d: &BigUint) -> (BigUint, BigUint) {
     (q, r >> shift)
 }
 
+/// Divides `u` by `d` in place, leaving the quotient in `u` and returning the remainder.
+///
+/// Unlike `div_rem_ref`, the dividend is not cloned: its buffer is reused for the quotient when
+/// dividing by a single digit, or for the remainder otherwise.
+pub fn div_rem_assign(u: &mut BigUint, d: &BigUint) -> BigUint {
+    if d.is_zero() {
+        panic!()
+    }
+    if u.is_zero() {
+        return Zero::zero();
+    }
+    if let Some(shift) = power_of_two_shift(d) {
+        let r = low_bits(u, shift);
+        *u >>= shift;
+        return r;
+    }
+
+    if d.data.len() == 1 {
+        if d.data == [1] {
+            return Zero::zero();
+        }
+
+        let (div, rem) = div_rem_digit(mem::replace(u, Zero::zero()), d.data[0]);
+        *u = div;
+        return rem.into();
+    }
+
+    // Required or the q_len calculation below can underflow:
+    match (*u).cmp(d) {
+        Less => return mem::replace(u, Zero::zero()),
+        Equal => {
+            u.set_one();
+            return Zero::zero();
+        }
+        Greater => {} // Do nothing
+    }
+
+    // See `div_rem` for the normalization performed here.  `u` is shifted in place, into
+    // one more digit that also leaves room for the quotient to replace it.
+    let shift = d.data.last().unwrap().leading_zeros() as usize;
+    u.data.push(0);
+    let r = if shift == 0 {
+        div_rem_core_in_place(&mut u.data, &d.data)
+    } else {
+        let mut carry = 0;
+        for elem in u.data.iter_mut() {
+            let new_carry = *elem >> (big_digit::BITS - shift);
+            *elem = (*elem << shift) | carry;
+            carry = new_carry;
+        }
+        div_rem_core_in_place(&mut u.data, &(d << shift).data)
+    };
+    u.normalize();
+    // renormalize the remainder
+    r >> shift
+}
+
+/// Returns `true` if `d` divides `u`, without computing the quotient.
+///
+/// Only zero is a multiple of zero, matching `Integer::is_multiple_of` for primitives.
+pub fn is_multiple_of(u: &BigUint, d: &BigUint) -> bool {
+    if d.is_zero() {
+        return u.is_zero();
+    }
+    let u_zeros = match trailing_zeros(u) {
+        Some(zeros) => zeros,
+        None => return true,
+    };
+
+    // every factor of two in `d` must also be in `u`, which settles powers of two outright
+    if let Some(shift) = power_of_two_shift(d) {
+        return u_zeros >= shift;
+    }
+    if u_zeros < trailing_zeros(d).unwrap() {
+        return false;
+    }
+
+    match u.cmp(d) {
+        Less => return false,
+        Equal => return true,
+        Greater => {}
+    }
+
+    if d.data.len() == 1 {
+        return rem_digit(u, d.data[0]) == 0;
+    }
+
+    // See `div_rem` for the normalization performed here.
+    let shift = d.data.last().unwrap().leading_zeros() as usize;
+    let r = if shift == 0 {
+        rem_core(u.clone(), d)
+    } else {
+        rem_core(u << shift, &(d << shift))
+    };
+    r.is_zero()
+}
+
 /// an implementation of Knuth, TAOCP vol 2 section 4.3, algorithm D
 ///
 /// # Correctness
@@ -599,7 +744,76 @@ pub fn div_rem_ref(u: &BigUint, d: &BigUint) -> (BigUint, BigUint) {
 /// - `a > b`
 /// - `d.data.len() > 1`
 /// - `d.data.last().unwrap().leading_zeros() == 0`
-fn div_rem_core(mut a: BigUint, b: &BigUint) -> (BigUint, BigUint) {
+fn div_rem_core(a: BigUint, b: &BigUint) -> (BigUint, BigUint) {
+    let q_len = a.data.len() - b.data.len() + 1;
+    let mut q = BigUint {
+        data: vec![0; q_len],
+    };
+
+    let r = reduce_core(a, b, Some(&mut q.data[..]));
+    (q.normalized(), r)
+}
+
+/// `div_rem_core` reusing the storage of the dividend `a` for the quotient, under the same
+/// conditions on `a` and `b`.
+///
+/// With `q_len = a.len() - b.len()`, `a` must be less than `b * B^q_len`, where `B` is the
+/// digit base: its top `b.len() + 1` digits are below `b` shifted up by one digit, so the
+/// quotient has only `q_len` digits. This holds for a dividend that had a zero digit
+/// pushed on top before the normalizing shift, as the carry the shift leaves in that digit
+/// is less than the top digit of the normalized `b`.
+///
+/// Each step of the reduction clears the top digit of what is left of the remainder, and
+/// the quotient digit it finds takes that place, so `a` ends up with the remainder in its
+/// low `b.len()` digits and the quotient above it. The remainder is returned and the
+/// quotient is moved down to the start of `a`, which may be left unnormalized.
+fn div_rem_core_in_place(a: &mut Vec<BigDigit>, b: &[BigDigit]) -> BigUint {
+    let b_len = b.len();
+    let bn = b[b_len - 1];
+    let q_len = a.len() - b_len;
+
+    // the product of `b` and each quotient digit, reused to avoid hitting the allocator
+    let mut prod = vec![0; b_len + 1];
+
+    for j in (0..q_len).rev() {
+        // What is left of the remainder is below `b << (j + 1)` digits, so its top digit is
+        // at most `bn`. With `b` normalized, this guess from the top two digits is at most
+        // two more than the quotient digit (Knuth, TAOCP vol 2 section 4.3.1, theorem B).
+        let (hi, lo) = (a[j + b_len], a[j + b_len - 1]);
+        let mut q0 = if hi < bn {
+            div_wide(hi, lo, bn).0
+        } else {
+            BigDigit::max_value()
+        };
+
+        for p in prod.iter_mut() {
+            *p = 0;
+        }
+        mac_digit(&mut prod, b, q0);
+        // both windows are `b_len + 1` digits, so they compare digit by digit from the top
+        while prod.iter().rev().cmp(a[j..j + b_len + 1].iter().rev()) == Greater {
+            q0 -= 1;
+            sub2(&mut prod, b);
+        }
+
+        sub2(&mut a[j..j + b_len + 1], &prod);
+        debug_assert!(a[j + b_len] == 0);
+        a[j + b_len] = q0;
+    }
+
+    let r = BigUint::new(a[..b_len].to_vec());
+    a.drain(..b_len);
+    r
+}
+
+/// The remainder of `div_rem_core`, without allocating or accumulating the quotient.
+fn rem_core(a: BigUint, b: &BigUint) -> BigUint {
+    reduce_core(a, b, None)
+}
+
+/// The reduction loop shared by `div_rem_core` and `rem_core`, returning the remainder and
+/// adding the quotient into `q` when one is given.
+fn reduce_core(mut a: BigUint, b: &BigUint, mut q: Option<&mut [BigDigit]>) -> BigUint {
     // The algorithm works by incrementally calculating "guesses", q0, for part of the
     // remainder. Once we have any number q0 such that q0 * b <= a, we can set
     //
@@ -616,9 +830,6 @@ fn div_rem_core(mut a: BigUint, b: &BigUint) -> (BigUint, BigUint) {
 
     let bn = *b.data.last().unwrap();
     let q_len = a.data.len() - b.data.len() + 1;
-    let mut q = BigUint {
-        data: vec![0; q_len],
-    };
 
     // We reuse the same temporary to avoid hitting the allocator in our inner loop - this is
     // sized to hold a0 (in the common case; if a particular digit of the quotient is zero a0
@@ -660,7 +871,9 @@ fn div_rem_core(mut a: BigUint, b: &BigUint) -> (BigUint, BigUint) {
             prod = prod - b;
         }
 
-        add2(&mut q.data[j..], &q0.data[..]);
+        if let Some(ref mut q) = q {
+            add2(&mut q[j..], &q0.data[..]);
+        }
         sub2(&mut a.data[j..], &prod.data[..]);
         a.normalize();
 
@@ -669,7 +882,7 @@ fn div_rem_core(mut a: BigUint, b: &BigUint) -> (BigUint, BigUint) {
 
     debug_assert!(&a < b);
 
-    (q.normalized(), a)
+    a
 }
 
 /// Find last set bit
@@ -684,6 +897,10 @@ pub fn ilog2<T: traits::PrimInt>(v: T) -> usize {
 
 #[inline]
 pub fn biguint_shl(n: Cow<BigUint>, bits: usize) -> BigUint {
+    // zero stays zero, without allocating for the shifted-in digits
+    if n.data.is_empty() {
+        return n.into_owned();
+    }
     let n_unit = bits / big_digit::BITS;
     let mut data = match n_unit {
         0 => n.into_owned().data,
@@ -739,6 +956,49 @@ pub fn biguint_shr(n: Cow<BigUint>, bits: usize) -> BigUint {
     BigUint::new(data)
 }
 
+/// Computes `n = (n << bits) + b`, or `n = (n << bits) | b` if `or` is set, shifting the limbs
+/// of `n` in place rather than through a temporary.
+pub fn biguint_shl_combine(n: &mut BigUint, bits: usize, b: &[BigDigit], or: bool) {
+    let n_unit = bits / big_digit::BITS;
+    let n_bits = bits % big_digit::BITS;
+
+    let old_len = n.data.len();
+    if old_len > 0 && bits > 0 {
+        n.data.resize(old_len + n_unit + 1, 0);
+        let data = &mut n.data[..];
+        if n_bits > 0 {
+            let rev_bits = big_digit::BITS - n_bits;
+            data[old_len + n_unit] = data[old_len - 1] >> rev_bits;
+            for i in (1..old_len).rev() {
+                data[i + n_unit] = (data[i] << n_bits) | (data[i - 1] >> rev_bits);
+            }
+            data[n_unit] = data[0] << n_bits;
+        } else {
+            for i in (0..old_len).rev() {
+                data[i + n_unit] = data[i];
+            }
+        }
+        for elem in data[..n_unit].iter_mut() {
+            *elem = 0;
+        }
+    }
+
+    if n.data.len() < b.len() {
+        n.data.resize(b.len(), 0);
+    }
+    if or {
+        for (ai, bi) in n.data.iter_mut().zip(b) {
+            *ai |= *bi;
+        }
+    } else {
+        let carry = __add2(&mut n.data, b);
+        if carry != 0 {
+            n.data.push(carry);
+        }
+    }
+    n.normalize();
+}
+
 pub fn cmp_slice(a: &[BigDigit], b: &[BigDigit]) -> Ordering {
     debug_assert!(a.last() != Some(&0));
     debug_assert!(b.last() != Some(&0));
@@ -786,4 +1046,27 @@ mod algorithm_tests {
         assert_eq!(sub_sign_i(&a.data[..], &b.data[..]), &a_i - &b_i);
         assert_eq!(sub_sign_i(&b.data[..], &a.data[..]), &b_i - &a_i);
     }
+
+    #[test]
+    fn test_div_rem_assign_in_place() {
+        use super::div_rem_assign;
+
+        let u = BigUint::from_str_radix("265252859812191058636308480000000123456789", 10).unwrap();
+        // the first divisor needs normalizing and the second does not
+        let ds = [
+            BigUint::from_str_radix("26525285981219105863630848000000", 10).unwrap(),
+            BigUint::from_str_radix("80000000000000000000000c", 16).unwrap(),
+        ];
+        for d in &ds {
+            let mut q = u.clone();
+            // the room the quotient needs while the remainder is cleared from under it
+            q.data.reserve(1);
+            let (ptr, capacity) = (q.data.as_ptr(), q.data.capacity());
+
+            let r = div_rem_assign(&mut q, d);
+            assert_eq!((&q, &r), (&(&u / d), &(&u % d)));
+            assert_eq!(q.data.as_ptr(), ptr);
+            assert_eq!(q.data.capacity(), capacity);
+        }
+    }
 }
@@ -0,0 +1,366 @@
+//! Zero-copy serialization with `rkyv`.
+//!
+//! The archived forms store the magnitude as little-endian `u32` digits, like the serde
+//! format, so they do not depend on the internal digit size or on the target's byte order.
+//! Validation rejects archives whose digits are not normalized or whose sign is inconsistent
+//! with the magnitude, so a validated archive can be compared and converted directly.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::slice;
+
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use {BigInt, BigUint, Sign};
+
+/// An archived `BigUint`.
+///
+/// This can be compared with other archived values and with `BigUint` (as `archived == value`,
+/// with the archived side on the left), or converted with
+/// [`to_biguint`](#method.to_biguint), without deserializing the whole archive.
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = ::rkyv::bytecheck, verify)]
+#[repr(transparent)]
+pub struct ArchivedBigUint {
+    digits: ArchivedVec<Archived<u32>>,
+}
+
+/// An archived `BigInt`.
+///
+/// This can be compared with other archived values and with `BigInt` (as `archived == value`,
+/// with the archived side on the left), or converted with
+/// [`to_bigint`](#method.to_bigint), without deserializing the whole archive.
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = ::rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedBigInt {
+    sign: Archived<i8>,
+    data: ArchivedBigUint,
+}
+
+#[derive(Debug)]
+struct InvalidArchive(&'static str);
+
+impl fmt::Display for InvalidArchive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for InvalidArchive {
+    fn description(&self) -> &str {
+        self.0
+    }
+}
+
+unsafe impl<C> Verify<C> for ArchivedBigUint
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        match self.digits.as_slice().last() {
+            Some(d) if d.to_native() == 0 => Err(Source::new(InvalidArchive(
+                "archived BigUint has trailing zero digits",
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+unsafe impl<C> Verify<C> for ArchivedBigInt
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        let valid = match self.sign {
+            -1 | 1 => !self.data.is_zero(),
+            0 => self.data.is_zero(),
+            _ => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Source::new(InvalidArchive(
+                "archived BigInt has an invalid sign",
+            )))
+        }
+    }
+}
+
+/// An iterator of the `u32` digits of an `ArchivedBigUint`, least significant first.
+#[derive(Clone, Debug)]
+pub struct ArchivedU32Digits<'a> {
+    it: slice::Iter<'a, Archived<u32>>,
+}
+
+impl<'a> Iterator for ArchivedU32Digits<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        self.it.next().map(|d| d.to_native())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ArchivedU32Digits<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u32> {
+        self.it.next_back().map(|d| d.to_native())
+    }
+}
+
+impl<'a> ExactSizeIterator for ArchivedU32Digits<'a> {}
+
+impl ArchivedBigUint {
+    /// Returns the `u32` digits of the archived value, least significant first.
+    #[inline]
+    pub fn digits(&self) -> &[Archived<u32>] {
+        self.digits.as_slice()
+    }
+
+    /// Returns an iterator of the `u32` digits of the archived value, least significant first.
+    #[inline]
+    pub fn iter_u32_digits<'a>(&'a self) -> ArchivedU32Digits<'a> {
+        ArchivedU32Digits {
+            it: self.digits().iter(),
+        }
+    }
+
+    /// Returns `true` if the archived value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Determines the fewest bits necessary to express the archived value.
+    pub fn bits(&self) -> usize {
+        match self.digits().last() {
+            Some(d) => self.digits.len() * 32 - d.to_native().leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Converts the archived value to a `BigUint`.
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::new(self.iter_u32_digits().collect())
+    }
+}
+
+impl ArchivedBigInt {
+    /// Returns the sign of the archived value.
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        match self.sign {
+            -1 => Sign::Minus,
+            0 => Sign::NoSign,
+            _ => Sign::Plus,
+        }
+    }
+
+    /// Returns the archived magnitude.
+    #[inline]
+    pub fn magnitude(&self) -> &ArchivedBigUint {
+        &self.data
+    }
+
+    /// Converts the archived value to a `BigInt`.
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from_biguint(self.sign(), self.data.to_biguint())
+    }
+}
+
+fn cmp_digits<A, B>(a: A, b: B) -> Ordering
+where
+    A: DoubleEndedIterator<Item = u32> + ExactSizeIterator,
+    B: DoubleEndedIterator<Item = u32> + ExactSizeIterator,
+{
+    a.len().cmp(&b.len()).then_with(|| a.rev().cmp(b.rev()))
+}
+
+impl PartialEq for ArchivedBigUint {
+    #[inline]
+    fn eq(&self, other: &ArchivedBigUint) -> bool {
+        self.digits() == other.digits()
+    }
+}
+
+impl Eq for ArchivedBigUint {}
+
+impl PartialOrd for ArchivedBigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &ArchivedBigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ArchivedBigUint {
+    fn cmp(&self, other: &ArchivedBigUint) -> Ordering {
+        cmp_digits(self.iter_u32_digits(), other.iter_u32_digits())
+    }
+}
+
+impl PartialEq<BigUint> for ArchivedBigUint {
+    #[inline]
+    fn eq(&self, other: &BigUint) -> bool {
+        cmp_digits(self.iter_u32_digits(), other.iter_u32_digits()) == Ordering::Equal
+    }
+}
+
+impl PartialOrd<BigUint> for ArchivedBigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(cmp_digits(self.iter_u32_digits(), other.iter_u32_digits()))
+    }
+}
+
+impl fmt::Debug for ArchivedBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArchivedBigUint")
+            .field("digits", &self.digits())
+            .finish()
+    }
+}
+
+impl fmt::Display for ArchivedBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
+
+fn cmp_signed(sign: Sign, a: Ordering) -> Ordering {
+    match sign {
+        Sign::Minus => a.reverse(),
+        _ => a,
+    }
+}
+
+impl PartialEq for ArchivedBigInt {
+    #[inline]
+    fn eq(&self, other: &ArchivedBigInt) -> bool {
+        self.sign == other.sign && self.data == other.data
+    }
+}
+
+impl Eq for ArchivedBigInt {}
+
+impl PartialOrd for ArchivedBigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &ArchivedBigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ArchivedBigInt {
+    fn cmp(&self, other: &ArchivedBigInt) -> Ordering {
+        self.sign
+            .cmp(&other.sign)
+            .then_with(|| cmp_signed(self.sign(), self.data.cmp(&other.data)))
+    }
+}
+
+impl PartialEq<BigInt> for ArchivedBigInt {
+    #[inline]
+    fn eq(&self, other: &BigInt) -> bool {
+        self.sign() == other.sign()
+            && cmp_digits(self.data.iter_u32_digits(), other.iter_u32_digits()) == Ordering::Equal
+    }
+}
+
+impl PartialOrd<BigInt> for ArchivedBigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        let ord = self.sign().cmp(&other.sign()).then_with(|| {
+            let mag = cmp_digits(self.data.iter_u32_digits(), other.iter_u32_digits());
+            cmp_signed(self.sign(), mag)
+        });
+        Some(ord)
+    }
+}
+
+impl fmt::Debug for ArchivedBigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArchivedBigInt")
+            .field("sign", &self.sign())
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl fmt::Display for ArchivedBigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_bigint(), f)
+    }
+}
+
+impl Archive for BigUint {
+    type Archived = ArchivedBigUint;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: VecResolver, out: Place<ArchivedBigUint>) {
+        rkyv::munge::munge!(let ArchivedBigUint { digits } = out);
+        // `BigDigit` size dependent
+        ArchivedVec::resolve_from_slice(self.digits(), resolver, digits);
+    }
+}
+
+impl<S> Serialize<S> for BigUint
+where
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<VecResolver, S::Error> {
+        // `BigDigit` size dependent
+        ArchivedVec::<Archived<u32>>::serialize_from_slice(self.digits(), serializer)
+    }
+}
+
+impl<D> Deserialize<BigUint, D> for ArchivedBigUint
+where
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<BigUint, D::Error> {
+        Ok(self.to_biguint())
+    }
+}
+
+impl Archive for BigInt {
+    type Archived = ArchivedBigInt;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: VecResolver, out: Place<ArchivedBigInt>) {
+        rkyv::munge::munge!(let ArchivedBigInt { sign, data } = out);
+        self.sign().to_i8().resolve((), sign);
+        rkyv::munge::munge!(let ArchivedBigUint { digits } = data);
+        ArchivedVec::resolve_from_slice(self.digits(), resolver, digits);
+    }
+}
+
+impl<S> Serialize<S> for BigInt
+where
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<VecResolver, S::Error> {
+        ArchivedVec::<Archived<u32>>::serialize_from_slice(self.digits(), serializer)
+    }
+}
+
+impl<D> Deserialize<BigInt, D> for ArchivedBigInt
+where
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<BigInt, D::Error> {
+        Ok(self.to_bigint())
+    }
+}
@@ -1,6 +1,7 @@
-#[allow(deprecated, unused_imports)]
-use std::ascii::AsciiExt;
+use std::cmp;
 use std::cmp::Ordering::{self, Equal, Greater, Less};
+#[cfg(has_try_from)]
+use std::convert::TryFrom;
 use std::default::Default;
 use std::fmt;
 use std::iter::{Product, Sum};
@@ -19,17 +20,21 @@ use serde;
 
 use integer::{Integer, Roots};
 use traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, Pow, Signed,
-    ToPrimitive, Zero,
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    FromPrimitive, MulAdd, MulAddAssign, Num, One, Pow, Signed, ToPrimitive, Zero,
 };
 
 use self::Sign::{Minus, NoSign, Plus};
 
 use super::ParseBigIntError;
+#[cfg(has_try_from)]
+use super::TryFromBigIntError;
 use big_digit::{self, BigDigit, DoubleBigDigit};
 use biguint;
-use biguint::to_str_radix_reversed;
-use biguint::{BigUint, IntDigits};
+use biguint::{from_str_prefixed_unsigned, from_str_scientific_unsigned, SCIENTIFIC_MAX_BITS};
+use biguint::{remove_separators, restore_separators, shr_amount, to_str_radix_reversed};
+use biguint::{BigUint, IntDigits, U32Digits, U64Digits};
+use format::to_str_decimal;
 
 use IsizePromotion;
 use UsizePromotion;
@@ -72,6 +77,71 @@ impl Mul<Sign> for Sign {
     }
 }
 
+impl Sign {
+    /// Returns the sign of an `i8`: `Minus` if negative, `NoSign` if zero, or `Plus` if
+    /// positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::Sign;
+    ///
+    /// assert_eq!(Sign::from_i8(-5), Sign::Minus);
+    /// assert_eq!(Sign::from_i8(0), Sign::NoSign);
+    /// assert_eq!(Sign::from_i8(1), Sign::Plus);
+    /// ```
+    #[inline]
+    pub fn from_i8(n: i8) -> Sign {
+        Sign::from(n.cmp(&0))
+    }
+
+    /// Returns `-1`, `0`, or `1` for `Minus`, `NoSign`, or `Plus` respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::Sign;
+    ///
+    /// assert_eq!(Sign::Minus.to_i8(), -1);
+    /// assert_eq!(Sign::NoSign.to_i8(), 0);
+    /// assert_eq!((-Sign::Minus * Sign::Plus).to_i8(), 1);
+    /// ```
+    #[inline]
+    pub fn to_i8(self) -> i8 {
+        match self {
+            Minus => -1,
+            NoSign => 0,
+            Plus => 1,
+        }
+    }
+}
+
+/// Maps `Less`, `Equal`, and `Greater` to `Minus`, `NoSign`, and `Plus`, as with the sign
+/// of a comparison against zero.
+impl From<Ordering> for Sign {
+    #[inline]
+    fn from(ord: Ordering) -> Sign {
+        match ord {
+            Less => Minus,
+            Equal => NoSign,
+            Greater => Plus,
+        }
+    }
+}
+
+/// Maps `Minus`, `NoSign`, and `Plus` to `Less`, `Equal`, and `Greater`, the ordering of
+/// a value with that sign against zero.
+impl From<Sign> for Ordering {
+    #[inline]
+    fn from(sign: Sign) -> Ordering {
+        match sign {
+            Minus => Less,
+            NoSign => Equal,
+            Plus => Greater,
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Sign {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -128,14 +198,20 @@ impl Arbitrary for BigInt {
     fn shrink(&self) -> Box<Iterator<Item = Self>> {
         let sign = self.sign();
         let unsigned_shrink = self.data.shrink();
-        Box::new(unsigned_shrink.map(move |x| BigInt::from_biguint(sign, x)))
+        let shrink = unsigned_shrink.map(move |x| BigInt::from_biguint(sign, x));
+        if sign == Sign::Minus {
+            // Try the positive value first.
+            let positive = BigInt::from_biguint(Sign::Plus, self.data.clone());
+            Box::new(Some(positive).into_iter().chain(shrink))
+        } else {
+            Box::new(shrink)
+        }
     }
 }
 
 /// Return the magnitude of a `BigInt`.
 ///
 /// This is in a private module, pseudo pub(crate)
-#[cfg(feature = "rand")]
 pub fn magnitude(i: &BigInt) -> &BigUint {
     &i.data
 }
@@ -180,6 +256,85 @@ impl Ord for BigInt {
     }
 }
 
+// Compares with a primitive without converting it: a number too big to convert is
+// beyond any value of the primitive type, in the direction of its sign.
+macro_rules! impl_scalar_cmp {
+    ($scalar:ty, $to_scalar:ident, $cmp:ident) => {
+        impl BigInt {
+            /// Compares `self` with a primitive integer, without allocating.
+            #[inline]
+            pub fn $cmp(&self, other: $scalar) -> Ordering {
+                match self.$to_scalar() {
+                    Some(n) => n.cmp(&other),
+                    None if self.sign == Minus => Less,
+                    None => Greater,
+                }
+            }
+        }
+    };
+}
+
+impl_scalar_cmp!(u8, to_u8, cmp_u8);
+impl_scalar_cmp!(u16, to_u16, cmp_u16);
+impl_scalar_cmp!(u32, to_u32, cmp_u32);
+impl_scalar_cmp!(u64, to_u64, cmp_u64);
+impl_scalar_cmp!(usize, to_usize, cmp_usize);
+#[cfg(has_i128)]
+impl_scalar_cmp!(u128, to_u128, cmp_u128);
+impl_scalar_cmp!(i8, to_i8, cmp_i8);
+impl_scalar_cmp!(i16, to_i16, cmp_i16);
+impl_scalar_cmp!(i32, to_i32, cmp_i32);
+impl_scalar_cmp!(i64, to_i64, cmp_i64);
+impl_scalar_cmp!(isize, to_isize, cmp_isize);
+#[cfg(has_i128)]
+impl_scalar_cmp!(i128, to_i128, cmp_i128);
+
+/// Compares `n` exactly with `x`, or returns `None` if `x` is NaN.
+fn cmp_f64(n: &BigInt, x: f64) -> Option<Ordering> {
+    match n.sign {
+        Minus if x >= 0.0 => Some(Less),
+        Minus => biguint::cmp_f64(&n.data, -x).map(Ordering::reverse),
+        NoSign | Plus => biguint::cmp_f64(&n.data, x),
+    }
+}
+
+impl BigInt {
+    /// Compares `self` exactly with a float, or returns `None` if `other` is NaN.
+    #[inline]
+    pub fn partial_cmp_f64(&self, other: f64) -> Option<Ordering> {
+        cmp_f64(self, other)
+    }
+
+    /// Compares `self` exactly with a float, or returns `None` if `other` is NaN.
+    #[inline]
+    pub fn partial_cmp_f32(&self, other: f32) -> Option<Ordering> {
+        cmp_f64(self, f64::from(other))
+    }
+
+    /// Compares `self` with a `BigUint` by value.
+    ///
+    /// A negative number is less than any `BigUint` without looking at the magnitudes.
+    /// This is a method rather than a `PartialOrd` impl, which would break type inference
+    /// for existing comparisons like `n == Zero::zero()`.
+    #[inline]
+    pub fn cmp_biguint(&self, other: &BigUint) -> Ordering {
+        match self.sign {
+            Minus => Less,
+            NoSign | Plus => self.data.cmp(other),
+        }
+    }
+}
+
+impl BigUint {
+    /// Compares `self` with a `BigInt` by value.
+    ///
+    /// Any negative `BigInt` is less than `self`.
+    #[inline]
+    pub fn cmp_bigint(&self, other: &BigInt) -> Ordering {
+        other.cmp_biguint(self).reverse()
+    }
+}
+
 impl Default for BigInt {
     #[inline]
     fn default() -> BigInt {
@@ -187,9 +342,25 @@ impl Default for BigInt {
     }
 }
 
+/// Formats the number in decimal.
+///
+/// A precision limits the output to that many significant digits: a number with more
+/// digits is rounded half away from zero and written in scientific notation, so the
+/// exponent shows that digits were dropped.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigInt;
+///
+/// let n = BigInt::from(-1234567);
+/// assert_eq!(format!("{:.3}", n), "-1.23e6");
+/// assert_eq!(format!("{:>10.2}", n), "    -1.2e6");
+/// ```
 impl fmt::Display for BigInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad_integral(!self.is_negative(), "", &self.data.to_str_radix(10))
+        let s = to_str_decimal(&self.data, f.precision());
+        f.pad_integral(!self.is_negative(), "", &s)
     }
 }
 
@@ -213,8 +384,7 @@ impl fmt::LowerHex for BigInt {
 
 impl fmt::UpperHex for BigInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut s = self.data.to_str_radix(16);
-        s.make_ascii_uppercase();
+        let s = self.data.to_str_radix_upper(16);
         f.pad_integral(!self.is_negative(), "0x", &s)
     }
 }
@@ -670,6 +840,364 @@ impl<'a> BitXorAssign<&'a BigInt> for BigInt {
     }
 }
 
+// The little-endian digits of a scalar operand, and how many of them are significant,
+// so the bitwise helpers above can run against it without allocating a `BigInt`.
+#[inline]
+fn u64_to_digits(n: u64) -> ([BigDigit; 2], usize) {
+    let digits = [n as BigDigit, (n >> big_digit::BITS) as BigDigit];
+    let len = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    (digits, len)
+}
+
+#[cfg(has_i128)]
+#[inline]
+fn u128_to_digits(n: u128) -> ([BigDigit; 4], usize) {
+    let digits = [
+        n as BigDigit,
+        (n >> big_digit::BITS) as BigDigit,
+        (n >> (2 * big_digit::BITS)) as BigDigit,
+        (n >> (3 * big_digit::BITS)) as BigDigit,
+    ];
+    let len = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    (digits, len)
+}
+
+promote_all_scalars!(impl BitAnd for BigInt, bitand);
+promote_all_scalars_assign!(impl BitAndAssign for BigInt, bitand_assign);
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u32> for BigInt, bitand);
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u64> for BigInt, bitand);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u128> for BigInt, bitand);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitAnd<i32> for BigInt, bitand);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitAnd<i64> for BigInt, bitand);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitAnd<i128> for BigInt, bitand);
+
+promote_all_scalars!(impl BitOr for BigInt, bitor);
+promote_all_scalars_assign!(impl BitOrAssign for BigInt, bitor_assign);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u32> for BigInt, bitor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u64> for BigInt, bitor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u128> for BigInt, bitor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<i32> for BigInt, bitor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<i64> for BigInt, bitor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<i128> for BigInt, bitor);
+
+promote_all_scalars!(impl BitXor for BigInt, bitxor);
+promote_all_scalars_assign!(impl BitXorAssign for BigInt, bitxor_assign);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u32> for BigInt, bitxor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u64> for BigInt, bitxor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u128> for BigInt, bitxor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<i32> for BigInt, bitxor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<i64> for BigInt, bitxor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<i128> for BigInt, bitxor);
+
+impl<'a> BitAnd<u32> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitand(self, other: u32) -> BigInt {
+        self & other as u64
+    }
+}
+impl BitAndAssign<u32> for BigInt {
+    #[inline]
+    fn bitand_assign(&mut self, other: u32) {
+        *self &= other as u64;
+    }
+}
+impl<'a> BitAnd<&'a BigInt> for u32 {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitand(self, other: &BigInt) -> BigInt {
+        other & self
+    }
+}
+
+impl BitOr<u32> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitor(mut self, other: u32) -> BigInt {
+        self |= other;
+        self
+    }
+}
+impl BitOrAssign<u32> for BigInt {
+    #[inline]
+    fn bitor_assign(&mut self, other: u32) {
+        *self |= other as u64;
+    }
+}
+
+impl BitXor<u32> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitxor(mut self, other: u32) -> BigInt {
+        self ^= other;
+        self
+    }
+}
+impl BitXorAssign<u32> for BigInt {
+    #[inline]
+    fn bitxor_assign(&mut self, other: u32) {
+        *self ^= other as u64;
+    }
+}
+
+impl BitAnd<i32> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitand(mut self, other: i32) -> BigInt {
+        self &= other;
+        self
+    }
+}
+impl BitAndAssign<i32> for BigInt {
+    #[inline]
+    fn bitand_assign(&mut self, other: i32) {
+        *self &= other as i64;
+    }
+}
+
+impl BitOr<i32> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitor(mut self, other: i32) -> BigInt {
+        self |= other;
+        self
+    }
+}
+impl BitOrAssign<i32> for BigInt {
+    #[inline]
+    fn bitor_assign(&mut self, other: i32) {
+        *self |= other as i64;
+    }
+}
+
+impl BitXor<i32> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn bitxor(mut self, other: i32) -> BigInt {
+        self ^= other;
+        self
+    }
+}
+impl BitXorAssign<i32> for BigInt {
+    #[inline]
+    fn bitxor_assign(&mut self, other: i32) {
+        *self ^= other as i64;
+    }
+}
+
+// Bitwise operators against a primitive of at least one full digit, run directly on
+// the scalar's digits with the same two's complement helpers used between `BigInt`s.
+macro_rules! impl_bitwise_scalar {
+    ($u:ty, $i:ty, $to_digits:ident, $abs:ident) => {
+        // A non-negative mask keeps only its own width, so just the low digits of
+        // `self` matter and the rest never needs to be copied.
+        impl<'a> BitAnd<$u> for &'a BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitand(self, other: $u) -> BigInt {
+                match self.sign {
+                    NoSign => Zero::zero(),
+                    Plus => From::from(&self.data & other),
+                    Minus => {
+                        let n = mem::size_of::<$u>() * 8 / big_digit::BITS;
+                        let low = self.digits()[..cmp::min(n, self.len())]
+                            .iter()
+                            .rev()
+                            .fold(0, |acc: $u, &d| (acc << big_digit::BITS) | d as $u);
+                        From::from(low.wrapping_neg() & other)
+                    }
+                }
+            }
+        }
+        impl BitAndAssign<$u> for BigInt {
+            #[inline]
+            fn bitand_assign(&mut self, other: $u) {
+                match self.sign {
+                    NoSign => {}
+                    Plus => {
+                        self.data &= other;
+                        self.normalize();
+                    }
+                    Minus => {
+                        let (digits, len) = $to_digits(other);
+                        bitand_neg_pos(self.digits_mut(), &digits[..len]);
+                        self.sign = Plus;
+                        self.normalize();
+                    }
+                }
+            }
+        }
+        impl<'a> BitAnd<&'a BigInt> for $u {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitand(self, other: &BigInt) -> BigInt {
+                other & self
+            }
+        }
+
+        impl BitAnd<$i> for BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitand(mut self, other: $i) -> BigInt {
+                self &= other;
+                self
+            }
+        }
+        impl BitAndAssign<$i> for BigInt {
+            #[inline]
+            fn bitand_assign(&mut self, other: $i) {
+                if other >= 0 {
+                    *self &= other as $u;
+                } else {
+                    let (digits, len) = $to_digits($abs(other));
+                    match self.sign {
+                        NoSign => {}
+                        Plus => bitand_pos_neg(self.digits_mut(), &digits[..len]),
+                        Minus => bitand_neg_neg(self.digits_mut(), &digits[..len]),
+                    }
+                    self.normalize();
+                }
+            }
+        }
+
+        impl BitOr<$u> for BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitor(mut self, other: $u) -> BigInt {
+                self |= other;
+                self
+            }
+        }
+        impl BitOrAssign<$u> for BigInt {
+            #[inline]
+            fn bitor_assign(&mut self, other: $u) {
+                match self.sign {
+                    NoSign => *self = From::from(other),
+                    Plus => self.data |= other,
+                    Minus => {
+                        let (digits, len) = $to_digits(other);
+                        bitor_neg_pos(self.digits_mut(), &digits[..len]);
+                        self.normalize();
+                    }
+                }
+            }
+        }
+
+        impl BitOr<$i> for BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitor(mut self, other: $i) -> BigInt {
+                self |= other;
+                self
+            }
+        }
+        impl BitOrAssign<$i> for BigInt {
+            #[inline]
+            fn bitor_assign(&mut self, other: $i) {
+                if other >= 0 {
+                    *self |= other as $u;
+                } else {
+                    let (digits, len) = $to_digits($abs(other));
+                    match self.sign {
+                        NoSign => *self = From::from(other),
+                        Plus => {
+                            bitor_pos_neg(self.digits_mut(), &digits[..len]);
+                            self.sign = Minus;
+                            self.normalize();
+                        }
+                        Minus => {
+                            bitor_neg_neg(self.digits_mut(), &digits[..len]);
+                            self.normalize();
+                        }
+                    }
+                }
+            }
+        }
+
+        impl BitXor<$u> for BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitxor(mut self, other: $u) -> BigInt {
+                self ^= other;
+                self
+            }
+        }
+        impl BitXorAssign<$u> for BigInt {
+            #[inline]
+            fn bitxor_assign(&mut self, other: $u) {
+                match self.sign {
+                    NoSign => *self = From::from(other),
+                    Plus => {
+                        self.data ^= other;
+                        self.normalize();
+                    }
+                    Minus => {
+                        let (digits, len) = $to_digits(other);
+                        bitxor_neg_pos(self.digits_mut(), &digits[..len]);
+                        self.normalize();
+                    }
+                }
+            }
+        }
+
+        impl BitXor<$i> for BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn bitxor(mut self, other: $i) -> BigInt {
+                self ^= other;
+                self
+            }
+        }
+        impl BitXorAssign<$i> for BigInt {
+            #[inline]
+            fn bitxor_assign(&mut self, other: $i) {
+                if other >= 0 {
+                    *self ^= other as $u;
+                } else {
+                    let (digits, len) = $to_digits($abs(other));
+                    match self.sign {
+                        NoSign => *self = From::from(other),
+                        Plus => {
+                            bitxor_pos_neg(self.digits_mut(), &digits[..len]);
+                            self.sign = Minus;
+                            self.normalize();
+                        }
+                        Minus => {
+                            bitxor_neg_neg(self.digits_mut(), &digits[..len]);
+                            self.sign = Plus;
+                            self.normalize();
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_bitwise_scalar!(u64, i64, u64_to_digits, i64_abs_as_u64);
+#[cfg(has_i128)]
+impl_bitwise_scalar!(u128, i128, u128_to_digits, i128_abs_as_u128);
+
 impl FromStr for BigInt {
     type Err = ParseBigIntError;
 
@@ -685,6 +1213,7 @@ impl Num for BigInt {
     /// Creates and initializes a BigInt.
     #[inline]
     fn from_str_radix(mut s: &str, radix: u32) -> Result<BigInt, ParseBigIntError> {
+        let len = s.len();
         let sign = if s.starts_with('-') {
             let tail = &s[1..];
             if !tail.starts_with('+') {
@@ -694,7 +1223,8 @@ impl Num for BigInt {
         } else {
             Plus
         };
-        let bu = try!(BigUint::from_str_radix(s, radix));
+        let start = len - s.len();
+        let bu = try!(BigUint::from_str_radix(s, radix).map_err(|e| e.offset(start)));
         Ok(BigInt::from_biguint(sign, bu))
     }
 }
@@ -734,6 +1264,9 @@ fn shr_round_down(i: &BigInt, rhs: usize) -> bool {
             .unwrap_or(false)
 }
 
+/// Shifts right, rounding toward negative infinity like an arithmetic shift of a two's
+/// complement value, so `-3 >> 1` is `-2`.
+/// [`BigInt::shr_trunc`](struct.BigInt.html#method.shr_trunc) rounds toward zero instead.
 impl Shr<usize> for BigInt {
     type Output = BigInt;
 
@@ -768,35 +1301,189 @@ impl ShrAssign<usize> for BigInt {
     }
 }
 
-impl Zero for BigInt {
-    #[inline]
-    fn zero() -> BigInt {
-        BigInt::from_biguint(NoSign, Zero::zero())
-    }
-
-    #[inline]
-    fn set_zero(&mut self) {
-        self.data.set_zero();
-        self.sign = NoSign;
+fn bigint_shr_by(n: &BigInt, rhs: Option<usize>) -> BigInt {
+    match rhs {
+        Some(rhs) => n >> rhs,
+        None if n.is_negative() => -BigInt::one(),
+        None => BigInt::zero(),
     }
+}
 
-    #[inline]
-    fn is_zero(&self) -> bool {
-        self.sign == NoSign
+fn bigint_shr_assign_by(n: &mut BigInt, rhs: Option<usize>) {
+    match rhs {
+        Some(rhs) => *n >>= rhs,
+        None => *n = bigint_shr_by(n, None),
     }
 }
 
-impl One for BigInt {
-    #[inline]
-    fn one() -> BigInt {
-        BigInt::from_biguint(Plus, One::one())
-    }
+// Shifts by the other primitive integers, with the same rules as for `BigUint`.  A right
+// shift too large for `usize` leaves zero, or minus one if negative, as it rounds down.
+macro_rules! impl_shift_methods {
+    ($($scalar:ty: $shl:ident, $shr:ident;)*) => {
+        impl BigInt {$(
+            /// Returns `self << rhs` for an amount of a primitive type other than `usize`.
+            ///
+            /// Panics if the amount is negative, or if a nonzero value would not fit in
+            /// memory.
+            #[inline]
+            pub fn $shl(&self, rhs: $scalar) -> BigInt {
+                BigInt::from_biguint(self.sign, self.data.$shl(rhs))
+            }
 
-    #[inline]
-    fn set_one(&mut self) {
-        self.data.set_one();
-        self.sign = Plus;
-    }
+            /// Returns `self >> rhs` for an amount of a primitive type other than `usize`,
+            /// rounding toward negative infinity.
+            ///
+            /// Panics if the amount is negative.  An amount too large for `usize` leaves
+            /// zero, or minus one if `self` is negative.
+            #[inline]
+            pub fn $shr(&self, rhs: $scalar) -> BigInt {
+                bigint_shr_by(self, shr_amount(rhs))
+            }
+        )*}
+    };
+}
+
+impl_shift_methods! {
+    u8: shl_u8, shr_u8;
+    u16: shl_u16, shr_u16;
+    u32: shl_u32, shr_u32;
+    u64: shl_u64, shr_u64;
+    i8: shl_i8, shr_i8;
+    i16: shl_i16, shr_i16;
+    i32: shl_i32, shr_i32;
+    i64: shl_i64, shr_i64;
+    isize: shl_isize, shr_isize;
+}
+#[cfg(has_i128)]
+impl_shift_methods! {
+    u128: shl_u128, shr_u128;
+    i128: shl_i128, shr_i128;
+}
+
+// Shifts by a `BigUint` amount, with the same rules as for the primitive ones.
+impl<'b> Shl<&'b BigUint> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shl(mut self, rhs: &BigUint) -> BigInt {
+        self <<= rhs;
+        self
+    }
+}
+impl<'a, 'b> Shl<&'b BigUint> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shl(self, rhs: &BigUint) -> BigInt {
+        BigInt::from_biguint(self.sign, &self.data << rhs)
+    }
+}
+impl<'b> ShlAssign<&'b BigUint> for BigInt {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &BigUint) {
+        self.data <<= rhs;
+    }
+}
+
+impl<'b> Shr<&'b BigUint> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shr(mut self, rhs: &BigUint) -> BigInt {
+        self >>= rhs;
+        self
+    }
+}
+impl<'a, 'b> Shr<&'b BigUint> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shr(self, rhs: &BigUint) -> BigInt {
+        bigint_shr_by(self, rhs.to_usize())
+    }
+}
+impl<'b> ShrAssign<&'b BigUint> for BigInt {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &BigUint) {
+        bigint_shr_assign_by(self, rhs.to_usize());
+    }
+}
+
+impl Shl<BigUint> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shl(self, rhs: BigUint) -> BigInt {
+        self << &rhs
+    }
+}
+impl<'a> Shl<BigUint> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shl(self, rhs: BigUint) -> BigInt {
+        self << &rhs
+    }
+}
+impl ShlAssign<BigUint> for BigInt {
+    #[inline]
+    fn shl_assign(&mut self, rhs: BigUint) {
+        *self <<= &rhs;
+    }
+}
+
+impl Shr<BigUint> for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shr(self, rhs: BigUint) -> BigInt {
+        self >> &rhs
+    }
+}
+impl<'a> Shr<BigUint> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn shr(self, rhs: BigUint) -> BigInt {
+        self >> &rhs
+    }
+}
+impl ShrAssign<BigUint> for BigInt {
+    #[inline]
+    fn shr_assign(&mut self, rhs: BigUint) {
+        *self >>= &rhs;
+    }
+}
+
+impl Zero for BigInt {
+    #[inline]
+    fn zero() -> BigInt {
+        BigInt::from_biguint(NoSign, Zero::zero())
+    }
+
+    #[inline]
+    fn set_zero(&mut self) {
+        self.data.set_zero();
+        self.sign = NoSign;
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.sign == NoSign
+    }
+}
+
+impl One for BigInt {
+    #[inline]
+    fn one() -> BigInt {
+        BigInt::from_biguint(Plus, One::one())
+    }
+
+    #[inline]
+    fn set_one(&mut self) {
+        self.data.set_one();
+        self.sign = Plus;
+    }
 
     #[inline]
     fn is_one(&self) -> bool {
@@ -1463,6 +2150,21 @@ impl<'a> MulAssign<&'a BigInt> for BigInt {
 }
 forward_val_assign!(impl MulAssign for BigInt, mul_assign);
 
+// When the product and the addend have the same sign, their magnitudes simply add, so
+// the product can be accumulated straight into the addend's digits.
+fn fused_mul_add(x: &BigInt, y: &BigInt, z: BigInt) -> BigInt {
+    let sign = x.sign * y.sign;
+    if sign == NoSign {
+        z
+    } else if z.sign == NoSign || z.sign == sign {
+        BigInt::from_biguint(sign, biguint::fused_mul_add(&x.data, &y.data, z.data))
+    } else {
+        x * y + z
+    }
+}
+
+impl_mul_add!(BigInt, fused_mul_add);
+
 promote_all_scalars!(impl Mul for BigInt, mul);
 promote_all_scalars_assign!(impl MulAssign for BigInt, mul_assign);
 forward_all_scalar_binop_to_val_val_commutative!(impl Mul<u32> for BigInt, mul);
@@ -1622,7 +2324,7 @@ impl<'a, 'b> Div<&'b BigInt> for &'a BigInt {
 impl<'a> DivAssign<&'a BigInt> for BigInt {
     #[inline]
     fn div_assign(&mut self, other: &BigInt) {
-        *self = &*self / other;
+        self.div_rem_assign(other);
     }
 }
 forward_val_assign!(impl DivAssign for BigInt, div_assign);
@@ -1858,7 +2560,7 @@ impl<'a, 'b> Rem<&'b BigInt> for &'a BigInt {
 impl<'a> RemAssign<&'a BigInt> for BigInt {
     #[inline]
     fn rem_assign(&mut self, other: &BigInt) {
-        *self = &*self % other;
+        *self = self.div_rem_assign(other);
     }
 }
 forward_val_assign!(impl RemAssign for BigInt, rem_assign);
@@ -2093,6 +2795,33 @@ impl<'a> Neg for &'a BigInt {
     }
 }
 
+/// Negating a `BigUint` gives a `BigInt`, as the result is only unsigned if it is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigInt, BigUint};
+///
+/// assert_eq!(-BigUint::from(5u32), BigInt::from(-5));
+/// ```
+impl Neg for BigUint {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(Minus, self)
+    }
+}
+
+impl<'a> Neg for &'a BigUint {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
 impl CheckedAdd for BigInt {
     #[inline]
     fn checked_add(&self, v: &BigInt) -> Option<BigInt> {
@@ -2124,6 +2853,53 @@ impl CheckedDiv for BigInt {
     }
 }
 
+impl CheckedRem for BigInt {
+    #[inline]
+    fn checked_rem(&self, v: &BigInt) -> Option<BigInt> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(self.rem(v))
+    }
+}
+
+impl CheckedNeg for BigInt {
+    #[inline]
+    fn checked_neg(&self) -> Option<BigInt> {
+        Some(-self)
+    }
+}
+
+impl Euclid for BigInt {
+    #[inline]
+    fn div_euclid(&self, v: &BigInt) -> BigInt {
+        BigInt::div_euclid(self, v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &BigInt) -> BigInt {
+        BigInt::rem_euclid(self, v)
+    }
+}
+
+impl CheckedEuclid for BigInt {
+    #[inline]
+    fn checked_div_euclid(&self, v: &BigInt) -> Option<BigInt> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(BigInt::div_euclid(self, v))
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &BigInt) -> Option<BigInt> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(BigInt::rem_euclid(self, v))
+    }
+}
+
 impl Integer for BigInt {
     #[inline]
     fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
@@ -2198,6 +2974,8 @@ impl Integer for BigInt {
     }
 
     /// Returns `true` if the number is a multiple of `other`.
+    ///
+    /// Only zero is a multiple of zero.
     #[inline]
     fn is_multiple_of(&self, other: &BigInt) -> bool {
         self.data.is_multiple_of(&other.data)
@@ -2343,6 +3121,55 @@ impl FromPrimitive for BigInt {
     }
 }
 
+#[cfg(has_try_from)]
+macro_rules! impl_try_from_bigint {
+    ($T:ty, $to_ty:path) => {
+        impl<'a> TryFrom<&'a BigInt> for $T {
+            type Error = TryFromBigIntError<()>;
+
+            #[inline]
+            fn try_from(value: &BigInt) -> Result<$T, TryFromBigIntError<()>> {
+                $to_ty(value).ok_or(TryFromBigIntError::new(()))
+            }
+        }
+
+        impl TryFrom<BigInt> for $T {
+            type Error = TryFromBigIntError<BigInt>;
+
+            #[inline]
+            fn try_from(value: BigInt) -> Result<$T, TryFromBigIntError<BigInt>> {
+                <$T>::try_from(&value).map_err(|_| TryFromBigIntError::new(value))
+            }
+        }
+    };
+}
+
+#[cfg(has_try_from)]
+impl_try_from_bigint!(u8, ToPrimitive::to_u8);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(u16, ToPrimitive::to_u16);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(u32, ToPrimitive::to_u32);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(u64, ToPrimitive::to_u64);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(usize, ToPrimitive::to_usize);
+#[cfg(all(has_try_from, has_i128))]
+impl_try_from_bigint!(u128, ToPrimitive::to_u128);
+
+#[cfg(has_try_from)]
+impl_try_from_bigint!(i8, ToPrimitive::to_i8);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(i16, ToPrimitive::to_i16);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(i32, ToPrimitive::to_i32);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(i64, ToPrimitive::to_i64);
+#[cfg(has_try_from)]
+impl_try_from_bigint!(isize, ToPrimitive::to_isize);
+#[cfg(all(has_try_from, has_i128))]
+impl_try_from_bigint!(i128, ToPrimitive::to_i128);
+
 impl From<i64> for BigInt {
     #[inline]
     fn from(n: i64) -> Self {
@@ -2450,10 +3277,6 @@ impl From<BigUint> for BigInt {
 }
 
 impl IntDigits for BigInt {
-    #[inline]
-    fn digits(&self) -> &[BigDigit] {
-        self.data.digits()
-    }
     #[inline]
     fn digits_mut(&mut self) -> &mut Vec<BigDigit> {
         self.data.digits_mut()
@@ -2538,6 +3361,32 @@ impl biguint::ToBigUint for BigInt {
     }
 }
 
+#[cfg(has_try_from)]
+impl<'a> TryFrom<&'a BigInt> for BigUint {
+    type Error = TryFromBigIntError<()>;
+
+    #[inline]
+    fn try_from(value: &BigInt) -> Result<BigUint, TryFromBigIntError<()>> {
+        value
+            .to_biguint()
+            .ok_or_else(|| TryFromBigIntError::new(()))
+    }
+}
+
+#[cfg(has_try_from)]
+impl TryFrom<BigInt> for BigUint {
+    type Error = TryFromBigIntError<BigInt>;
+
+    #[inline]
+    fn try_from(value: BigInt) -> Result<BigUint, TryFromBigIntError<BigInt>> {
+        if value.sign() == Minus {
+            Err(TryFromBigIntError::new(value))
+        } else {
+            Ok(value.data)
+        }
+    }
+}
+
 macro_rules! impl_to_bigint {
     ($T:ty, $from_ty:path) => {
         impl ToBigInt for $T {
@@ -2568,6 +3417,26 @@ impl_to_bigint!(u128, FromPrimitive::from_u128);
 impl_to_bigint!(f32, FromPrimitive::from_f32);
 impl_to_bigint!(f64, FromPrimitive::from_f64);
 
+#[cfg(has_const_vec_new)]
+impl BigInt {
+    /// Zero, as a constant for `static` and `const` items.
+    ///
+    /// There is no such constant for other values, which need a heap allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Sign};
+    ///
+    /// const ZERO: BigInt = BigInt::ZERO;
+    /// assert_eq!(ZERO.sign(), Sign::NoSign);
+    /// ```
+    pub const ZERO: BigInt = BigInt {
+        sign: NoSign,
+        data: BigUint::ZERO,
+    };
+}
+
 impl BigInt {
     /// Creates and initializes a BigInt.
     ///
@@ -2615,6 +3484,28 @@ impl BigInt {
         }
     }
 
+    /// Creates and initializes a `BigInt`.
+    ///
+    /// The digits are in little-endian base 2<sup>64</sup>.
+    #[inline]
+    pub fn from_slice_u64(sign: Sign, slice: &[u64]) -> BigInt {
+        BigInt::from_biguint(sign, BigUint::from_slice_u64(slice))
+    }
+
+    /// Reinitializes a `BigInt`.
+    ///
+    /// The digits are in little-endian base 2<sup>64</sup>.
+    #[inline]
+    pub fn assign_from_slice_u64(&mut self, sign: Sign, slice: &[u64]) {
+        if sign == NoSign {
+            self.data.assign_from_slice(&[]);
+            self.sign = NoSign;
+        } else {
+            self.data.assign_from_slice_u64(slice);
+            self.sign = if self.data.is_zero() { NoSign } else { sign };
+        }
+    }
+
     /// Creates and initializes a `BigInt`.
     ///
     /// The bytes are in big-endian byte order.
@@ -2646,6 +3537,23 @@ impl BigInt {
         BigInt::from_biguint(sign, BigUint::from_bytes_le(bytes))
     }
 
+    /// Creates and initializes a `BigInt`.
+    ///
+    /// The bytes are in the native byte order of the target platform.
+    #[inline]
+    pub fn from_bytes_ne(sign: Sign, bytes: &[u8]) -> BigInt {
+        BigInt::from_biguint(sign, BigUint::from_bytes_ne(bytes))
+    }
+
+    /// Creates and initializes a `BigInt`.
+    ///
+    /// The `u32` digits are ordered like the bytes of `from_bytes_ne`; see
+    /// [`BigUint::from_u32_digits_ne`](struct.BigUint.html#method.from_u32_digits_ne).
+    #[inline]
+    pub fn from_u32_digits_ne(sign: Sign, digits: &[u32]) -> BigInt {
+        BigInt::from_biguint(sign, BigUint::from_u32_digits_ne(digits))
+    }
+
     /// Creates and initializes a `BigInt` from an array of bytes in
     /// two's complement binary representation.
     ///
@@ -2702,63 +3610,179 @@ impl BigInt {
     /// ```
     #[inline]
     pub fn parse_bytes(buf: &[u8], radix: u32) -> Option<BigInt> {
-        str::from_utf8(buf)
-            .ok()
-            .and_then(|s| BigInt::from_str_radix(s, radix).ok())
+        BigInt::try_parse_bytes(buf, radix).ok()
     }
 
-    /// Creates and initializes a `BigInt`. Each u8 of the input slice is
-    /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
-    ///
-    /// The bytes are in big-endian byte order.
-    /// `radix` must be in the range `2...256`.
+    /// Creates and initializes a `BigInt` like `parse_bytes`, but with an error that
+    /// tells whether the input was empty, had an invalid digit, or the radix was not in
+    /// the range `2...62`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{BigInt, Sign};
+    /// use num_bigint::{BigInt, BigIntErrorKind};
     ///
-    /// let inbase190 = vec![15, 33, 125, 12, 14];
-    /// let a = BigInt::from_radix_be(Sign::Minus, &inbase190, 190).unwrap();
-    /// assert_eq!(a.to_radix_be(190), (Sign:: Minus, inbase190));
+    /// assert_eq!(BigInt::try_parse_bytes(b"-ABCD", 16), Ok(BigInt::from(-0xABCD)));
+    /// let err = BigInt::try_parse_bytes(b"-", 16).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::Empty);
     /// ```
-    pub fn from_radix_be(sign: Sign, buf: &[u8], radix: u32) -> Option<BigInt> {
-        BigUint::from_radix_be(buf, radix).map(|u| BigInt::from_biguint(sign, u))
+    pub fn try_parse_bytes(buf: &[u8], radix: u32) -> Result<BigInt, ParseBigIntError> {
+        if radix < 2 || radix > 62 {
+            return Err(ParseBigIntError::invalid_radix());
+        }
+        match str::from_utf8(buf) {
+            Ok(s) => BigInt::from_str_radix(s, radix),
+            Err(e) => Err(ParseBigIntError::invalid_utf8(e.valid_up_to())),
+        }
     }
 
-    /// Creates and initializes a `BigInt`. Each u8 of the input slice is
-    /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
+    /// Parses a number with an optional leading `+` or `-`, choosing the radix from its
+    /// prefix: `0x` for hexadecimal, `0o` for octal, `0b` for binary, or decimal without
+    /// one.
     ///
-    /// The bytes are in little-endian byte order.
-    /// `radix` must be in the range `2...256`.
+    /// The prefix may also be uppercase, and the digits may be separated by `_`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{BigInt, Sign};
+    /// use num_bigint::BigInt;
     ///
-    /// let inbase190 = vec![14, 12, 125, 33, 15];
-    /// let a = BigInt::from_radix_be(Sign::Minus, &inbase190, 190).unwrap();
-    /// assert_eq!(a.to_radix_be(190), (Sign::Minus, inbase190));
+    /// assert_eq!(BigInt::from_str_prefixed("-0xff"), Ok(BigInt::from(-255)));
+    /// assert_eq!(BigInt::from_str_prefixed("+0o17"), Ok(BigInt::from(15)));
+    /// assert_eq!(BigInt::from_str_prefixed("-12"), Ok(BigInt::from(-12)));
+    /// assert!(BigInt::from_str_prefixed("0x-ff").is_err());
     /// ```
-    pub fn from_radix_le(sign: Sign, buf: &[u8], radix: u32) -> Option<BigInt> {
-        BigUint::from_radix_le(buf, radix).map(|u| BigInt::from_biguint(sign, u))
+    pub fn from_str_prefixed(s: &str) -> Result<BigInt, ParseBigIntError> {
+        let (sign, start) = if s.starts_with('-') {
+            (Minus, 1)
+        } else if s.starts_with('+') {
+            (Plus, 1)
+        } else {
+            (Plus, 0)
+        };
+        let s = &s[start..];
+        let magnitude = try!(from_str_prefixed_unsigned(s).map_err(|e| e.offset(start)));
+        Ok(BigInt::from_biguint(sign, magnitude))
     }
 
-    /// Returns the sign and the byte representation of the `BigInt` in big-endian byte order.
+    /// Parses a number in the given radix like `from_str_radix`, but first removes every
+    /// occurrence of the `separators`, wherever they are.
+    ///
+    /// This accepts numbers with digit group separators, like `"-1,234,567"` or a
+    /// hexadecimal constant spread over several lines.
+    /// `radix` must be in the range `2...62`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{ToBigInt, Sign};
+    /// use num_bigint::BigInt;
     ///
-    /// let i = -1125.to_bigint().unwrap();
-    /// assert_eq!(i.to_bytes_be(), (Sign::Minus, vec![4, 101]));
+    /// let n = BigInt::from_str_radix_separated("-1 234 567", 10, &[' ']);
+    /// assert_eq!(n, Ok(BigInt::from(-1234567)));
     /// ```
-    #[inline]
-    pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
+    pub fn from_str_radix_separated(
+        s: &str,
+        radix: u32,
+        separators: &[char],
+    ) -> Result<BigInt, ParseBigIntError> {
+        BigInt::from_str_radix(&remove_separators(s, separators), radix)
+            .map_err(|e| restore_separators(e, s, separators))
+    }
+
+    /// Parses a decimal number in scientific notation, like `"-1e100"` or `"1.5e10"`,
+    /// with an optional leading `+` or `-`.
+    ///
+    /// The fraction and the exponent are both optional, and the exponent may be
+    /// negative, but the value must be an integer: a fractional part that is not made up
+    /// by the exponent is an error.
+    ///
+    /// The magnitude is limited to 2<sup>20</sup> bits, as in
+    /// [`BigUint::from_str_scientific`](struct.BigUint.html#method.from_str_scientific).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_str_scientific("-1.5e3"), Ok(BigInt::from(-1500)));
+    /// assert!(BigInt::from_str_scientific("-0.5").is_err());
+    /// ```
+    pub fn from_str_scientific(s: &str) -> Result<BigInt, ParseBigIntError> {
+        BigInt::from_str_scientific_max_bits(s, SCIENTIFIC_MAX_BITS)
+    }
+
+    /// Parses a decimal number in scientific notation like
+    /// [`from_str_scientific`](#method.from_str_scientific), with a magnitude of at most
+    /// `max_bits` bits.
+    pub fn from_str_scientific_max_bits(
+        s: &str,
+        max_bits: u64,
+    ) -> Result<BigInt, ParseBigIntError> {
+        let (sign, start) = if s.starts_with('-') {
+            (Minus, 1)
+        } else if s.starts_with('+') {
+            (Plus, 1)
+        } else {
+            (Plus, 0)
+        };
+        let s = &s[start..];
+        let magnitude =
+            try!(from_str_scientific_unsigned(s, max_bits).map_err(|e| e.offset(start)));
+        Ok(BigInt::from_biguint(sign, magnitude))
+    }
+
+    /// Creates and initializes a `BigInt`. Each u8 of the input slice is
+    /// interpreted as one digit of the number
+    /// and must therefore be less than `radix`.
+    ///
+    /// The bytes are in big-endian byte order.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Sign};
+    ///
+    /// let inbase190 = vec![15, 33, 125, 12, 14];
+    /// let a = BigInt::from_radix_be(Sign::Minus, &inbase190, 190).unwrap();
+    /// assert_eq!(a.to_radix_be(190), (Sign:: Minus, inbase190));
+    /// ```
+    pub fn from_radix_be(sign: Sign, buf: &[u8], radix: u32) -> Option<BigInt> {
+        BigUint::from_radix_be(buf, radix).map(|u| BigInt::from_biguint(sign, u))
+    }
+
+    /// Creates and initializes a `BigInt`. Each u8 of the input slice is
+    /// interpreted as one digit of the number
+    /// and must therefore be less than `radix`.
+    ///
+    /// The bytes are in little-endian byte order.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Sign};
+    ///
+    /// let inbase190 = vec![14, 12, 125, 33, 15];
+    /// let a = BigInt::from_radix_be(Sign::Minus, &inbase190, 190).unwrap();
+    /// assert_eq!(a.to_radix_be(190), (Sign::Minus, inbase190));
+    /// ```
+    pub fn from_radix_le(sign: Sign, buf: &[u8], radix: u32) -> Option<BigInt> {
+        BigUint::from_radix_le(buf, radix).map(|u| BigInt::from_biguint(sign, u))
+    }
+
+    /// Returns the sign and the byte representation of the `BigInt` in big-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{ToBigInt, Sign};
+    ///
+    /// let i = -1125.to_bigint().unwrap();
+    /// assert_eq!(i.to_bytes_be(), (Sign::Minus, vec![4, 101]));
+    /// ```
+    #[inline]
+    pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
         (self.sign, self.data.to_bytes_be())
     }
 
@@ -2777,6 +3801,98 @@ impl BigInt {
         (self.sign, self.data.to_bytes_le())
     }
 
+    /// Returns the sign and the byte representation of the `BigInt` in the native byte order of
+    /// the target platform.
+    #[inline]
+    pub fn to_bytes_ne(&self) -> (Sign, Vec<u8>) {
+        (self.sign, self.data.to_bytes_ne())
+    }
+
+    /// Returns the sign and the `u32` digits representation of the `BigInt` ordered least
+    /// significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Sign};
+    ///
+    /// assert_eq!(BigInt::from(-1125).to_u32_digits(), (Sign::Minus, vec![1125]));
+    /// assert_eq!(BigInt::from(4294967295u32).to_u32_digits(), (Sign::Plus, vec![4294967295]));
+    /// assert_eq!(BigInt::from(4294967296u64).to_u32_digits(), (Sign::Plus, vec![0, 1]));
+    /// assert_eq!(BigInt::from(-112500000000i64).to_u32_digits(), (Sign::Minus, vec![830850304, 26]));
+    /// ```
+    #[inline]
+    pub fn to_u32_digits(&self) -> (Sign, Vec<u32>) {
+        (self.sign, self.data.to_u32_digits())
+    }
+
+    /// Returns the sign and the `u32` digits of the `BigInt` ordered like the bytes of
+    /// `to_bytes_ne`.
+    #[inline]
+    pub fn to_u32_digits_ne(&self) -> (Sign, Vec<u32>) {
+        (self.sign, self.data.to_u32_digits_ne())
+    }
+
+    /// Returns the sign and the `u64` digits representation of the `BigInt` ordered least
+    /// significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Sign};
+    ///
+    /// assert_eq!(BigInt::from(-1125).to_u64_digits(), (Sign::Minus, vec![1125]));
+    /// assert_eq!(BigInt::from(4294967295u32).to_u64_digits(), (Sign::Plus, vec![4294967295]));
+    /// assert_eq!(BigInt::from(4294967296u64).to_u64_digits(), (Sign::Plus, vec![4294967296]));
+    /// assert_eq!(BigInt::from(-112500000000i64).to_u64_digits(), (Sign::Minus, vec![112500000000]));
+    /// ```
+    #[inline]
+    pub fn to_u64_digits(&self) -> (Sign, Vec<u64>) {
+        (self.sign, self.data.to_u64_digits())
+    }
+
+    /// Returns an iterator of `u32` digits representation of the magnitude of the `BigInt`
+    /// ordered least significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1125).iter_u32_digits().collect::<Vec<u32>>(), vec![1125]);
+    /// assert_eq!(BigInt::from(4294967296u64).iter_u32_digits().collect::<Vec<u32>>(), vec![0, 1]);
+    /// ```
+    #[inline]
+    pub fn iter_u32_digits<'a>(&'a self) -> U32Digits<'a> {
+        self.data.iter_u32_digits()
+    }
+
+    /// Returns an iterator of `u64` digits representation of the magnitude of the `BigInt`
+    /// ordered least significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1125).iter_u64_digits().collect::<Vec<u64>>(), vec![1125]);
+    /// assert_eq!(BigInt::from(4294967296u64).iter_u64_digits().collect::<Vec<u64>>(), vec![4294967296]);
+    /// ```
+    #[inline]
+    pub fn iter_u64_digits<'a>(&'a self) -> U64Digits<'a> {
+        self.data.iter_u64_digits()
+    }
+
+    /// Returns the internal digits of the magnitude of the `BigInt`, ordered least significant
+    /// digit first, without copying them.
+    ///
+    /// As with [`BigUint::digits`](struct.BigUint.html#method.digits), the size of a digit is an
+    /// implementation detail which may change in a future release.
+    #[inline]
+    pub fn digits(&self) -> &[BigDigit] {
+        self.data.digits()
+    }
+
     /// Returns the two's complement byte representation of the `BigInt` in big-endian byte order.
     ///
     /// # Examples
@@ -2834,7 +3950,8 @@ impl BigInt {
     }
 
     /// Returns the integer formatted as a string in the given radix.
-    /// `radix` must be in the range `2...36`.
+    /// `radix` must be in the range `2...62`.  Above radix 36, the digits are
+    /// `0-9A-Za-z` as in GMP.
     ///
     /// # Examples
     ///
@@ -2846,7 +3963,32 @@ impl BigInt {
     /// ```
     #[inline]
     pub fn to_str_radix(&self, radix: u32) -> String {
-        let mut v = to_str_radix_reversed(&self.data, radix);
+        let mut v = to_str_radix_reversed(&self.data, radix, false);
+
+        if self.is_negative() {
+            v.push(b'-');
+        }
+
+        v.reverse();
+        unsafe { String::from_utf8_unchecked(v) }
+    }
+
+    /// Returns the integer formatted as a string in the given radix, with uppercase
+    /// letters for digits above nine.
+    /// `radix` must be in the range `2...62`.  Above radix 36, the digits are
+    /// `0-9A-Za-z` as in GMP, the same as `to_str_radix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let i = BigInt::parse_bytes(b"-ff", 16).unwrap();
+    /// assert_eq!(i.to_str_radix_upper(16), "-FF");
+    /// ```
+    #[inline]
+    pub fn to_str_radix_upper(&self, radix: u32) -> String {
+        let mut v = to_str_radix_reversed(&self.data, radix, true);
 
         if self.is_negative() {
             v.push(b'-');
@@ -2910,11 +4052,232 @@ impl BigInt {
         self.sign
     }
 
+    /// Returns a reference to the magnitude of the `BigInt`, without copying the digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// let n = BigInt::from(-1234);
+    /// assert_eq!(n.magnitude(), &BigUint::from(1234u32));
+    /// assert_eq!(BigInt::from(0).magnitude(), &BigUint::from(0u32));
+    /// ```
+    #[inline]
+    pub fn magnitude(&self) -> &BigUint {
+        &self.data
+    }
+
+    /// Splits the `BigInt` into its sign and magnitude, without copying the digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint, Sign};
+    ///
+    /// let (sign, magnitude) = BigInt::from(-1234).into_parts();
+    /// assert_eq!(sign, Sign::Minus);
+    /// assert_eq!(magnitude, BigUint::from(1234u32));
+    /// assert_eq!(BigInt::from_parts(sign, magnitude), BigInt::from(-1234));
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (Sign, BigUint) {
+        (self.sign, self.data)
+    }
+
+    /// Creates a `BigInt` from a sign and a magnitude, as returned by
+    /// [`into_parts`](#method.into_parts).
+    ///
+    /// This is the same as [`from_biguint`](#method.from_biguint): a zero magnitude makes
+    /// the sign `NoSign`, and `NoSign` makes the value zero.
+    #[inline]
+    pub fn from_parts(sign: Sign, magnitude: BigUint) -> BigInt {
+        BigInt::from_biguint(sign, magnitude)
+    }
+
     /// Determines the fewest bits necessary to express the `BigInt`,
     /// not including the sign.
+    ///
+    /// Panics if the count doesn't fit in `usize`, which can happen on 32-bit targets.
+    #[deprecated(since = "0.2.3", note = "use `bit_len`, which returns `u64`")]
     #[inline]
     pub fn bits(&self) -> usize {
-        self.data.bits()
+        biguint::bits_to_usize(self.bit_len())
+    }
+
+    /// Determines the fewest bits necessary to express the `BigInt`,
+    /// not including the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0).bit_len(), 0);
+    /// assert_eq!(BigInt::from(-0b1011).bit_len(), 4);
+    /// ```
+    #[inline]
+    pub fn bit_len(&self) -> u64 {
+        self.data.bit_len()
+    }
+
+    /// Returns the number of ones in the binary representation of the magnitude of `self`,
+    /// ignoring the sign.
+    ///
+    /// See [`count_ones_in_width`](#method.count_ones_in_width) to count the ones of a
+    /// negative value in two's complement instead.
+    #[inline]
+    pub fn count_ones(&self) -> u64 {
+        self.data.count_ones()
+    }
+
+    /// Returns the number of ones in the low `width` bits of the two's complement of `self`,
+    /// as if it were stored in a signed integer of `width` bits.
+    ///
+    /// The two's complement of a negative value has infinitely many leading ones, so the
+    /// width bounds the count.  Bits of the magnitude above the width are ignored, as if the
+    /// value had wrapped around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1).count_ones_in_width(64), 64);
+    /// assert_eq!(BigInt::from(-128).count_ones_in_width(8), 1);
+    /// assert_eq!(BigInt::from(6).count_ones_in_width(2), 1);
+    /// assert_eq!(BigInt::from(-6).count_ones(), 2);
+    /// ```
+    pub fn count_ones_in_width(&self, width: u64) -> u64 {
+        match self.sign {
+            // -m == !(m - 1) in two's complement.
+            Minus => width - biguint::count_ones_low(&(&self.data - 1u32), width),
+            NoSign | Plus => biguint::count_ones_low(&self.data, width),
+        }
+    }
+
+    /// Returns the number of 32-bit digits used to store the magnitude of this `BigInt`.
+    #[inline]
+    pub fn digits_len(&self) -> usize {
+        self.data.digits_len()
+    }
+
+    /// Returns `true` if the magnitude is normalized and the sign is `NoSign` exactly when
+    /// the value is zero.
+    ///
+    /// This is only available with debug assertions, as a check of internal invariants.
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.data.is_normalized() && (self.sign == NoSign) == self.data.is_zero()
+    }
+
+    /// Returns the number of bytes allocated on the heap for the digits of this `BigInt`.
+    #[inline]
+    pub fn heap_size_in_bytes(&self) -> usize {
+        self.data.heap_size_in_bytes()
+    }
+
+    /// Releases any excess capacity held by the internal buffer of this `BigInt`.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Returns `self << rhs`, or `None` if the magnitude of the result would have too many
+    /// digits to ever be allocated.  See
+    /// [`BigUint::checked_shl`](struct.BigUint.html#method.checked_shl).
+    #[inline]
+    pub fn checked_shl(&self, rhs: usize) -> Option<BigInt> {
+        self.data
+            .checked_shl(rhs)
+            .map(|data| BigInt::from_biguint(self.sign, data))
+    }
+
+    /// Returns `self << rhs`, or `None` if the magnitude of the result would need more than
+    /// `max_bits` bits.  See
+    /// [`BigUint::checked_shl_max`](struct.BigUint.html#method.checked_shl_max).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(-3);
+    /// assert_eq!(n.checked_shl_max(4, 8), Some(BigInt::from(-48)));
+    /// assert_eq!(n.checked_shl_max(7, 8), None);
+    /// ```
+    #[inline]
+    pub fn checked_shl_max(&self, rhs: usize, max_bits: u64) -> Option<BigInt> {
+        self.data
+            .checked_shl_max(rhs, max_bits)
+            .map(|data| BigInt::from_biguint(self.sign, data))
+    }
+
+    /// Returns `self ^ exp`, or `None` if the magnitude of the result would have too many
+    /// digits to ever be allocated.  See
+    /// [`BigUint::checked_pow`](struct.BigUint.html#method.checked_pow).
+    #[inline]
+    pub fn checked_pow(&self, exp: u64) -> Option<BigInt> {
+        self.data
+            .checked_pow(exp)
+            .map(|data| BigInt::from_biguint(powsign(self.sign, &exp), data))
+    }
+
+    /// Returns `self ^ exp`, or `None` if the magnitude of the result would need more than
+    /// `max_bits` bits.  See
+    /// [`BigUint::checked_pow_max`](struct.BigUint.html#method.checked_pow_max).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(-3);
+    /// assert_eq!(n.checked_pow_max(5, 8), Some(BigInt::from(-243)));
+    /// assert_eq!(n.checked_pow_max(6, 8), None);
+    /// ```
+    #[inline]
+    pub fn checked_pow_max(&self, exp: u64, max_bits: u64) -> Option<BigInt> {
+        self.data
+            .checked_pow_max(exp, max_bits)
+            .map(|data| BigInt::from_biguint(powsign(self.sign, &exp), data))
+    }
+
+    /// Shifts right by `rhs` bits, rounding toward negative infinity.
+    ///
+    /// This is the same as the `>>` operator, and as an arithmetic shift of a two's complement
+    /// value: it equals `self.div_floor(2^rhs)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-3).shr_floor(1), BigInt::from(-2));
+    /// assert_eq!(BigInt::from(3).shr_floor(1), BigInt::from(1));
+    /// ```
+    #[inline]
+    pub fn shr_floor(&self, rhs: usize) -> BigInt {
+        self >> rhs
+    }
+
+    /// Shifts right by `rhs` bits, rounding toward zero.
+    ///
+    /// This shifts the magnitude and keeps the sign, so it equals `self / 2^rhs`, as with
+    /// dividing a signed integer in C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-3).shr_trunc(1), BigInt::from(-1));
+    /// assert_eq!(BigInt::from(3).shr_trunc(1), BigInt::from(1));
+    /// ```
+    #[inline]
+    pub fn shr_trunc(&self, rhs: usize) -> BigInt {
+        BigInt::from_biguint(self.sign, &self.data >> rhs)
     }
 
     /// Converts this `BigInt` into a `BigUint`, if it's not negative.
@@ -2950,6 +4313,119 @@ impl BigInt {
         return Some(self.div(v));
     }
 
+    /// Divides `self` by `other` in place, leaving the truncated quotient in `self` and returning
+    /// the remainder, which has the same sign as the original `self`.
+    ///
+    /// This is equivalent to `Integer::div_rem`, but does not need to clone `self` first.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut n = BigInt::from(-100);
+    /// let r = n.div_rem_assign(&BigInt::from(7));
+    /// assert_eq!(n, BigInt::from(-14));
+    /// assert_eq!(r, BigInt::from(-2));
+    /// ```
+    pub fn div_rem_assign(&mut self, other: &BigInt) -> BigInt {
+        let r_ui = self.data.div_rem_assign(&other.data);
+        let r = BigInt::from_biguint(self.sign, r_ui);
+        let q_ui = mem::replace(&mut self.data, Zero::zero());
+        *self = BigInt::from_biguint(self.sign * other.sign, q_ui);
+        r
+    }
+
+    /// Returns the quotient and remainder of Euclidean division, where the remainder is
+    /// never negative, like `i64::div_euclid` and `i64::rem_euclid`.
+    ///
+    /// The `/` and `%` operators truncate toward zero, so `%` takes the sign of `self`, and
+    /// `mod_floor` takes the sign of `other`.  The Euclidean remainder is always in
+    /// `[0, |other|)`, and `self == q * other + r`.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let a = BigInt::from(-7);
+    /// assert_eq!(a.div_rem_euclid(&BigInt::from(2)), (BigInt::from(-4), BigInt::from(1)));
+    /// assert_eq!(a.div_rem_euclid(&BigInt::from(-2)), (BigInt::from(4), BigInt::from(1)));
+    /// ```
+    pub fn div_rem_euclid(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = self.div_rem(other);
+        if !r.is_negative() {
+            (q, r)
+        } else if other.is_negative() {
+            (q + 1u32, r - other)
+        } else {
+            (q - 1u32, r + other)
+        }
+    }
+
+    /// Returns the quotient of Euclidean division -- see
+    /// [`div_rem_euclid`](#method.div_rem_euclid).
+    #[inline]
+    pub fn div_euclid(&self, other: &BigInt) -> BigInt {
+        self.div_rem_euclid(other).0
+    }
+
+    /// Returns the remainder of Euclidean division, which is never negative -- see
+    /// [`div_rem_euclid`](#method.div_rem_euclid).
+    #[inline]
+    pub fn rem_euclid(&self, other: &BigInt) -> BigInt {
+        self.div_rem_euclid(other).1
+    }
+
+    /// Returns `self / other` rounded toward positive infinity.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(8).div_ceil(&BigInt::from(3)), BigInt::from(3));
+    /// assert_eq!(BigInt::from(-8).div_ceil(&BigInt::from(3)), BigInt::from(-2));
+    /// ```
+    pub fn div_ceil(&self, other: &BigInt) -> BigInt {
+        let (mut q, r) = self.div_rem(other);
+        // The remainder has the sign of `self`, so the exact quotient is positive, and
+        // truncation rounded it down, when it matches the sign of `other`.
+        if !r.is_zero() && self.sign == other.sign {
+            q += 1u32;
+        }
+        q
+    }
+
+    /// Returns the smallest multiple of `other` that is at least `self` if `other` is
+    /// positive, or the largest that is at most `self` if `other` is negative, like
+    /// `Integer::next_multiple_of`.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-23).next_multiple_of(&BigInt::from(8)), BigInt::from(-16));
+    /// assert_eq!(BigInt::from(-23).next_multiple_of(&BigInt::from(-8)), BigInt::from(-24));
+    /// ```
+    pub fn next_multiple_of(&self, other: &BigInt) -> BigInt {
+        let m = self.mod_floor(other);
+        if m.is_zero() {
+            self.clone()
+        } else {
+            self + (other - m)
+        }
+    }
+
     /// Returns `(self ^ exponent) mod modulus`
     ///
     /// Note that this rounds like `mod_floor`, not like the `%` operator,
@@ -2997,6 +4473,82 @@ impl BigInt {
     pub fn nth_root(&self, n: u32) -> Self {
         Roots::nth_root(self, n)
     }
+
+    /// Returns the base 2 logarithm of `self`, rounded down, or `None` if `self` is not
+    /// positive.
+    #[inline]
+    pub fn checked_ilog2(&self) -> Option<u64> {
+        self.positive_magnitude().and_then(BigUint::checked_ilog2)
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` is not positive.
+    pub fn ilog2(&self) -> u64 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is not
+    /// positive.
+    #[inline]
+    pub fn checked_ilog10(&self) -> Option<u64> {
+        self.positive_magnitude().and_then(BigUint::checked_ilog10)
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` is not positive.
+    pub fn ilog10(&self) -> u64 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the logarithm of `self` in `base`, rounded down, or `None` if `self` is not
+    /// positive or `base` is less than 2 -- see
+    /// [`BigUint::checked_ilog`](struct.BigUint.html#method.checked_ilog).
+    #[inline]
+    pub fn checked_ilog(&self, base: u32) -> Option<u64> {
+        self.positive_magnitude().and_then(|n| n.checked_ilog(base))
+    }
+
+    /// Returns the logarithm of `self` in `base`, rounded down.
+    ///
+    /// Panics if `self` is not positive or `base` is less than 2.
+    pub fn ilog(&self, base: u32) -> u64 {
+        assert!(base >= 2, "base of integer logarithm must be at least 2");
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    fn positive_magnitude(&self) -> Option<&BigUint> {
+        if self.sign == Plus {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the product of the values, multiplied in a balanced binary tree, or one if
+    /// there are none.
+    ///
+    /// See [`BigUint::product_tree`](struct.BigUint.html#method.product_tree).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let product = BigInt::product_tree((1..101).map(|i| if i % 2 == 0 { i } else { -i }));
+    /// assert_eq!(product, (1..101).map(BigInt::from).product());
+    /// ```
+    pub fn product_tree<I>(iter: I) -> BigInt
+    where
+        I: IntoIterator,
+        I::Item: Into<BigInt>,
+    {
+        biguint::product_tree(iter.into_iter().map(Into::into))
+    }
 }
 
 impl_sum_iter_type!(BigInt);
@@ -3081,3 +4633,21 @@ fn test_assign_from_slice() {
     check(Minus, 1, Minus, 1);
     check(NoSign, 1, NoSign, 0);
 }
+
+#[test]
+fn test_assign_from_slice_u64() {
+    fn check(inp_s: Sign, inp_n: u64, ans_s: Sign, ans_n: u64) {
+        let mut inp = BigInt::from_slice(Minus, &[2627_u32, 0_u32, 9182_u32, 42_u32]);
+        inp.assign_from_slice_u64(inp_s, &[inp_n]);
+        let ans = BigInt {
+            sign: ans_s,
+            data: FromPrimitive::from_u64(ans_n).unwrap(),
+        };
+        assert_eq!(inp, ans);
+        assert_eq!(BigInt::from_slice_u64(inp_s, &[inp_n]), ans);
+    }
+    check(Plus, 1, Plus, 1);
+    check(Plus, 0, NoSign, 0);
+    check(Minus, 1 << 40, Minus, 1 << 40);
+    check(NoSign, 1, NoSign, 0);
+}
@@ -10,30 +10,80 @@ use Sign::*;
 
 use big_digit::BigDigit;
 use bigint::{into_magnitude, magnitude};
+use biguint::bits_to_usize;
 
 use integer::Integer;
-use traits::Zero;
+use traits::{One, Zero};
 
 pub trait RandBigInt {
     /// Generate a random `BigUint` of the given bit size.
     fn gen_biguint(&mut self, bit_size: usize) -> BigUint;
 
+    /// Generate a random `BigUint` of exactly the given bit size, with its top bit set, so
+    /// that it is uniform in `[2^(bit_size - 1), 2^bit_size)`. Fails when the bit size is
+    /// zero.
+    fn gen_biguint_exact_bits(&mut self, bit_size: usize) -> BigUint;
+
+    /// Generate a random odd `BigUint` of the given bit size, uniform among the odd numbers
+    /// below `2^bit_size`. Fails when the bit size is zero.
+    fn gen_biguint_odd(&mut self, bit_size: usize) -> BigUint;
+
+    /// Generate a random `BigUint` of the given bit size that is congruent to `r` modulo
+    /// `m`, uniform among such numbers below `2^bit_size`. Fails when `m` is zero, or when
+    /// no number of that size is congruent to `r`.
+    fn gen_biguint_congruent(&mut self, bit_size: usize, r: &BigUint, m: &BigUint) -> BigUint;
+
     /// Generate a random BigInt of the given bit size.
     fn gen_bigint(&mut self, bit_size: usize) -> BigInt;
 
     /// Generate a random `BigUint` less than the given bound. Fails
     /// when the bound is zero.
+    ///
+    /// The result is exactly uniform: this draws as many random bits as
+    /// `bound - 1` has, masking off the rest of the top digit, and draws
+    /// again whenever that is not below the bound.  As the bound is more
+    /// than half of the range drawn from, each draw is rejected with
+    /// probability less than 1/2, so fewer than one draw is rejected on
+    /// average, and none at all when the bound is a power of two.
     fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint;
 
     /// Generate a random `BigUint` within the given range. The lower
     /// bound is inclusive; the upper bound is exclusive. Fails when
     /// the upper bound is not greater than the lower bound.
+    ///
+    /// This is the lower bound plus `gen_biguint_below` of the width of
+    /// the range, so it is exactly uniform too.
     fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint;
 
     /// Generate a random `BigInt` within the given range. The lower
     /// bound is inclusive; the upper bound is exclusive. Fails when
     /// the upper bound is not greater than the lower bound.
     fn gen_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt;
+
+    /// Generate a random `BigUint` within the given range, including
+    /// both bounds. Fails when the upper bound is less than the lower
+    /// bound.
+    fn gen_biguint_range_inclusive(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint;
+
+    /// Generate a random `BigInt` within the given range, including
+    /// both bounds. Fails when the upper bound is less than the lower
+    /// bound.
+    fn gen_bigint_range_inclusive(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt;
+}
+
+/// Draws like `gen_biguint_below`, and also returns how many draws were rejected for
+/// not being below the bound.
+fn gen_biguint_below_counted<R: Rng + ?Sized>(rng: &mut R, bound: &BigUint) -> (BigUint, usize) {
+    assert!(!bound.is_zero());
+    let bits = bits_to_usize((bound - 1u32).bit_len());
+    let mut rejections = 0;
+    loop {
+        let n = rng.gen_biguint(bits);
+        if n < *bound {
+            return (n, rejections);
+        }
+        rejections += 1;
+    }
 }
 
 impl<R: Rng + ?Sized> RandBigInt for R {
@@ -53,6 +103,26 @@ impl<R: Rng + ?Sized> RandBigInt for R {
         BigUint::new(data)
     }
 
+    fn gen_biguint_exact_bits(&mut self, bit_size: usize) -> BigUint {
+        assert!(bit_size > 0);
+        let top = BigUint::one() << (bit_size - 1);
+        self.gen_biguint(bit_size - 1) | top
+    }
+
+    fn gen_biguint_odd(&mut self, bit_size: usize) -> BigUint {
+        assert!(bit_size > 0);
+        (self.gen_biguint(bit_size - 1) << 1usize) | BigUint::one()
+    }
+
+    fn gen_biguint_congruent(&mut self, bit_size: usize, r: &BigUint, m: &BigUint) -> BigUint {
+        // The candidates are r + k * m for 0 <= k < count, so pick k uniformly.
+        let r = r % m;
+        let limit = BigUint::one() << bit_size;
+        assert!(r < limit);
+        let count = (limit - &r + m - 1u32) / m;
+        r + self.gen_biguint_below(&count) * m
+    }
+
     fn gen_bigint(&mut self, bit_size: usize) -> BigInt {
         loop {
             // Generate a random BigUint...
@@ -78,14 +148,7 @@ impl<R: Rng + ?Sized> RandBigInt for R {
     }
 
     fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint {
-        assert!(!bound.is_zero());
-        let bits = bound.bits();
-        loop {
-            let n = self.gen_biguint(bits);
-            if n < *bound {
-                return n;
-            }
-        }
+        gen_biguint_below_counted(self, bound).0
     }
 
     fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
@@ -108,6 +171,17 @@ impl<R: Rng + ?Sized> RandBigInt for R {
             lbound + BigInt::from(self.gen_biguint_below(magnitude(&delta)))
         }
     }
+
+    fn gen_biguint_range_inclusive(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
+        assert!(*lbound <= *ubound);
+        lbound + self.gen_biguint_below(&(ubound - lbound + 1u32))
+    }
+
+    fn gen_bigint_range_inclusive(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt {
+        assert!(*lbound <= *ubound);
+        let len = into_magnitude(ubound - lbound) + 1u32;
+        lbound + BigInt::from(self.gen_biguint_below(&len))
+    }
 }
 
 /// The back-end implementing rand's `UniformSampler` for `BigUint`.
@@ -216,3 +290,102 @@ impl Distribution<BigInt> for RandomBits {
         rng.gen_bigint(self.bits)
     }
 }
+
+/// Adapts a random number generator from `rand` 0.8, or anything else implementing
+/// `rand_core` 0.6, for use with `RandBigInt` and the distributions here.
+///
+/// Wrap the generator, or a mutable reference to it, before calling the `RandBigInt`
+/// methods, as in `RngCompat08(rand::thread_rng()).gen_biguint(1000)`.
+#[cfg(feature = "rand-08")]
+#[derive(Clone, Debug)]
+pub struct RngCompat08<R>(pub R);
+
+#[cfg(feature = "rand-08")]
+impl<R: rand_core_06::RngCore> RngCore for RngCompat08<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest).map_err(|_| {
+            rand::Error::new(
+                rand::ErrorKind::Unavailable,
+                "random number generator failed",
+            )
+        })
+    }
+}
+
+/// Adapts a random number generator from `rand` 0.9, or anything else implementing
+/// `rand_core` 0.9, for use with `RandBigInt` and the distributions here.
+///
+/// Wrap the generator, or a mutable reference to it, before calling the `RandBigInt`
+/// methods, as in `RngCompat09(rand::rng()).gen_biguint(1000)`.
+#[cfg(feature = "rand-09")]
+#[derive(Clone, Debug)]
+pub struct RngCompat09<R>(pub R);
+
+#[cfg(feature = "rand-09")]
+impl<R: rand_core_09::RngCore> RngCore for RngCompat09<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gen_biguint_below_counted;
+    use rand::thread_rng;
+    use BigUint;
+
+    #[test]
+    fn test_rand_below_rejections() {
+        let mut rng = thread_rng();
+
+        // A power of two is drawn exactly, so nothing is ever rejected.
+        let pow2 = BigUint::from(1u32) << 100usize;
+        for _ in 0..100 {
+            let (n, rejections) = gen_biguint_below_counted(&mut rng, &pow2);
+            assert!(n < pow2);
+            assert_eq!(rejections, 0);
+        }
+
+        // Just over a power of two rejects almost half the draws, but never more on average.
+        let bound = &pow2 + 1u32;
+        let mut total = 0;
+        for _ in 0..1000 {
+            let (n, rejections) = gen_biguint_below_counted(&mut rng, &bound);
+            assert!(n < bound);
+            total += rejections;
+        }
+        assert!(total < 1500, "{} rejections", total);
+    }
+}
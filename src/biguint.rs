@@ -1,16 +1,17 @@
-#[allow(deprecated, unused_imports)]
-use std::ascii::AsciiExt;
 use std::borrow::Cow;
 use std::cmp;
 use std::cmp::Ordering::{self, Equal, Greater, Less};
+#[cfg(has_try_from)]
+use std::convert::TryFrom;
 use std::default::Default;
 use std::fmt;
-use std::iter::{Product, Sum};
+use std::iter::{Product, Rev, Sum};
 use std::mem;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
-    Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
+use std::slice;
 use std::str::{self, FromStr};
 use std::{f32, f64};
 use std::{u64, u8};
@@ -20,27 +21,42 @@ use serde;
 
 use integer::{Integer, Roots};
 use traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, FromPrimitive, Num, One, Pow,
-    ToPrimitive, Unsigned, Zero,
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    Float, FromPrimitive, MulAdd, MulAddAssign, Num, One, Pow, ToPrimitive, Unsigned, Zero,
 };
 
-use big_digit::{self, BigDigit};
+use big_digit::{self, BigDigit, DoubleBigDigit};
+use format::to_str_decimal;
 
 #[path = "algorithms.rs"]
 mod algorithms;
+#[cfg(has_const_generics)]
+#[path = "fixed.rs"]
+mod fixed;
+#[cfg(has_const_generics)]
+#[path = "heapless.rs"]
+mod heapless;
 #[path = "monty.rs"]
 mod monty;
 
 use self::algorithms::{__add2, __sub2rev, add2, sub2, sub2rev};
-use self::algorithms::{biguint_shl, biguint_shr};
-use self::algorithms::{cmp_slice, fls, ilog2};
-use self::algorithms::{div_rem, div_rem_digit, div_rem_ref, rem_digit};
-use self::algorithms::{mac_with_carry, mul3, scalar_mul};
+use self::algorithms::{biguint_shl, biguint_shl_combine, biguint_shr};
+use self::algorithms::{cmp_slice, fls, ilog2, is_multiple_of};
+use self::algorithms::{div_rem, div_rem_assign, div_rem_digit, div_rem_ref, rem_digit};
+use self::algorithms::{mac_with_carry, mul3, mul_add3, scalar_mul};
+#[cfg(has_const_generics)]
+pub use self::fixed::FixedBigUint;
+#[cfg(has_const_generics)]
+pub use self::heapless::HeaplessBigUint;
 use self::monty::monty_modpow;
+pub use self::monty::OddBigUint;
 
+use IsizePromotion;
 use UsizePromotion;
 
 use ParseBigIntError;
+#[cfg(has_try_from)]
+use TryFromBigIntError;
 
 #[cfg(feature = "quickcheck")]
 use quickcheck::{Arbitrary, Gen};
@@ -89,6 +105,79 @@ impl Ord for BigUint {
     }
 }
 
+// Compares with a primitive without converting it: a number too big to convert is
+// greater than any value of the primitive type.  These are inherent methods rather
+// than `PartialEq` and `PartialOrd` impls, which would break type inference for
+// existing comparisons like `n == Zero::zero()`.
+macro_rules! impl_scalar_cmp {
+    ($scalar:ty, $to_scalar:ident, $cmp:ident) => {
+        impl BigUint {
+            /// Compares `self` with a primitive integer, without allocating.
+            #[inline]
+            pub fn $cmp(&self, other: $scalar) -> Ordering {
+                match self.$to_scalar() {
+                    Some(n) => n.cmp(&other),
+                    None => Greater,
+                }
+            }
+        }
+    };
+}
+
+impl_scalar_cmp!(u8, to_u8, cmp_u8);
+impl_scalar_cmp!(u16, to_u16, cmp_u16);
+impl_scalar_cmp!(u32, to_u32, cmp_u32);
+impl_scalar_cmp!(u64, to_u64, cmp_u64);
+impl_scalar_cmp!(usize, to_usize, cmp_usize);
+#[cfg(has_i128)]
+impl_scalar_cmp!(u128, to_u128, cmp_u128);
+impl_scalar_cmp!(i8, to_i8, cmp_i8);
+impl_scalar_cmp!(i16, to_i16, cmp_i16);
+impl_scalar_cmp!(i32, to_i32, cmp_i32);
+impl_scalar_cmp!(i64, to_i64, cmp_i64);
+impl_scalar_cmp!(isize, to_isize, cmp_isize);
+#[cfg(has_i128)]
+impl_scalar_cmp!(i128, to_i128, cmp_i128);
+
+/// Compares `n` exactly with `x`, or returns `None` if `x` is NaN.
+///
+/// The integer part of `x` converts to a `BigUint` without rounding, and only breaks a
+/// tie with the fractional part.
+pub fn cmp_f64(n: &BigUint, x: f64) -> Option<Ordering> {
+    if x.is_nan() {
+        return None;
+    }
+    if x < 0.0 {
+        return Some(Greater);
+    }
+    if x.is_infinite() {
+        return Some(Less);
+    }
+    // every finite float is below `2^MAX_EXP`
+    if n.bit_len() > f64::MAX_EXP as u64 {
+        return Some(Greater);
+    }
+    let int = x.trunc();
+    Some(match n.cmp(&BigUint::from_f64(int).unwrap()) {
+        Equal if x > int => Less,
+        ord => ord,
+    })
+}
+
+impl BigUint {
+    /// Compares `self` exactly with a float, or returns `None` if `other` is NaN.
+    #[inline]
+    pub fn partial_cmp_f64(&self, other: f64) -> Option<Ordering> {
+        cmp_f64(self, other)
+    }
+
+    /// Compares `self` exactly with a float, or returns `None` if `other` is NaN.
+    #[inline]
+    pub fn partial_cmp_f32(&self, other: f32) -> Option<Ordering> {
+        cmp_f64(self, f64::from(other))
+    }
+}
+
 impl Default for BigUint {
     #[inline]
     fn default() -> BigUint {
@@ -96,9 +185,25 @@ impl Default for BigUint {
     }
 }
 
+/// Formats the number in decimal.
+///
+/// A precision limits the output to that many significant digits: a number with more
+/// digits is rounded half away from zero and written in scientific notation, so the
+/// exponent shows that digits were dropped.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigUint;
+///
+/// let n = BigUint::from(1234567u32);
+/// assert_eq!(format!("{}", n), "1234567");
+/// assert_eq!(format!("{:.3}", n), "1.23e6");
+/// assert_eq!(format!("{:.7}", n), "1234567");
+/// ```
 impl fmt::Display for BigUint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad_integral(true, "", &self.to_str_radix(10))
+        f.pad_integral(true, "", &to_str_decimal(self, f.precision()))
     }
 }
 
@@ -110,9 +215,7 @@ impl fmt::LowerHex for BigUint {
 
 impl fmt::UpperHex for BigUint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut s = self.to_str_radix(16);
-        s.make_ascii_uppercase();
-        f.pad_integral(true, "0x", &s)
+        f.pad_integral(true, "0x", &self.to_str_radix_upper(16))
     }
 }
 
@@ -240,8 +343,12 @@ impl Num for BigUint {
     type FromStrRadixErr = ParseBigIntError;
 
     /// Creates and initializes a `BigUint`.
+    ///
+    /// Up to radix 36, letters are digits from ten regardless of case.  Above that, the
+    /// digits are `0-9A-Za-z` as in GMP, so case matters.
     fn from_str_radix(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
-        assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
+        assert!(2 <= radix && radix <= 62, "The radix must be within 2...62");
+        let input = s;
         let mut s = s;
         if s.starts_with('+') {
             let tail = &s[1..];
@@ -249,6 +356,7 @@ impl Num for BigUint {
                 s = tail
             }
         }
+        let start = input.len() - s.len();
 
         if s.is_empty() {
             return Err(ParseBigIntError::empty());
@@ -256,24 +364,18 @@ impl Num for BigUint {
 
         if s.starts_with('_') {
             // Must lead with a real digit!
-            return Err(ParseBigIntError::invalid());
+            return Err(ParseBigIntError::invalid_at(input, start));
         }
 
         // First normalize all characters to plain digit values
         let mut v = Vec::with_capacity(s.len());
-        for b in s.bytes() {
-            #[allow(unknown_lints, ellipsis_inclusive_range_patterns)]
-            let d = match b {
-                b'0'...b'9' => b - b'0',
-                b'a'...b'z' => b - b'a' + 10,
-                b'A'...b'Z' => b - b'A' + 10,
-                b'_' => continue,
-                _ => u8::MAX,
-            };
-            if d < radix as u8 {
-                v.push(d);
-            } else {
-                return Err(ParseBigIntError::invalid());
+        for (i, b) in s.bytes().enumerate() {
+            if b == b'_' {
+                continue;
+            }
+            match digit_value(b, radix) {
+                Some(d) => v.push(d),
+                None => return Err(ParseBigIntError::invalid_at(input, start + i)),
             }
         }
 
@@ -383,6 +485,273 @@ impl<'a> BitXorAssign<&'a BigUint> for BigUint {
     }
 }
 
+promote_unsigned_scalars!(impl BitAnd for BigUint, bitand);
+promote_unsigned_scalars_assign!(impl BitAndAssign for BigUint, bitand_assign);
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u32> for BigUint, bitand);
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u64> for BigUint, bitand);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_ref_val!(impl BitAnd<u128> for BigUint, bitand);
+
+impl<'a> BitAnd<u32> for &'a BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: u32) -> BigUint {
+        // only the lowest digit can survive the mask
+        let lo = self.data.first().cloned().unwrap_or(0);
+        From::from(lo & other as BigDigit)
+    }
+}
+impl BitAndAssign<u32> for BigUint {
+    #[inline]
+    fn bitand_assign(&mut self, other: u32) {
+        self.data.truncate(1);
+        if let Some(lo) = self.data.first_mut() {
+            *lo &= other as BigDigit;
+        }
+        self.normalize();
+    }
+}
+
+impl<'a> BitAnd<&'a BigUint> for u32 {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: &BigUint) -> BigUint {
+        other & self
+    }
+}
+
+impl<'a> BitAnd<u64> for &'a BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: u64) -> BigUint {
+        let lo = self.data.get(0).cloned().unwrap_or(0);
+        let hi = self.data.get(1).cloned().unwrap_or(0);
+        From::from(big_digit::to_doublebigdigit(hi, lo) & other)
+    }
+}
+impl BitAndAssign<u64> for BigUint {
+    #[inline]
+    fn bitand_assign(&mut self, other: u64) {
+        let (hi, lo) = big_digit::from_doublebigdigit(other);
+        self.data.truncate(2);
+        for (ai, bi) in self.data.iter_mut().zip(&[lo, hi]) {
+            *ai &= bi;
+        }
+        self.normalize();
+    }
+}
+
+impl<'a> BitAnd<&'a BigUint> for u64 {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: &BigUint) -> BigUint {
+        other & self
+    }
+}
+
+#[cfg(has_i128)]
+impl<'a> BitAnd<u128> for &'a BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: u128) -> BigUint {
+        let digit = |i: usize| self.data.get(i).cloned().unwrap_or(0);
+        From::from(u32_to_u128(digit(3), digit(2), digit(1), digit(0)) & other)
+    }
+}
+#[cfg(has_i128)]
+impl BitAndAssign<u128> for BigUint {
+    #[inline]
+    fn bitand_assign(&mut self, other: u128) {
+        let (a, b, c, d) = u32_from_u128(other);
+        self.data.truncate(4);
+        for (ai, bi) in self.data.iter_mut().zip(&[d, c, b, a]) {
+            *ai &= bi;
+        }
+        self.normalize();
+    }
+}
+
+#[cfg(has_i128)]
+impl<'a> BitAnd<&'a BigUint> for u128 {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitand(self, other: &BigUint) -> BigUint {
+        other & self
+    }
+}
+
+promote_unsigned_scalars!(impl BitOr for BigUint, bitor);
+promote_unsigned_scalars_assign!(impl BitOrAssign for BigUint, bitor_assign);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u32> for BigUint, bitor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u64> for BigUint, bitor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitOr<u128> for BigUint, bitor);
+
+impl BitOr<u32> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitor(mut self, other: u32) -> BigUint {
+        self |= other;
+        self
+    }
+}
+impl BitOrAssign<u32> for BigUint {
+    #[inline]
+    fn bitor_assign(&mut self, other: u32) {
+        if other != 0 {
+            match self.data.first_mut() {
+                Some(lo) => *lo |= other as BigDigit,
+                None => self.data.push(other as BigDigit),
+            }
+        }
+    }
+}
+
+impl BitOr<u64> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitor(mut self, other: u64) -> BigUint {
+        self |= other;
+        self
+    }
+}
+impl BitOrAssign<u64> for BigUint {
+    #[inline]
+    fn bitor_assign(&mut self, other: u64) {
+        let (hi, lo) = big_digit::from_doublebigdigit(other);
+        if hi == 0 {
+            *self |= lo;
+        } else {
+            while self.data.len() < 2 {
+                self.data.push(0);
+            }
+            self.data[0] |= lo;
+            self.data[1] |= hi;
+        }
+    }
+}
+
+#[cfg(has_i128)]
+impl BitOr<u128> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitor(mut self, other: u128) -> BigUint {
+        self |= other;
+        self
+    }
+}
+#[cfg(has_i128)]
+impl BitOrAssign<u128> for BigUint {
+    #[inline]
+    fn bitor_assign(&mut self, other: u128) {
+        if other <= u128::from(u64::max_value()) {
+            *self |= other as u64;
+        } else {
+            let (a, b, c, d) = u32_from_u128(other);
+            while self.data.len() < 4 {
+                self.data.push(0);
+            }
+            for (ai, bi) in self.data.iter_mut().zip(&[d, c, b, a]) {
+                *ai |= bi;
+            }
+            self.normalize();
+        }
+    }
+}
+
+promote_unsigned_scalars!(impl BitXor for BigUint, bitxor);
+promote_unsigned_scalars_assign!(impl BitXorAssign for BigUint, bitxor_assign);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u32> for BigUint, bitxor);
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u64> for BigUint, bitxor);
+#[cfg(has_i128)]
+forward_all_scalar_binop_to_val_val_commutative!(impl BitXor<u128> for BigUint, bitxor);
+
+impl BitXor<u32> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitxor(mut self, other: u32) -> BigUint {
+        self ^= other;
+        self
+    }
+}
+impl BitXorAssign<u32> for BigUint {
+    #[inline]
+    fn bitxor_assign(&mut self, other: u32) {
+        if other != 0 {
+            match self.data.first_mut() {
+                Some(lo) => *lo ^= other as BigDigit,
+                None => self.data.push(other as BigDigit),
+            }
+            self.normalize();
+        }
+    }
+}
+
+impl BitXor<u64> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitxor(mut self, other: u64) -> BigUint {
+        self ^= other;
+        self
+    }
+}
+impl BitXorAssign<u64> for BigUint {
+    #[inline]
+    fn bitxor_assign(&mut self, other: u64) {
+        let (hi, lo) = big_digit::from_doublebigdigit(other);
+        if hi == 0 {
+            *self ^= lo;
+        } else {
+            while self.data.len() < 2 {
+                self.data.push(0);
+            }
+            self.data[0] ^= lo;
+            self.data[1] ^= hi;
+            self.normalize();
+        }
+    }
+}
+
+#[cfg(has_i128)]
+impl BitXor<u128> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn bitxor(mut self, other: u128) -> BigUint {
+        self ^= other;
+        self
+    }
+}
+#[cfg(has_i128)]
+impl BitXorAssign<u128> for BigUint {
+    #[inline]
+    fn bitxor_assign(&mut self, other: u128) {
+        if other <= u128::from(u64::max_value()) {
+            *self ^= other as u64;
+        } else {
+            let (a, b, c, d) = u32_from_u128(other);
+            while self.data.len() < 4 {
+                self.data.push(0);
+            }
+            for (ai, bi) in self.data.iter_mut().zip(&[d, c, b, a]) {
+                *ai ^= bi;
+            }
+            self.normalize();
+        }
+    }
+}
+
 impl Shl<usize> for BigUint {
     type Output = BigUint;
 
@@ -433,6 +802,141 @@ impl ShrAssign<usize> for BigUint {
     }
 }
 
+/// Converts a left shift amount to `usize`, or `None` if it is too large.
+///
+/// # Panics
+///
+/// Panics if the amount is negative.
+pub fn shl_amount<T: ToPrimitive + Zero + PartialOrd>(rhs: T) -> Option<usize> {
+    assert!(rhs >= T::zero(), "attempt to shift left with negative");
+    rhs.to_usize()
+}
+
+/// Converts a right shift amount to `usize`, or `None` if it is too large.
+///
+/// # Panics
+///
+/// Panics if the amount is negative.
+pub fn shr_amount<T: ToPrimitive + Zero + PartialOrd>(rhs: T) -> Option<usize> {
+    assert!(rhs >= T::zero(), "attempt to shift right with negative");
+    rhs.to_usize()
+}
+
+fn biguint_shl_by(n: Cow<BigUint>, rhs: Option<usize>) -> BigUint {
+    match rhs {
+        Some(rhs) => biguint_shl(n, rhs),
+        None if n.is_zero() => n.into_owned(),
+        None => panic!("capacity overflow"),
+    }
+}
+
+fn biguint_shr_by(n: Cow<BigUint>, rhs: Option<usize>) -> BigUint {
+    match rhs {
+        Some(rhs) => biguint_shr(n, rhs),
+        None => BigUint::zero(),
+    }
+}
+
+// Shifts by the other primitive integers are named methods rather than operators, so
+// that an unsuffixed literal amount still picks `usize`.  A negative amount panics, like
+// a primitive shift overflow, and one too large for `usize` shifts out every bit to the
+// right, while shifting any nonzero value that far left could never fit in memory.
+macro_rules! impl_shift_methods {
+    ($($scalar:ty: $shl:ident, $shr:ident;)*) => {
+        impl BigUint {$(
+            /// Returns `self << rhs` for an amount of a primitive type other than `usize`.
+            ///
+            /// Panics if the amount is negative, or if a nonzero value would not fit in
+            /// memory.
+            #[inline]
+            pub fn $shl(&self, rhs: $scalar) -> BigUint {
+                biguint_shl_by(Cow::Borrowed(self), shl_amount(rhs))
+            }
+
+            /// Returns `self >> rhs` for an amount of a primitive type other than `usize`.
+            ///
+            /// Panics if the amount is negative.  An amount too large for `usize` leaves
+            /// zero.
+            #[inline]
+            pub fn $shr(&self, rhs: $scalar) -> BigUint {
+                biguint_shr_by(Cow::Borrowed(self), shr_amount(rhs))
+            }
+        )*}
+    };
+}
+
+impl_shift_methods! {
+    u8: shl_u8, shr_u8;
+    u16: shl_u16, shr_u16;
+    u32: shl_u32, shr_u32;
+    u64: shl_u64, shr_u64;
+    i8: shl_i8, shr_i8;
+    i16: shl_i16, shr_i16;
+    i32: shl_i32, shr_i32;
+    i64: shl_i64, shr_i64;
+    isize: shl_isize, shr_isize;
+}
+#[cfg(has_i128)]
+impl_shift_methods! {
+    u128: shl_u128, shr_u128;
+    i128: shl_i128, shr_i128;
+}
+
+// Shifts by a `BigUint` amount, which is converted to `usize` like a primitive one.
+macro_rules! impl_shift_by_biguint {
+    (impl $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident, $by:ident) => {
+        impl<'b> $imp<&'b BigUint> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, rhs: &BigUint) -> BigUint {
+                $by(Cow::Owned(self), rhs.to_usize())
+            }
+        }
+        impl<'a, 'b> $imp<&'b BigUint> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, rhs: &BigUint) -> BigUint {
+                $by(Cow::Borrowed(self), rhs.to_usize())
+            }
+        }
+        impl $imp<BigUint> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, rhs: BigUint) -> BigUint {
+                $imp::$method(self, &rhs)
+            }
+        }
+        impl<'a> $imp<BigUint> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, rhs: BigUint) -> BigUint {
+                $imp::$method(self, &rhs)
+            }
+        }
+
+        impl<'b> $imp_assign<&'b BigUint> for BigUint {
+            #[inline]
+            fn $method_assign(&mut self, rhs: &BigUint) {
+                let n = mem::replace(self, BigUint::zero());
+                *self = $imp::$method(n, rhs);
+            }
+        }
+        impl $imp_assign<BigUint> for BigUint {
+            #[inline]
+            fn $method_assign(&mut self, rhs: BigUint) {
+                self.$method_assign(&rhs);
+            }
+        }
+    };
+}
+
+impl_shift_by_biguint!(impl Shl, shl, ShlAssign, shl_assign, biguint_shl_by);
+impl_shift_by_biguint!(impl Shr, shr, ShrAssign, shr_assign, biguint_shr_by);
+
 impl Zero for BigUint {
     #[inline]
     fn zero() -> BigUint {
@@ -840,6 +1344,28 @@ impl<'a> MulAssign<&'a BigUint> for BigUint {
     }
 }
 
+/// Return `x * y + z`, accumulating the product into the storage of `z`.
+///
+/// This is in a private module, pseudo pub(crate)
+#[inline]
+pub fn fused_mul_add(x: &BigUint, y: &BigUint, z: BigUint) -> BigUint {
+    mul_add3(&x.data[..], &y.data[..], z)
+}
+
+impl_mul_add!(BigUint, fused_mul_add);
+
+/// Convert a bit count to `usize`, panicking rather than wrapping if it doesn't fit.
+///
+/// This is in a private module, pseudo pub(crate)
+#[inline]
+pub fn bits_to_usize(bits: u64) -> usize {
+    assert!(
+        bits <= usize::max_value() as u64,
+        "bit count overflows usize"
+    );
+    bits as usize
+}
+
 promote_unsigned_scalars!(impl Mul for BigUint, mul);
 promote_unsigned_scalars_assign!(impl MulAssign for BigUint, mul_assign);
 forward_all_scalar_binop_to_val_val_commutative!(impl Mul<u32> for BigUint, mul);
@@ -944,7 +1470,7 @@ impl<'a, 'b> Div<&'b BigUint> for &'a BigUint {
 impl<'a> DivAssign<&'a BigUint> for BigUint {
     #[inline]
     fn div_assign(&mut self, other: &'a BigUint) {
-        *self = &*self / other;
+        div_rem_assign(self, other);
     }
 }
 
@@ -1081,7 +1607,7 @@ impl<'a, 'b> Rem<&'b BigUint> for &'a BigUint {
 impl<'a> RemAssign<&'a BigUint> for BigUint {
     #[inline]
     fn rem_assign(&mut self, other: &BigUint) {
-        *self = &*self % other;
+        *self = div_rem_assign(self, other);
     }
 }
 
@@ -1203,24 +1729,92 @@ impl Rem<BigUint> for u128 {
     }
 }
 
-impl Neg for BigUint {
-    type Output = BigUint;
+// Signed scalars are supported where the result can be unsigned: adding or subtracting one
+// panics if the result would be negative, like subtracting a larger `BigUint`, and the
+// remainder takes the sign of the dividend, so only its magnitude matters.
+macro_rules! impl_signed_scalar_ops {
+    ($scalar:ty, $unsigned:ty) => {
+        forward_all_scalar_binop_to_val_val_commutative!(impl Add<$scalar> for BigUint, add);
+        forward_all_scalar_binop_rhs_to_val_val!(impl Sub<$scalar> for BigUint, sub);
+        forward_all_scalar_binop_rhs_to_val_val!(impl Rem<$scalar> for BigUint, rem);
 
-    #[inline]
-    fn neg(self) -> BigUint {
-        panic!()
-    }
-}
+        impl Add<$scalar> for BigUint {
+            type Output = BigUint;
 
-impl<'a> Neg for &'a BigUint {
-    type Output = BigUint;
+            #[inline]
+            fn add(self, other: $scalar) -> BigUint {
+                if other >= 0 {
+                    self + other as $unsigned
+                } else {
+                    self - other.wrapping_abs() as $unsigned
+                }
+            }
+        }
+        impl AddAssign<$scalar> for BigUint {
+            #[inline]
+            fn add_assign(&mut self, other: $scalar) {
+                if other >= 0 {
+                    *self += other as $unsigned;
+                } else {
+                    *self -= other.wrapping_abs() as $unsigned;
+                }
+            }
+        }
 
-    #[inline]
-    fn neg(self) -> BigUint {
-        panic!()
-    }
+        impl Sub<$scalar> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn sub(self, other: $scalar) -> BigUint {
+                if other >= 0 {
+                    self - other as $unsigned
+                } else {
+                    self + other.wrapping_abs() as $unsigned
+                }
+            }
+        }
+        impl SubAssign<$scalar> for BigUint {
+            #[inline]
+            fn sub_assign(&mut self, other: $scalar) {
+                if other >= 0 {
+                    *self -= other as $unsigned;
+                } else {
+                    *self += other.wrapping_abs() as $unsigned;
+                }
+            }
+        }
+
+        impl Rem<$scalar> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn rem(self, other: $scalar) -> BigUint {
+                self % other.wrapping_abs() as $unsigned
+            }
+        }
+        impl RemAssign<$scalar> for BigUint {
+            #[inline]
+            fn rem_assign(&mut self, other: $scalar) {
+                *self %= other.wrapping_abs() as $unsigned;
+            }
+        }
+    };
 }
 
+impl_signed_scalar_ops!(i32, u32);
+impl_signed_scalar_ops!(i64, u64);
+#[cfg(has_i128)]
+impl_signed_scalar_ops!(i128, u128);
+
+promote_signed_scalars!(impl Add for BigUint, add);
+promote_signed_scalars_assign!(impl AddAssign for BigUint, add_assign);
+promote_scalars_rhs!(impl Sub<i32> for BigUint, sub, i8, i16);
+promote_scalars_rhs!(impl Sub<IsizePromotion> for BigUint, sub, isize);
+promote_signed_scalars_assign!(impl SubAssign for BigUint, sub_assign);
+promote_scalars_rhs!(impl Rem<i32> for BigUint, rem, i8, i16);
+promote_scalars_rhs!(impl Rem<IsizePromotion> for BigUint, rem, isize);
+promote_signed_scalars_assign!(impl RemAssign for BigUint, rem_assign);
+
 impl CheckedAdd for BigUint {
     #[inline]
     fn checked_add(&self, v: &BigUint) -> Option<BigUint> {
@@ -1256,6 +1850,54 @@ impl CheckedDiv for BigUint {
     }
 }
 
+impl CheckedRem for BigUint {
+    #[inline]
+    fn checked_rem(&self, v: &BigUint) -> Option<BigUint> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(self.rem(v))
+    }
+}
+
+impl CheckedNeg for BigUint {
+    /// Returns `Some(0)` for zero, which is its own negation, and `None` otherwise.
+    #[inline]
+    fn checked_neg(&self) -> Option<BigUint> {
+        if self.is_zero() {
+            Some(BigUint::zero())
+        } else {
+            None
+        }
+    }
+}
+
+/// Unsigned division already rounds toward negative infinity, so the Euclidean forms
+/// are the same as `/` and `%`.
+impl Euclid for BigUint {
+    #[inline]
+    fn div_euclid(&self, v: &BigUint) -> BigUint {
+        self / v
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &BigUint) -> BigUint {
+        self % v
+    }
+}
+
+impl CheckedEuclid for BigUint {
+    #[inline]
+    fn checked_div_euclid(&self, v: &BigUint) -> Option<BigUint> {
+        self.checked_div(v)
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &BigUint) -> Option<BigUint> {
+        self.checked_rem(v)
+    }
+}
+
 impl Integer for BigUint {
     #[inline]
     fn div_rem(&self, other: &BigUint) -> (BigUint, BigUint) {
@@ -1334,9 +1976,11 @@ impl Integer for BigUint {
     }
 
     /// Returns `true` if the number is a multiple of `other`.
+    ///
+    /// Only zero is a multiple of zero.
     #[inline]
     fn is_multiple_of(&self, other: &BigUint) -> bool {
-        (self % other).is_zero()
+        is_multiple_of(self, other)
     }
 
     /// Returns `true` if the number is divisible by `2`.
@@ -1357,7 +2001,7 @@ impl Integer for BigUint {
 }
 
 #[inline]
-fn fixpoint<F>(mut x: BigUint, max_bits: usize, f: F) -> BigUint
+fn fixpoint<F>(mut x: BigUint, max_bits: u64, f: F) -> BigUint
 where
     F: Fn(&BigUint) -> BigUint,
 {
@@ -1369,8 +2013,8 @@ where
         // Sometimes an increase will go way too far, especially with large
         // powers, and then take a long time to walk back.  We know an upper
         // bound based on bit size, so saturate on that.
-        x = if xn.bits() > max_bits {
-            BigUint::one() << max_bits
+        x = if xn.bit_len() > max_bits {
+            BigUint::one().shl_u64(max_bits)
         } else {
             xn
         };
@@ -1407,8 +2051,8 @@ impl Roots for BigUint {
         }
 
         // The root of non-zero values less than 2ⁿ can only be 1.
-        let bits = self.bits();
-        if bits <= n as usize {
+        let bits = self.bit_len();
+        if bits <= u64::from(n) {
             return BigUint::one();
         }
 
@@ -1417,7 +2061,7 @@ impl Roots for BigUint {
             return x.nth_root(n).into();
         }
 
-        let max_bits = bits / n as usize + 1;
+        let max_bits = bits / u64::from(n) + 1;
 
         let guess = if let Some(f) = self.to_f64() {
             // We fit in `f64` (lossy), so get a better initial guess from that.
@@ -1425,14 +2069,14 @@ impl Roots for BigUint {
         } else {
             // Try to guess by scaling down such that it does fit in `f64`.
             // With some (x * 2ⁿᵏ), its nth root ≈ (ⁿ√x * 2ᵏ)
-            let nsz = n as usize;
-            let extra_bits = bits - (f64::MAX_EXP as usize - 1);
+            let nsz = u64::from(n);
+            let extra_bits = bits - (f64::MAX_EXP as u64 - 1);
             let root_scale = (extra_bits + (nsz - 1)) / nsz;
             let scale = root_scale * nsz;
             if scale < bits && bits - scale > nsz {
-                (self >> scale).nth_root(n) << root_scale
+                self.shr_u64(scale).nth_root(n).shl_u64(root_scale)
             } else {
-                BigUint::one() << max_bits
+                BigUint::one().shl_u64(max_bits)
             }
         };
 
@@ -1456,8 +2100,8 @@ impl Roots for BigUint {
             return x.sqrt().into();
         }
 
-        let bits = self.bits();
-        let max_bits = bits / 2 as usize + 1;
+        let bits = self.bit_len();
+        let max_bits = bits / 2 + 1;
 
         let guess = if let Some(f) = self.to_f64() {
             // We fit in `f64` (lossy), so get a better initial guess from that.
@@ -1465,10 +2109,10 @@ impl Roots for BigUint {
         } else {
             // Try to guess by scaling down such that it does fit in `f64`.
             // With some (x * 2²ᵏ), its sqrt ≈ (√x * 2ᵏ)
-            let extra_bits = bits - (f64::MAX_EXP as usize - 1);
+            let extra_bits = bits - (f64::MAX_EXP as u64 - 1);
             let root_scale = (extra_bits + 1) / 2;
             let scale = root_scale * 2;
-            (self >> scale).sqrt() << root_scale
+            self.shr_u64(scale).sqrt().shl_u64(root_scale)
         };
 
         fixpoint(guess, max_bits, move |s| {
@@ -1488,8 +2132,8 @@ impl Roots for BigUint {
             return x.cbrt().into();
         }
 
-        let bits = self.bits();
-        let max_bits = bits / 3 as usize + 1;
+        let bits = self.bit_len();
+        let max_bits = bits / 3 + 1;
 
         let guess = if let Some(f) = self.to_f64() {
             // We fit in `f64` (lossy), so get a better initial guess from that.
@@ -1497,10 +2141,10 @@ impl Roots for BigUint {
         } else {
             // Try to guess by scaling down such that it does fit in `f64`.
             // With some (x * 2³ᵏ), its cbrt ≈ (∛x * 2ᵏ)
-            let extra_bits = bits - (f64::MAX_EXP as usize - 1);
+            let extra_bits = bits - (f64::MAX_EXP as u64 - 1);
             let root_scale = (extra_bits + 2) / 3;
             let scale = root_scale * 3;
-            (self >> scale).cbrt() << root_scale
+            self.shr_u64(scale).cbrt().shl_u64(root_scale)
         };
 
         fixpoint(guess, max_bits, move |s| {
@@ -1516,12 +2160,12 @@ fn high_bits_to_u64(v: &BigUint) -> u64 {
         0 => 0,
         1 => u64::from(v.data[0]),
         _ => {
-            let mut bits = v.bits();
+            let mut bits = v.bit_len();
             let mut ret = 0u64;
             let mut ret_bits = 0;
 
             for d in v.data.iter().rev() {
-                let digit_bits = (bits - 1) % big_digit::BITS + 1;
+                let digit_bits = (bits - 1) % big_digit::BITS as u64 + 1;
                 let bits_want = cmp::min(64 - ret_bits, digit_bits);
 
                 if bits_want != 64 {
@@ -1591,9 +2235,9 @@ impl ToPrimitive for BigUint {
     #[inline]
     fn to_f32(&self) -> Option<f32> {
         let mantissa = high_bits_to_u64(self);
-        let exponent = self.bits() - fls(mantissa);
+        let exponent = self.bit_len() - fls(mantissa) as u64;
 
-        if exponent > f32::MAX_EXP as usize {
+        if exponent > f32::MAX_EXP as u64 {
             None
         } else {
             let ret = (mantissa as f32) * 2.0f32.powi(exponent as i32);
@@ -1608,9 +2252,9 @@ impl ToPrimitive for BigUint {
     #[inline]
     fn to_f64(&self) -> Option<f64> {
         let mantissa = high_bits_to_u64(self);
-        let exponent = self.bits() - fls(mantissa);
+        let exponent = self.bit_len() - fls(mantissa) as u64;
 
-        if exponent > f64::MAX_EXP as usize {
+        if exponent > f64::MAX_EXP as u64 {
             None
         } else {
             let ret = (mantissa as f64) * 2.0f64.powi(exponent as i32);
@@ -1685,6 +2329,55 @@ impl FromPrimitive for BigUint {
     }
 }
 
+#[cfg(has_try_from)]
+macro_rules! impl_try_from_biguint {
+    ($T:ty, $to_ty:path) => {
+        impl<'a> TryFrom<&'a BigUint> for $T {
+            type Error = TryFromBigIntError<()>;
+
+            #[inline]
+            fn try_from(value: &BigUint) -> Result<$T, TryFromBigIntError<()>> {
+                $to_ty(value).ok_or(TryFromBigIntError::new(()))
+            }
+        }
+
+        impl TryFrom<BigUint> for $T {
+            type Error = TryFromBigIntError<BigUint>;
+
+            #[inline]
+            fn try_from(value: BigUint) -> Result<$T, TryFromBigIntError<BigUint>> {
+                <$T>::try_from(&value).map_err(|_| TryFromBigIntError::new(value))
+            }
+        }
+    };
+}
+
+#[cfg(has_try_from)]
+impl_try_from_biguint!(u8, ToPrimitive::to_u8);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(u16, ToPrimitive::to_u16);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(u32, ToPrimitive::to_u32);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(u64, ToPrimitive::to_u64);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(usize, ToPrimitive::to_usize);
+#[cfg(all(has_try_from, has_i128))]
+impl_try_from_biguint!(u128, ToPrimitive::to_u128);
+
+#[cfg(has_try_from)]
+impl_try_from_biguint!(i8, ToPrimitive::to_i8);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(i16, ToPrimitive::to_i16);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(i32, ToPrimitive::to_i32);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(i64, ToPrimitive::to_i64);
+#[cfg(has_try_from)]
+impl_try_from_biguint!(isize, ToPrimitive::to_isize);
+#[cfg(all(has_try_from, has_i128))]
+impl_try_from_biguint!(i128, ToPrimitive::to_i128);
+
 impl From<u64> for BigUint {
     #[inline]
     fn from(mut n: u64) -> Self {
@@ -1731,6 +2424,20 @@ impl_biguint_from_uint!(u16);
 impl_biguint_from_uint!(u32);
 impl_biguint_from_uint!(usize);
 
+macro_rules! impl_biguint_from_byte_array {
+    ($($N:expr),*) => {$(
+        impl From<[u8; $N]> for BigUint {
+            /// Creates a `BigUint` from big-endian bytes, such as a hash digest.
+            #[inline]
+            fn from(bytes: [u8; $N]) -> Self {
+                BigUint::from_bytes_be(&bytes)
+            }
+        }
+    )*};
+}
+
+impl_biguint_from_byte_array!(16, 32, 48, 64);
+
 /// A generic trait for converting a value to a `BigUint`.
 pub trait ToBigUint {
     /// Converts the value of `self` to a `BigUint`.
@@ -1781,7 +2488,7 @@ fn to_bitwise_digits_le(u: &BigUint, bits: usize) -> Vec<u8> {
     let last_i = u.data.len() - 1;
     let mask: BigDigit = (1 << bits) - 1;
     let digits_per_big_digit = big_digit::BITS / bits;
-    let digits = (u.bits() + bits - 1) / bits;
+    let digits = bits_to_usize((u.bit_len() + bits as u64 - 1) / bits as u64);
     let mut res = Vec::with_capacity(digits);
 
     for mut r in u.data[..last_i].iter().cloned() {
@@ -1805,7 +2512,7 @@ fn to_inexact_bitwise_digits_le(u: &BigUint, bits: usize) -> Vec<u8> {
     debug_assert!(!u.is_zero() && bits <= 8 && big_digit::BITS % bits != 0);
 
     let mask: BigDigit = (1 << bits) - 1;
-    let digits = (u.bits() + bits - 1) / bits;
+    let digits = bits_to_usize((u.bit_len() + bits as u64 - 1) / bits as u64);
     let mut res = Vec::with_capacity(digits);
 
     let mut r = 0;
@@ -1845,7 +2552,7 @@ fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
     debug_assert!(!u.is_zero() && !radix.is_power_of_two());
 
     // Estimate how big the result will be, so we can pre-allocate it.
-    let radix_digits = ((u.bits() as f64) / f64::from(radix).log2()).ceil();
+    let radix_digits = ((u.bit_len() as f64) / f64::from(radix).log2()).ceil();
     let mut res = Vec::with_capacity(radix_digits as usize);
     let mut digits = u.clone();
 
@@ -1890,8 +2597,176 @@ pub fn to_radix_le(u: &BigUint, radix: u32) -> Vec<u8> {
     }
 }
 
-pub fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
-    assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
+/// Returns the value of an ASCII digit in `radix`, if it is one.
+///
+/// Up to radix 36, letters are digits from ten regardless of case.  Above that, the
+/// digits are `0-9A-Za-z`.
+pub fn digit_value(b: u8, radix: u32) -> Option<u8> {
+    let lower = if radix <= 36 { 10 } else { 36 };
+    #[allow(unknown_lints, ellipsis_inclusive_range_patterns)]
+    let d = match b {
+        b'0'...b'9' => b - b'0',
+        b'a'...b'z' => b - b'a' + lower,
+        b'A'...b'Z' => b - b'A' + 10,
+        _ => return None,
+    };
+    if d < radix as u8 {
+        Some(d)
+    } else {
+        None
+    }
+}
+
+/// Parses an unsigned number with an optional `0x`, `0o`, or `0b` radix prefix.
+pub fn from_str_prefixed_unsigned(s: &str) -> Result<BigUint, ParseBigIntError> {
+    let (radix, digits) = if s.starts_with("0x") || s.starts_with("0X") {
+        (16, &s[2..])
+    } else if s.starts_with("0o") || s.starts_with("0O") {
+        (8, &s[2..])
+    } else if s.starts_with("0b") || s.starts_with("0B") {
+        (2, &s[2..])
+    } else {
+        (10, s)
+    };
+    let start = s.len() - digits.len();
+    // `from_str_radix` would accept a sign here
+    if digits.starts_with('+') || digits.starts_with('-') {
+        return Err(ParseBigIntError::invalid_at(s, start));
+    }
+    BigUint::from_str_radix(digits, radix).map_err(|e| e.offset(start))
+}
+
+/// Returns `s` without any of the `separators`.
+pub fn remove_separators(s: &str, separators: &[char]) -> String {
+    s.chars().filter(|c| !separators.contains(c)).collect()
+}
+
+/// Moves the position of an error in `s` without its `separators` back to `s` itself.
+pub fn restore_separators(e: ParseBigIntError, s: &str, separators: &[char]) -> ParseBigIntError {
+    let position = match e.position() {
+        Some(p) => p,
+        None => return e,
+    };
+    let mut len = 0;
+    for (i, c) in s.char_indices() {
+        if separators.contains(&c) {
+            continue;
+        }
+        if len == position {
+            return ParseBigIntError::invalid_at(s, i);
+        }
+        len += c.len_utf8();
+    }
+    ParseBigIntError::invalid_at(s, s.len())
+}
+
+/// Checks that `part`, at byte `start` of `s`, is a non-empty string of decimal digits.
+fn check_decimal(s: &str, part: &str, start: usize) -> Result<(), ParseBigIntError> {
+    match part.bytes().position(|b| b < b'0' || b > b'9') {
+        Some(i) => Err(ParseBigIntError::invalid_at(s, start + i)),
+        None if part.is_empty() => Err(ParseBigIntError::invalid_at(s, start)),
+        None => Ok(()),
+    }
+}
+
+/// The most bits `from_str_scientific` allows in its result, about 315,000 decimal
+/// digits.
+pub const SCIENTIFIC_MAX_BITS: u64 = 1 << 20;
+
+/// Computes `n` times ten to the power of `zeros`, or fails if that has more than
+/// `max_bits` bits.
+///
+/// An exponent that is far too large is rejected before allocating anything for it.
+fn mul_pow10_bounded(n: BigUint, zeros: usize, max_bits: u64) -> Result<BigUint, ParseBigIntError> {
+    let bits = n.bit_len();
+    if zeros > 0 && bits > 0 {
+        let zeros = zeros as u64;
+        // `10^zeros` has more than `3 * zeros` bits
+        if zeros > max_bits / 3 {
+            return Err(ParseBigIntError::too_large());
+        }
+        // and at least `zeros * 3.321` bits, since `log2(10) > 3.321`
+        let low = zeros * 3 + zeros / 1000 * 321 + zeros % 1000 * 321 / 1000;
+        if bits.saturating_add(low) > max_bits {
+            return Err(ParseBigIntError::too_large());
+        }
+    }
+    let n = if zeros == 0 || bits == 0 {
+        n
+    } else {
+        n * BigUint::from(10u32).pow(zeros)
+    };
+    if n.bit_len() > max_bits {
+        Err(ParseBigIntError::too_large())
+    } else {
+        Ok(n)
+    }
+}
+
+/// Parses an unsigned decimal number with an optional fraction and exponent, which
+/// must together make an integer of at most `max_bits` bits.
+pub fn from_str_scientific_unsigned(s: &str, max_bits: u64) -> Result<BigUint, ParseBigIntError> {
+    if s.is_empty() {
+        return Err(ParseBigIntError::empty());
+    }
+    let (mantissa, exp) = match s.find(&['e', 'E'][..]) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    try!(check_decimal(s, int, 0));
+    if !frac.is_empty() {
+        try!(check_decimal(s, frac, int.len() + 1));
+    }
+    let (exp_negative, exp) = match exp {
+        Some(exp) => {
+            let (negative, digits) = if exp.starts_with('-') {
+                (true, &exp[1..])
+            } else if exp.starts_with('+') {
+                (false, &exp[1..])
+            } else {
+                (false, exp)
+            };
+            try!(check_decimal(s, digits, s.len() - digits.len()));
+            // the digits are valid, so this only fails on overflow, and an
+            // exponent that large behaves the same once it saturates
+            let exp = digits.parse::<usize>().unwrap_or(usize::max_value());
+            (negative, exp)
+        }
+        None => (false, 0),
+    };
+
+    let mut digits = String::with_capacity(int.len() + frac.len());
+    digits.push_str(int);
+    digits.push_str(frac);
+    // the number is `digits` times ten to the power of `exp - frac.len()`
+    let (drop, zeros) = if exp_negative {
+        (exp.saturating_add(frac.len()), 0)
+    } else if exp >= frac.len() {
+        (0, exp - frac.len())
+    } else {
+        (frac.len() - exp, 0)
+    };
+    let keep = digits.len().saturating_sub(drop);
+    if digits[keep..].bytes().any(|b| b != b'0') {
+        return Err(ParseBigIntError::fractional());
+    }
+    let n = if keep == 0 {
+        BigUint::zero()
+    } else {
+        from_radix_digits_be(
+            &digits[..keep].bytes().map(|b| b - b'0').collect::<Vec<_>>(),
+            10,
+        )
+    };
+    mul_pow10_bounded(n, zeros, max_bits)
+}
+
+pub fn to_str_radix_reversed(u: &BigUint, radix: u32, upper: bool) -> Vec<u8> {
+    assert!(2 <= radix && radix <= 62, "The radix must be within 2...62");
 
     if u.is_zero() {
         return vec![b'0'];
@@ -1900,17 +2775,275 @@ pub fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
     let mut res = to_radix_le(u, radix);
 
     // Now convert everything to ASCII digits.
+    let alpha = if upper || radix > 36 { b'A' } else { b'a' };
     for r in &mut res {
         debug_assert!(u32::from(*r) < radix);
         if *r < 10 {
             *r += b'0';
+        } else if *r < 36 {
+            *r += alpha - 10;
         } else {
-            *r += b'a' - 10;
+            *r += b'a' - 36;
+        }
+    }
+    res
+}
+
+/// An iterator of `u32` digits representation of a `BigUint` or `BigInt`,
+/// ordered least significant digit first.
+#[derive(Clone, Debug)]
+pub struct U32Digits<'a> {
+    it: slice::Iter<'a, BigDigit>,
+}
+
+impl<'a> Iterator for U32Digits<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        self.it.next().cloned()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for U32Digits<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u32> {
+        self.it.next_back().cloned()
+    }
+}
+
+impl<'a> ExactSizeIterator for U32Digits<'a> {}
+
+/// An iterator of `u64` digits representation of a `BigUint` or `BigInt`,
+/// ordered least significant digit first.
+#[derive(Clone, Debug)]
+pub struct U64Digits<'a> {
+    it: slice::Chunks<'a, BigDigit>,
+}
+
+impl<'a> Iterator for U64Digits<'a> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        self.it.next().map(u64_from_chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for U64Digits<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u64> {
+        self.it.next_back().map(u64_from_chunk)
+    }
+}
+
+impl<'a> ExactSizeIterator for U64Digits<'a> {}
+
+/// An iterator of the bits of a `BigUint`, as `bool`s ordered least significant bit first,
+/// up to the highest set bit.
+#[derive(Clone, Debug)]
+pub struct IterBits<'a> {
+    data: &'a [BigDigit],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> IterBits<'a> {
+    #[inline]
+    fn get(&self, i: usize) -> bool {
+        self.data[i / big_digit::BITS] >> (i % big_digit::BITS) & 1 != 0
+    }
+}
+
+impl<'a> Iterator for IterBits<'a> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        let bit = self.get(self.front);
+        self.front += 1;
+        Some(bit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for IterBits<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl<'a> ExactSizeIterator for IterBits<'a> {}
+
+// `DoubleBigDigit` size dependent
+#[inline]
+fn u64_from_chunk(chunk: &[BigDigit]) -> u64 {
+    if chunk.len() == 2 {
+        big_digit::to_doublebigdigit(chunk[1], chunk[0])
+    } else {
+        u64::from(chunk[0])
+    }
+}
+
+/// The order of words or of bytes within a word, as used by [`BigUint::import`] and
+/// [`BigUint::export`].
+///
+/// [`BigUint::import`]: struct.BigUint.html#method.import
+/// [`BigUint::export`]: struct.BigUint.html#method.export
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant first.
+    Big,
+    /// Least significant first.
+    Little,
+    /// The native byte order of the target platform.
+    Native,
+}
+
+impl Endian {
+    fn is_big(self) -> bool {
+        match self {
+            Endian::Big => true,
+            Endian::Little => false,
+            Endian::Native => cfg!(target_endian = "big"),
+        }
+    }
+}
+
+// Reads `data` as words of `size` bytes, using the low `size * 8 - nails` bits of each.
+fn import_words(data: &[u8], size: usize, order: Endian, endian: Endian, nails: usize) -> BigUint {
+    assert!(size > 0, "word size must be non-zero");
+    assert!(nails < size * 8, "nails must be smaller than the word size");
+    assert!(
+        data.len() % size == 0,
+        "data length must be a multiple of the word size"
+    );
+
+    let count = data.len() / size;
+    let word_bits = size * 8 - nails;
+    let mut res = Vec::with_capacity((count * word_bits + big_digit::BITS - 1) / big_digit::BITS);
+    let mut acc: DoubleBigDigit = 0;
+    let mut acc_bits = 0;
+
+    for i in 0..count {
+        let w = if order.is_big() { count - 1 - i } else { i };
+        let word = &data[w * size..(w + 1) * size];
+
+        let mut remaining = word_bits;
+        for j in 0..size {
+            if remaining == 0 {
+                break;
+            }
+            let byte = if endian.is_big() {
+                word[size - 1 - j]
+            } else {
+                word[j]
+            };
+            let n = cmp::min(8, remaining);
+            let mask = ((1u16 << n) - 1) as u8;
+
+            acc |= DoubleBigDigit::from(byte & mask) << acc_bits;
+            acc_bits += n;
+            remaining -= n;
+            if acc_bits >= big_digit::BITS {
+                res.push(acc as BigDigit);
+                acc >>= big_digit::BITS;
+                acc_bits -= big_digit::BITS;
+            }
+        }
+    }
+    if acc_bits > 0 {
+        res.push(acc as BigDigit);
+    }
+
+    BigUint::new(res)
+}
+
+// Writes `u` as words of `size` bytes, filling the low `size * 8 - nails` bits of each.
+fn export_words(u: &BigUint, size: usize, order: Endian, endian: Endian, nails: usize) -> Vec<u8> {
+    assert!(size > 0, "word size must be non-zero");
+    assert!(nails < size * 8, "nails must be smaller than the word size");
+
+    let word_bits = size * 8 - nails;
+    let count = bits_to_usize((u.bit_len() + word_bits as u64 - 1) / word_bits as u64);
+    let mut res = vec![0u8; count * size];
+    let mut digits = u.data.iter();
+    let mut acc: DoubleBigDigit = 0;
+    let mut acc_bits = 0;
+
+    for i in 0..count {
+        let w = if order.is_big() { count - 1 - i } else { i };
+        let word = &mut res[w * size..(w + 1) * size];
+
+        let mut remaining = word_bits;
+        for j in 0..size {
+            if remaining == 0 {
+                break;
+            }
+            let n = cmp::min(8, remaining);
+            if acc_bits < n {
+                if let Some(&d) = digits.next() {
+                    acc |= DoubleBigDigit::from(d) << acc_bits;
+                    acc_bits += big_digit::BITS;
+                }
+            }
+            let mask = (1 << n) - 1;
+            let byte = (acc & mask) as u8;
+            acc >>= n;
+            acc_bits = acc_bits.saturating_sub(n);
+            remaining -= n;
+
+            if endian.is_big() {
+                word[size - 1 - j] = byte;
+            } else {
+                word[j] = byte;
+            }
         }
     }
+
     res
 }
 
+#[cfg(has_const_vec_new)]
+impl BigUint {
+    /// Zero, as a constant for `static` and `const` items.
+    ///
+    /// There is no such constant for other values, which need a heap allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// static TOTAL: BigUint = BigUint::ZERO;
+    /// assert_eq!(TOTAL, BigUint::from(0u32));
+    /// ```
+    pub const ZERO: BigUint = BigUint { data: Vec::new() };
+}
+
 impl BigUint {
     /// Creates and initializes a `BigUint`.
     ///
@@ -1938,6 +3071,40 @@ impl BigUint {
         self.normalize();
     }
 
+    /// Creates and initializes a `BigUint`.
+    ///
+    /// The digits are in little-endian base 2<sup>64</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from_slice_u64(&[0, 1]);
+    /// assert_eq!(n, BigUint::from_slice(&[0, 0, 1]));
+    /// ```
+    #[inline]
+    pub fn from_slice_u64(slice: &[u64]) -> BigUint {
+        let mut n = BigUint::zero();
+        n.assign_from_slice_u64(slice);
+        n
+    }
+
+    /// Assign a value to a `BigUint`.
+    ///
+    /// The digits are in little-endian base 2<sup>64</sup>.
+    pub fn assign_from_slice_u64(&mut self, slice: &[u64]) {
+        // `DoubleBigDigit` size dependent
+        self.data.clear();
+        self.data.reserve(slice.len() * 2);
+        for &d in slice {
+            let (hi, lo) = big_digit::from_doublebigdigit(d);
+            self.data.push(lo);
+            self.data.push(hi);
+        }
+        self.normalize();
+    }
+
     /// Creates and initializes a `BigUint`.
     ///
     /// The bytes are in big-endian byte order.
@@ -1967,9 +3134,48 @@ impl BigUint {
         }
     }
 
-    /// Creates and initializes a `BigUint`.
+    /// Creates a `BigUint` from the big-endian bytes of a hash digest, or anything else
+    /// that can be viewed as a byte slice.
     ///
-    /// The bytes are in little-endian byte order.
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let digest: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    /// assert_eq!(BigUint::from_digest(digest), BigUint::from(0xdeadbeefu32));
+    /// assert_eq!(BigUint::from_digest(vec![1, 0]), BigUint::from(256u32));
+    /// ```
+    #[inline]
+    pub fn from_digest<D: AsRef<[u8]>>(digest: D) -> BigUint {
+        BigUint::from_bytes_be(digest.as_ref())
+    }
+
+    /// Creates a `BigUint` from the big-endian bytes of a hash digest, reduced modulo
+    /// `modulus`, as when hashing to a scalar.
+    ///
+    /// The result is only close to uniform if the digest is much wider than the modulus,
+    /// e.g. by 128 bits or more.
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let digest: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    /// let m = BigUint::from(1000u32);
+    /// assert_eq!(BigUint::from_digest_mod(digest, &m), BigUint::from(0xdeadbeefu32 % 1000));
+    /// ```
+    #[inline]
+    pub fn from_digest_mod<D: AsRef<[u8]>>(digest: D, modulus: &BigUint) -> BigUint {
+        BigUint::from_bytes_be(digest.as_ref()) % modulus
+    }
+
+    /// Creates and initializes a `BigUint`.
+    ///
+    /// The bytes are in little-endian byte order.
     #[inline]
     pub fn from_bytes_le(bytes: &[u8]) -> BigUint {
         if bytes.is_empty() {
@@ -1979,217 +3185,1287 @@ impl BigUint {
         }
     }
 
-    /// Creates and initializes a `BigUint`. The input slice must contain
-    /// ascii/utf8 characters in [0-9a-zA-Z].
-    /// `radix` must be in the range `2...36`.
+    /// Creates and initializes a `BigUint`.
+    ///
+    /// The bytes are in the native byte order of the target platform, so this is the same as
+    /// `from_bytes_le` on little-endian targets and `from_bytes_be` on big-endian targets.
+    #[inline]
+    pub fn from_bytes_ne(bytes: &[u8]) -> BigUint {
+        if cfg!(target_endian = "little") {
+            BigUint::from_bytes_le(bytes)
+        } else {
+            BigUint::from_bytes_be(bytes)
+        }
+    }
+
+    /// Creates and initializes a `BigUint` from `u32` digits ordered like the bytes of
+    /// `from_bytes_ne`, so a native-endian number in memory can be read a whole limb at a
+    /// time.
+    ///
+    /// This is the same as `from_slice` on little-endian targets, and takes the most
+    /// significant digit first on big-endian targets.
+    #[inline]
+    pub fn from_u32_digits_ne(digits: &[u32]) -> BigUint {
+        if cfg!(target_endian = "little") {
+            BigUint::from_slice(digits)
+        } else {
+            BigUint::new(digits.iter().rev().cloned().collect())
+        }
+    }
+
+    /// Creates and initializes a `BigUint`. The input slice must contain
+    /// ascii/utf8 characters in [0-9a-zA-Z].
+    /// `radix` must be in the range `2...62`, and letters are case-sensitive
+    /// digits above radix 36.
+    ///
+    /// The function `from_str_radix` from the `Num` trait provides the same logic
+    /// for `&str` buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, ToBigUint};
+    ///
+    /// assert_eq!(BigUint::parse_bytes(b"1234", 10), ToBigUint::to_biguint(&1234));
+    /// assert_eq!(BigUint::parse_bytes(b"ABCD", 16), ToBigUint::to_biguint(&0xABCD));
+    /// assert_eq!(BigUint::parse_bytes(b"G", 16), None);
+    /// ```
+    #[inline]
+    pub fn parse_bytes(buf: &[u8], radix: u32) -> Option<BigUint> {
+        BigUint::try_parse_bytes(buf, radix).ok()
+    }
+
+    /// Creates and initializes a `BigUint` like `parse_bytes`, but with an error that
+    /// tells whether the input was empty, had an invalid digit, or the radix was not in
+    /// the range `2...62`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigIntErrorKind, BigUint};
+    ///
+    /// assert_eq!(BigUint::try_parse_bytes(b"ABCD", 16), Ok(BigUint::from(0xABCDu32)));
+    /// let err = BigUint::try_parse_bytes(b"ABCD", 99).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::InvalidRadix);
+    /// ```
+    pub fn try_parse_bytes(buf: &[u8], radix: u32) -> Result<BigUint, ParseBigIntError> {
+        if radix < 2 || radix > 62 {
+            return Err(ParseBigIntError::invalid_radix());
+        }
+        match str::from_utf8(buf) {
+            Ok(s) => BigUint::from_str_radix(s, radix),
+            Err(e) => Err(ParseBigIntError::invalid_utf8(e.valid_up_to())),
+        }
+    }
+
+    /// Parses a number with an optional leading `+`, choosing the radix from its prefix:
+    /// `0x` for hexadecimal, `0o` for octal, `0b` for binary, or decimal without one.
+    ///
+    /// The prefix may also be uppercase, and the digits may be separated by `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_prefixed("0xff"), Ok(BigUint::from(255u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("0o17"), Ok(BigUint::from(15u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("+0b1010"), Ok(BigUint::from(10u32)));
+    /// assert_eq!(BigUint::from_str_prefixed("1_000"), Ok(BigUint::from(1000u32)));
+    /// assert!(BigUint::from_str_prefixed("0x").is_err());
+    /// ```
+    pub fn from_str_prefixed(s: &str) -> Result<BigUint, ParseBigIntError> {
+        if s.starts_with('+') {
+            from_str_prefixed_unsigned(&s[1..]).map_err(|e| e.offset(1))
+        } else {
+            from_str_prefixed_unsigned(s)
+        }
+    }
+
+    /// Parses a number in the given radix like `from_str_radix`, but first removes every
+    /// occurrence of the `separators`, wherever they are.
+    ///
+    /// This accepts numbers with digit group separators, like `"1,234,567"` or a
+    /// hexadecimal constant spread over several lines.
+    /// `radix` must be in the range `2...62`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from_str_radix_separated("1,234,567", 10, &[',']);
+    /// assert_eq!(n, Ok(BigUint::from(1234567u32)));
+    ///
+    /// let n = BigUint::from_str_radix_separated("dead beef\n cafe", 16, &[' ', '\n']);
+    /// assert_eq!(n, Ok(BigUint::from(0xdeadbeefcafe_u64)));
+    /// ```
+    pub fn from_str_radix_separated(
+        s: &str,
+        radix: u32,
+        separators: &[char],
+    ) -> Result<BigUint, ParseBigIntError> {
+        BigUint::from_str_radix(&remove_separators(s, separators), radix)
+            .map_err(|e| restore_separators(e, s, separators))
+    }
+
+    /// Parses a decimal number in scientific notation, like `"1e100"` or `"1.5e10"`,
+    /// with an optional leading `+`.
+    ///
+    /// The fraction and the exponent are both optional, and the exponent may be
+    /// negative, but the value must be an integer: a fractional part that is not made up
+    /// by the exponent is an error.
+    ///
+    /// A short string can describe a huge number, like `"1e999999999999"`, so the result
+    /// is limited to 2<sup>20</sup> bits, about 315,000 decimal digits. A larger number
+    /// fails with [`BigIntErrorKind::TooLarge`](enum.BigIntErrorKind.html) before it
+    /// is computed; use
+    /// [`from_str_scientific_max_bits`](#method.from_str_scientific_max_bits) to choose
+    /// another limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_str_scientific("1.5e3"), Ok(BigUint::from(1500u32)));
+    /// assert_eq!(BigUint::from_str_scientific("2500E-2"), Ok(BigUint::from(25u32)));
+    /// assert_eq!(BigUint::from_str_scientific("1e6"), Ok(BigUint::from(1000000u32)));
+    /// assert!(BigUint::from_str_scientific("1.25e1").is_err());
+    /// assert!(BigUint::from_str_scientific("1e999999999999").is_err());
+    /// ```
+    pub fn from_str_scientific(s: &str) -> Result<BigUint, ParseBigIntError> {
+        BigUint::from_str_scientific_max_bits(s, SCIENTIFIC_MAX_BITS)
+    }
+
+    /// Parses a decimal number in scientific notation like
+    /// [`from_str_scientific`](#method.from_str_scientific), with a result of at most
+    /// `max_bits` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigIntErrorKind, BigUint};
+    ///
+    /// assert_eq!(BigUint::from_str_scientific_max_bits("1e3", 10), Ok(BigUint::from(1000u32)));
+    /// let err = BigUint::from_str_scientific_max_bits("1e4", 10).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::TooLarge);
+    /// ```
+    pub fn from_str_scientific_max_bits(
+        s: &str,
+        max_bits: u64,
+    ) -> Result<BigUint, ParseBigIntError> {
+        if s.starts_with('+') {
+            from_str_scientific_unsigned(&s[1..], max_bits).map_err(|e| e.offset(1))
+        } else {
+            from_str_scientific_unsigned(s, max_bits)
+        }
+    }
+
+    /// Creates and initializes a `BigUint`. Each u8 of the input slice is
+    /// interpreted as one digit of the number
+    /// and must therefore be less than `radix`.
+    ///
+    /// The bytes are in big-endian byte order.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint};
+    ///
+    /// let inbase190 = &[15, 33, 125, 12, 14];
+    /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
+    /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// ```
+    pub fn from_radix_be(buf: &[u8], radix: u32) -> Option<BigUint> {
+        assert!(
+            2 <= radix && radix <= 256,
+            "The radix must be within 2...256"
+        );
+
+        if radix != 256 && buf.iter().any(|&b| b >= radix as u8) {
+            return None;
+        }
+
+        let res = if radix.is_power_of_two() {
+            // Powers of two can use bitwise masks and shifting instead of multiplication
+            let bits = ilog2(radix);
+            let mut v = Vec::from(buf);
+            v.reverse();
+            if big_digit::BITS % bits == 0 {
+                from_bitwise_digits_le(&v, bits)
+            } else {
+                from_inexact_bitwise_digits_le(&v, bits)
+            }
+        } else {
+            from_radix_digits_be(buf, radix)
+        };
+
+        Some(res)
+    }
+
+    /// Creates and initializes a `BigUint`. Each u8 of the input slice is
+    /// interpreted as one digit of the number
+    /// and must therefore be less than `radix`.
+    ///
+    /// The bytes are in little-endian byte order.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint};
+    ///
+    /// let inbase190 = &[14, 12, 125, 33, 15];
+    /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
+    /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// ```
+    pub fn from_radix_le(buf: &[u8], radix: u32) -> Option<BigUint> {
+        assert!(
+            2 <= radix && radix <= 256,
+            "The radix must be within 2...256"
+        );
+
+        if radix != 256 && buf.iter().any(|&b| b >= radix as u8) {
+            return None;
+        }
+
+        let res = if radix.is_power_of_two() {
+            // Powers of two can use bitwise masks and shifting instead of multiplication
+            let bits = ilog2(radix);
+            if big_digit::BITS % bits == 0 {
+                from_bitwise_digits_le(buf, bits)
+            } else {
+                from_inexact_bitwise_digits_le(buf, bits)
+            }
+        } else {
+            let mut v = Vec::from(buf);
+            v.reverse();
+            from_radix_digits_be(&v, radix)
+        };
+
+        Some(res)
+    }
+
+    /// Returns the byte representation of the `BigUint` in big-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
+    /// assert_eq!(i.to_bytes_be(), vec![4, 101]);
+    /// ```
+    #[inline]
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut v = self.to_bytes_le();
+        v.reverse();
+        v
+    }
+
+    /// Returns the byte representation of the `BigUint` in little-endian byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
+    /// assert_eq!(i.to_bytes_le(), vec![101, 4]);
+    /// ```
+    #[inline]
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        if self.is_zero() {
+            vec![0]
+        } else {
+            to_bitwise_digits_le(self, 8)
+        }
+    }
+
+    /// Returns the byte representation of the `BigUint` in the native byte order of the target
+    /// platform, i.e. `to_bytes_le` on little-endian targets and `to_bytes_be` on big-endian
+    /// targets.
+    #[inline]
+    pub fn to_bytes_ne(&self) -> Vec<u8> {
+        if cfg!(target_endian = "little") {
+            self.to_bytes_le()
+        } else {
+            self.to_bytes_be()
+        }
+    }
+
+    /// Creates and initializes a `BigUint` from words of `size` bytes, in the manner of GMP's
+    /// `mpz_import`.
+    ///
+    /// The words are ordered according to `order`, the bytes within each word according to
+    /// `endian`, and the most significant `nails` bits of each word are skipped.
+    ///
+    /// Panics if `size` is zero, if `nails` is not smaller than the number of bits in a word, or
+    /// if the length of `data` is not a multiple of `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, Endian};
+    ///
+    /// let data = [0x00, 0x01, 0x02, 0x03];
+    /// let n = BigUint::import(&data, 2, Endian::Little, Endian::Big, 0);
+    /// assert_eq!(n, BigUint::from(0x0203_0001u32));
+    ///
+    /// // with one nail bit, the top bit of each byte is ignored
+    /// let n = BigUint::import(&[0xff, 0x81], 1, Endian::Big, Endian::Big, 1);
+    /// assert_eq!(n, BigUint::from((0x7f << 7) | 0x01u32));
+    /// ```
+    #[inline]
+    pub fn import(
+        data: &[u8],
+        size: usize,
+        order: Endian,
+        endian: Endian,
+        nails: usize,
+    ) -> BigUint {
+        import_words(data, size, order, endian, nails)
+    }
+
+    /// Returns the value as words of `size` bytes, in the manner of GMP's `mpz_export`.
+    ///
+    /// The words are ordered according to `order` and the bytes within each word according to
+    /// `endian`. The most significant `nails` bits of each word are left as zero. As with GMP,
+    /// zero is exported as no words at all.
+    ///
+    /// Panics if `size` is zero or if `nails` is not smaller than the number of bits in a word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, Endian};
+    ///
+    /// let n = BigUint::from(0x0203_0001u32);
+    /// assert_eq!(n.export(2, Endian::Little, Endian::Big, 0), vec![0x00, 0x01, 0x02, 0x03]);
+    /// assert_eq!(n.export(4, Endian::Big, Endian::Little, 0), vec![0x01, 0x00, 0x03, 0x02]);
+    /// ```
+    #[inline]
+    pub fn export(&self, size: usize, order: Endian, endian: Endian, nails: usize) -> Vec<u8> {
+        export_words(self, size, order, endian, nails)
+    }
+
+    /// Returns the `u32` digits representation of the `BigUint` ordered least significant digit
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1125u32).to_u32_digits(), vec![1125]);
+    /// assert_eq!(BigUint::from(4294967295u32).to_u32_digits(), vec![4294967295]);
+    /// assert_eq!(BigUint::from(4294967296u64).to_u32_digits(), vec![0, 1]);
+    /// assert_eq!(BigUint::from(112500000000u64).to_u32_digits(), vec![830850304, 26]);
+    /// ```
+    #[inline]
+    pub fn to_u32_digits(&self) -> Vec<u32> {
+        // `BigDigit` size dependent
+        self.data.clone()
+    }
+
+    /// Returns the `u32` digits of the `BigUint` ordered like the bytes of `to_bytes_ne`,
+    /// i.e. `to_u32_digits` on little-endian targets, and most significant digit first on
+    /// big-endian targets.
+    #[inline]
+    pub fn to_u32_digits_ne(&self) -> Vec<u32> {
+        let mut digits = self.to_u32_digits();
+        if cfg!(target_endian = "big") {
+            digits.reverse();
+        }
+        digits
+    }
+
+    /// Returns the `u64` digits representation of the `BigUint` ordered least significant digit
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1125u32).to_u64_digits(), vec![1125]);
+    /// assert_eq!(BigUint::from(4294967295u32).to_u64_digits(), vec![4294967295]);
+    /// assert_eq!(BigUint::from(4294967296u64).to_u64_digits(), vec![4294967296]);
+    /// assert_eq!(BigUint::from(112500000000u64).to_u64_digits(), vec![112500000000]);
+    /// assert_eq!(BigUint::from_slice(&[0, 0, 1]).to_u64_digits(), vec![0, 1]);
+    /// ```
+    #[inline]
+    pub fn to_u64_digits(&self) -> Vec<u64> {
+        self.iter_u64_digits().collect()
+    }
+
+    /// Returns an iterator of `u32` digits representation of the `BigUint` ordered least
+    /// significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1125u32).iter_u32_digits().collect::<Vec<u32>>(), vec![1125]);
+    /// assert_eq!(BigUint::from(4294967296u64).iter_u32_digits().collect::<Vec<u32>>(), vec![0, 1]);
+    /// assert_eq!(BigUint::from(112500000000u64).iter_u32_digits().collect::<Vec<u32>>(), vec![830850304, 26]);
+    /// ```
+    #[inline]
+    pub fn iter_u32_digits<'a>(&'a self) -> U32Digits<'a> {
+        // `BigDigit` size dependent
+        U32Digits {
+            it: self.data.iter(),
+        }
+    }
+
+    /// Returns an iterator of `u64` digits representation of the `BigUint` ordered least
+    /// significant digit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1125u32).iter_u64_digits().collect::<Vec<u64>>(), vec![1125]);
+    /// assert_eq!(BigUint::from(4294967296u64).iter_u64_digits().collect::<Vec<u64>>(), vec![4294967296]);
+    /// assert_eq!(BigUint::from_slice(&[0, 0, 1]).iter_u64_digits().collect::<Vec<u64>>(), vec![0, 1]);
+    /// ```
+    #[inline]
+    pub fn iter_u64_digits<'a>(&'a self) -> U64Digits<'a> {
+        // `DoubleBigDigit` size dependent
+        U64Digits {
+            it: self.data.chunks(2),
+        }
+    }
+
+    /// Returns an iterator of the bits of the `BigUint`, least significant first, up to
+    /// the highest set bit.
+    ///
+    /// The iterator is double-ended, so `.rev()` walks the bits from the most significant
+    /// down, as [`iter_bits_be`](#method.iter_bits_be) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let bits: Vec<bool> = BigUint::from(6u32).iter_bits().collect();
+    /// assert_eq!(bits, vec![false, true, true]);
+    /// assert_eq!(BigUint::from(0u32).iter_bits().count(), 0);
+    /// ```
+    #[inline]
+    pub fn iter_bits<'a>(&'a self) -> IterBits<'a> {
+        IterBits {
+            data: &self.data,
+            front: 0,
+            back: bits_to_usize(self.bit_len()),
+        }
+    }
+
+    /// Returns an iterator of the bits of the `BigUint`, most significant first, starting
+    /// from the highest set bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let bits: Vec<bool> = BigUint::from(6u32).iter_bits_be().collect();
+    /// assert_eq!(bits, vec![true, true, false]);
+    /// ```
+    #[inline]
+    pub fn iter_bits_be<'a>(&'a self) -> Rev<IterBits<'a>> {
+        self.iter_bits().rev()
+    }
+
+    /// Returns the internal digits of the `BigUint`, ordered least significant digit first,
+    /// without copying them.
+    ///
+    /// The digits are normalized, so the last one is never zero. Note that the size of a digit
+    /// is an implementation detail which may change in a future release; use
+    /// [`iter_u32_digits`](#method.iter_u32_digits) or
+    /// [`iter_u64_digits`](#method.iter_u64_digits) when a fixed digit size is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1125u32);
+    /// assert_eq!(n.digits().len(), 1);
+    /// assert!(BigUint::from(0u32).digits().is_empty());
+    /// ```
+    #[inline]
+    pub fn digits(&self) -> &[BigDigit] {
+        &self.data
+    }
+
+    /// Returns the byte representation of the `BigUint` in big-endian byte order, left-padded
+    /// with zeros to exactly `len` bytes.
+    ///
+    /// Returns `None` if the value does not fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
+    /// assert_eq!(i.to_bytes_be_padded(4), Some(vec![0, 0, 4, 101]));
+    /// assert_eq!(i.to_bytes_be_padded(1), None);
+    /// ```
+    #[inline]
+    pub fn to_bytes_be_padded(&self, len: usize) -> Option<Vec<u8>> {
+        self.to_bytes_le_padded(len).map(|mut v| {
+            v.reverse();
+            v
+        })
+    }
+
+    /// Returns the byte representation of the `BigUint` in little-endian byte order,
+    /// right-padded with zeros to exactly `len` bytes.
+    ///
+    /// Returns `None` if the value does not fit in `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
+    /// assert_eq!(i.to_bytes_le_padded(4), Some(vec![101, 4, 0, 0]));
+    /// assert_eq!(i.to_bytes_le_padded(1), None);
+    /// ```
+    pub fn to_bytes_le_padded(&self, len: usize) -> Option<Vec<u8>> {
+        if (self.bit_len() + 7) / 8 > len as u64 {
+            return None;
+        }
+        let mut v = if self.is_zero() {
+            Vec::with_capacity(len)
+        } else {
+            to_bitwise_digits_le(self, 8)
+        };
+        v.resize(len, 0);
+        Some(v)
+    }
+
+    /// Returns the integer formatted as a string in the given radix.
+    /// `radix` must be in the range `2...62`.  Above radix 36, the digits are
+    /// `0-9A-Za-z` as in GMP.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"ff", 16).unwrap();
+    /// assert_eq!(i.to_str_radix(16), "ff");
+    /// ```
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let mut v = to_str_radix_reversed(self, radix, false);
+        v.reverse();
+        unsafe { String::from_utf8_unchecked(v) }
+    }
+
+    /// Returns the integer formatted as a string in the given radix, with uppercase
+    /// letters for digits above nine.
+    /// `radix` must be in the range `2...62`.  Above radix 36, the digits are
+    /// `0-9A-Za-z` as in GMP, the same as `to_str_radix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::parse_bytes(b"ff", 16).unwrap();
+    /// assert_eq!(i.to_str_radix_upper(16), "FF");
+    /// ```
+    #[inline]
+    pub fn to_str_radix_upper(&self, radix: u32) -> String {
+        let mut v = to_str_radix_reversed(self, radix, true);
+        v.reverse();
+        unsafe { String::from_utf8_unchecked(v) }
+    }
+
+    /// Returns the integer in the requested base in big-endian digit order.
+    /// The output is not given in a human readable alphabet but as a zero
+    /// based u8 number.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_be(159),
+    ///            vec![2, 94, 27]);
+    /// // 0xFFFF = 65535 = 2*(159^2) + 94*159 + 27
+    /// ```
+    #[inline]
+    pub fn to_radix_be(&self, radix: u32) -> Vec<u8> {
+        let mut v = to_radix_le(self, radix);
+        v.reverse();
+        v
+    }
+
+    /// Returns the integer in the requested base in little-endian digit order.
+    /// The output is not given in a human readable alphabet but as a zero
+    /// based u8 number.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_le(159),
+    ///            vec![27, 94, 2]);
+    /// // 0xFFFF = 65535 = 27 + 94*159 + 2*(159^2)
+    /// ```
+    #[inline]
+    pub fn to_radix_le(&self, radix: u32) -> Vec<u8> {
+        to_radix_le(self, radix)
+    }
+
+    /// Determines the fewest bits necessary to express the `BigUint`.
+    ///
+    /// Panics if the count doesn't fit in `usize`, which can happen on 32-bit targets.
+    #[deprecated(since = "0.2.3", note = "use `bit_len`, which returns `u64`")]
+    #[inline]
+    pub fn bits(&self) -> usize {
+        bits_to_usize(self.bit_len())
+    }
+
+    /// Determines the fewest bits necessary to express the `BigUint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).bit_len(), 0);
+    /// assert_eq!(BigUint::from(0b1011u32).bit_len(), 4);
+    /// assert_eq!((BigUint::from(1u32) << 100usize).bit_len(), 101);
+    /// ```
+    #[inline]
+    pub fn bit_len(&self) -> u64 {
+        match self.data.last() {
+            None => 0,
+            Some(hi) => {
+                let len = self.data.len() as u64 * big_digit::BITS as u64;
+                len - u64::from(hi.leading_zeros())
+            }
+        }
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = (BigUint::from(1u32) << 100usize) - 1u32;
+    /// assert_eq!(n.count_ones(), 100);
+    /// ```
+    #[inline]
+    pub fn count_ones(&self) -> u64 {
+        count_ones_low(self, u64::max_value())
+    }
+
+    /// Returns whether bit `bit` of `self` is set, counting from the least significant bit
+    /// at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(0b101u32);
+    /// assert!(n.bit(0));
+    /// assert!(!n.bit(1));
+    /// assert!(!n.bit(1000));
+    /// ```
+    pub fn bit(&self, bit: u64) -> bool {
+        let (digit, shift) = bit.div_rem(&(big_digit::BITS as u64));
+        if digit >= self.data.len() as u64 {
+            return false;
+        }
+        self.data[digit as usize] >> shift & 1 != 0
+    }
+
+    /// Sets bit `bit` of `self` to `value`, growing `self` if the bit is beyond its highest
+    /// digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(0u32);
+    /// n.set_bit(100, true);
+    /// assert_eq!(n, BigUint::from(1u32) << 100usize);
+    /// n.set_bit(100, false);
+    /// assert_eq!(n, BigUint::from(0u32));
+    /// ```
+    pub fn set_bit(&mut self, bit: u64, value: bool) {
+        let (digit, shift) = bit.div_rem(&(big_digit::BITS as u64));
+        let mask = (1 as BigDigit) << shift;
+        if value {
+            let digit = digit.to_usize().expect("capacity overflow");
+            if digit >= self.data.len() {
+                self.data.resize(digit + 1, 0);
+            }
+            self.data[digit] |= mask;
+        } else if digit < self.data.len() as u64 {
+            self.data[digit as usize] &= !mask;
+            self.normalize();
+        }
+    }
+
+    /// Flips bit `bit` of `self`, growing `self` if the bit is beyond its highest digit.
+    pub fn toggle_bit(&mut self, bit: u64) {
+        let value = !self.bit(bit);
+        self.set_bit(bit, value);
+    }
+
+    /// Keeps only the low `bits` bits of `self`, which leaves `self % 2^bits`.
+    ///
+    /// This drops the higher digits and masks the top one, which is much cheaper than
+    /// dividing by `BigUint::one() << bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(0x1234_5678u32);
+    /// n.keep_low_bits(12);
+    /// assert_eq!(n, BigUint::from(0x678u32));
+    /// ```
+    pub fn keep_low_bits(&mut self, bits: u64) {
+        let (len, rem) = bits.div_rem(&(big_digit::BITS as u64));
+        if len >= self.data.len() as u64 {
+            return;
+        }
+        let len = len as usize;
+        self.data.truncate(len + 1);
+        self.data[len] &= ((1 as BigDigit) << rem) - 1;
+        self.normalize();
+    }
+
+    /// Returns `self % 2^bits`, reusing the digits of `self` -- see
+    /// [`keep_low_bits`](#method.keep_low_bits).
+    #[inline]
+    pub fn into_low_bits(mut self, bits: u64) -> BigUint {
+        self.keep_low_bits(bits);
+        self
+    }
+
+    /// Returns the non-adjacent form of `self`: signed digits of -1, 0 or 1, least
+    /// significant first, with no two adjacent digits nonzero.
+    ///
+    /// This is [`to_wnaf(2)`](#method.to_wnaf).  It has the fewest nonzero digits of any
+    /// signed binary representation, a third of them on average, which saves
+    /// multiplications in exponentiation and scalar multiplication when an inverse is cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // 7 = 8 - 1
+    /// assert_eq!(BigUint::from(7u32).to_naf(), vec![-1, 0, 0, 1]);
+    /// ```
+    #[inline]
+    pub fn to_naf(&self) -> Vec<i8> {
+        self.to_wnaf(2)
+    }
+
+    /// Returns the width-`w` non-adjacent form of `self`, least significant digit first.
+    ///
+    /// Each digit is zero or odd with an absolute value less than 2^(`w` - 1), any `w`
+    /// consecutive digits have at most one that is nonzero, and `self` is the sum of each
+    /// digit times 2^i.  The result has no trailing zero digits, so it is empty for zero,
+    /// and it is at most one digit longer than `self.bit_len()`.
+    ///
+    /// Panics unless `w` is in `2...8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // 0b1011_1101 = 189 = 3 * 2^6 - 3
+    /// assert_eq!(BigUint::from(189u32).to_wnaf(3), vec![-3, 0, 0, 0, 0, 0, 3]);
+    /// ```
+    pub fn to_wnaf(&self, w: u32) -> Vec<i8> {
+        assert!(2 <= w && w <= 8, "the wNAF width must be within 2...8");
+
+        let width = 1u32 << w;
+        let mut naf = vec![0i8; bits_to_usize(self.bit_len()) + 1];
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < naf.len() {
+            // A pending carry adds one to the window, which may make it even.
+            let window = carry + self.bit_window(pos, w);
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i32 - width as i32) as i8;
+            }
+            pos += w as usize;
+        }
+
+        while naf.last() == Some(&0) {
+            naf.pop();
+        }
+        naf
+    }
+
+    /// Returns the low `width` bits of `self` in reverse order, so bit `i` moves to bit
+    /// `width - 1 - i`, or `None` if `self` does not fit in `width` bits.
+    ///
+    /// This is the index permutation of a radix-2 FFT when `width` is the log of its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(0b0110_0001u32);
+    /// assert_eq!(n.reverse_bits(8), Some(BigUint::from(0b1000_0110u32)));
+    /// assert_eq!(n.reverse_bits(10), Some(BigUint::from(0b10_0001_1000u32)));
+    /// assert_eq!(n.reverse_bits(6), None);
+    /// ```
+    pub fn reverse_bits(&self, width: u64) -> Option<BigUint> {
+        if self.bit_len() > width {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(BigUint::zero());
+        }
+
+        // Reverse the whole digits, then shift out the padding above `width`.
+        let (len, rem) = (width as usize).div_rem(&big_digit::BITS);
+        let len = if rem == 0 { len } else { len + 1 };
+        let mut data = vec![0; len];
+        for (r, &d) in data.iter_mut().rev().zip(&self.data) {
+            *r = reverse_digit(d);
+        }
+        let padding = len * big_digit::BITS - width as usize;
+        Some(BigUint::new(data) >> padding)
+    }
+
+    // Returns the `w < 32` bits of `self` starting at bit `pos`.
+    fn bit_window(&self, pos: usize, w: u32) -> u32 {
+        let (i, shift) = pos.div_rem(&big_digit::BITS);
+        let lo = self.data.get(i).map_or(0, |&d| d >> shift);
+        let hi = match self.data.get(i + 1) {
+            Some(&d) if shift != 0 => d << (big_digit::BITS - shift),
+            _ => 0,
+        };
+        (lo | hi) & ((1 << w) - 1)
+    }
+
+    /// Returns the number of 32-bit digits used to store this `BigUint`.
+    ///
+    /// This is zero for zero, and otherwise `bit_len()` divided by 32, rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u32).digits_len(), 0);
+    /// assert_eq!(BigUint::from(u32::max_value()).digits_len(), 1);
+    /// assert_eq!((BigUint::from(1u32) << 64usize).digits_len(), 3);
+    /// ```
+    #[inline]
+    pub fn digits_len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the most significant digit is non-zero, as every operation is
+    /// supposed to leave it.
+    ///
+    /// This is only available with debug assertions, as a check of internal invariants.
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.data.last() != Some(&0)
+    }
+
+    /// Returns the number of bytes allocated on the heap for the digits of this `BigUint`.
+    ///
+    /// This reflects the capacity of the internal buffer, which may be larger than what is
+    /// needed to hold the current value; see [`shrink_to_fit`](#method.shrink_to_fit).
+    #[inline]
+    pub fn heap_size_in_bytes(&self) -> usize {
+        self.data.capacity() * mem::size_of::<BigDigit>()
+    }
+
+    /// Releases any excess capacity held by the internal buffer of this `BigUint`.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Shifts `self` left by `bits` and adds `other`, as `*self = (*self << bits) + other`,
+    /// reusing the existing allocation of `self`.
+    ///
+    /// This is useful for building up a number from chunks, e.g. when converting from another
+    /// base or packing bit fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(0xFFu32);
+    /// n.shl_add_assign(8, &BigUint::from(0x1FFu32));
+    /// assert_eq!(n, BigUint::from(0x100FFu32));
+    /// ```
+    #[inline]
+    pub fn shl_add_assign(&mut self, bits: usize, other: &BigUint) {
+        biguint_shl_combine(self, bits, &other.data, false);
+    }
+
+    /// Shifts `self` left by `bits` and ors in `other`, as `*self = (*self << bits) | other`,
+    /// reusing the existing allocation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(0xABu32);
+    /// n.shl_or_assign(8, &BigUint::from(0xCDu32));
+    /// assert_eq!(n, BigUint::from(0xABCDu32));
+    /// ```
+    #[inline]
+    pub fn shl_or_assign(&mut self, bits: usize, other: &BigUint) {
+        biguint_shl_combine(self, bits, &other.data, true);
+    }
+
+    /// Returns `self << rhs`, or `None` if the result would have too many digits to ever be
+    /// allocated.
+    ///
+    /// This only rules out results that could not exist on this target.  A shift amount taken
+    /// from untrusted input can still ask for far more memory than is available, so prefer
+    /// [`checked_shl_max`](#method.checked_shl_max) in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(3u32);
+    /// assert_eq!(n.checked_shl(4), Some(BigUint::from(48u32)));
+    /// assert_eq!(n.checked_shl(usize::max_value()), None);
+    /// ```
+    #[inline]
+    pub fn checked_shl(&self, rhs: usize) -> Option<BigUint> {
+        let max_digits = isize::max_value() as usize / mem::size_of::<BigDigit>();
+        self.checked_shl_max(
+            rhs,
+            (max_digits as u64).saturating_mul(big_digit::BITS as u64),
+        )
+    }
+
+    /// Returns `self << rhs`, or `None` if the result would need more than `max_bits` bits.
+    ///
+    /// The check is made before anything is allocated, so this is safe to call with a shift
+    /// amount from untrusted input.  Zero shifts to zero whatever the amount.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(3u32);
+    /// assert_eq!(n.checked_shl_max(4, 8), Some(BigUint::from(48u32)));
+    /// assert_eq!(n.checked_shl_max(7, 8), None);
+    /// assert_eq!(BigUint::from(0u32).checked_shl_max(1000, 8), Some(BigUint::from(0u32)));
+    /// ```
+    pub fn checked_shl_max(&self, rhs: usize, max_bits: u64) -> Option<BigUint> {
+        if self.is_zero() {
+            return Some(BigUint::zero());
+        }
+        match self.bit_len().checked_add(rhs as u64) {
+            Some(bits) if bits <= max_bits => Some(self << rhs),
+            _ => None,
+        }
+    }
+
+    /// Returns `self ^ exp`, or `None` if the result would have too many digits to ever be
+    /// allocated.
     ///
-    /// The function `from_str_radix` from the `Num` trait provides the same logic
-    /// for `&str` buffers.
+    /// As with [`checked_shl`](#method.checked_shl), an exponent from untrusted input can
+    /// still ask for far more memory than is available, so prefer
+    /// [`checked_pow_max`](#method.checked_pow_max) in that case.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{BigUint, ToBigUint};
+    /// use num_bigint::BigUint;
     ///
-    /// assert_eq!(BigUint::parse_bytes(b"1234", 10), ToBigUint::to_biguint(&1234));
-    /// assert_eq!(BigUint::parse_bytes(b"ABCD", 16), ToBigUint::to_biguint(&0xABCD));
-    /// assert_eq!(BigUint::parse_bytes(b"G", 16), None);
+    /// assert_eq!(BigUint::from(3u32).checked_pow(4), Some(BigUint::from(81u32)));
+    /// assert_eq!(BigUint::from(3u32).checked_pow(u64::max_value()), None);
+    /// assert_eq!(BigUint::from(1u32).checked_pow(u64::max_value()), Some(BigUint::from(1u32)));
     /// ```
     #[inline]
-    pub fn parse_bytes(buf: &[u8], radix: u32) -> Option<BigUint> {
-        str::from_utf8(buf)
-            .ok()
-            .and_then(|s| BigUint::from_str_radix(s, radix).ok())
+    pub fn checked_pow(&self, exp: u64) -> Option<BigUint> {
+        let max_digits = isize::max_value() as usize / mem::size_of::<BigDigit>();
+        self.checked_pow_max(
+            exp,
+            (max_digits as u64).saturating_mul(big_digit::BITS as u64),
+        )
     }
 
-    /// Creates and initializes a `BigUint`. Each u8 of the input slice is
-    /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
+    /// Returns `self ^ exp`, or `None` if the result would need more than `max_bits` bits.
     ///
-    /// The bytes are in big-endian byte order.
-    /// `radix` must be in the range `2...256`.
+    /// The result is bounded from the size of `self` before multiplying, so this is safe to
+    /// call with an exponent from untrusted input: at most about twice `max_bits` bits are
+    /// ever allocated.  Any exponent is accepted for a base of zero or one.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{BigUint};
+    /// use num_bigint::BigUint;
     ///
-    /// let inbase190 = &[15, 33, 125, 12, 14];
-    /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
-    /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// let n = BigUint::from(3u32);
+    /// assert_eq!(n.checked_pow_max(5, 8), Some(BigUint::from(243u32)));
+    /// assert_eq!(n.checked_pow_max(6, 8), None);
+    /// assert_eq!(BigUint::from(0u32).checked_pow_max(1 << 40, 8), Some(BigUint::from(0u32)));
     /// ```
-    pub fn from_radix_be(buf: &[u8], radix: u32) -> Option<BigUint> {
-        assert!(
-            2 <= radix && radix <= 256,
-            "The radix must be within 2...256"
-        );
-
-        if radix != 256 && buf.iter().any(|&b| b >= radix as u8) {
-            return None;
+    pub fn checked_pow_max(&self, exp: u64, max_bits: u64) -> Option<BigUint> {
+        if self.is_zero() && exp != 0 {
+            return Some(BigUint::zero());
         }
-
-        let res = if radix.is_power_of_two() {
-            // Powers of two can use bitwise masks and shifting instead of multiplication
-            let bits = ilog2(radix);
-            let mut v = Vec::from(buf);
-            v.reverse();
-            if big_digit::BITS % bits == 0 {
-                from_bitwise_digits_le(&v, bits)
+        if self.is_one() || exp == 0 {
+            return if max_bits > 0 {
+                Some(BigUint::one())
             } else {
-                from_inexact_bitwise_digits_le(&v, bits)
-            }
-        } else {
-            from_radix_digits_be(buf, radix)
-        };
+                None
+            };
+        }
 
-        Some(res)
+        // `self ^ exp` has more than `(bits - 1) * exp` and at most `bits * exp` bits.
+        let bits = self.bit_len();
+        match (bits - 1).checked_mul(exp) {
+            Some(low) if low < max_bits => {}
+            _ => return None,
+        }
+        let result = self.pow(exp);
+        if result.bit_len() <= max_bits {
+            Some(result)
+        } else {
+            None
+        }
     }
 
-    /// Creates and initializes a `BigUint`. Each u8 of the input slice is
-    /// interpreted as one digit of the number
-    /// and must therefore be less than `radix`.
+    /// Returns `|self - other|` and whether the subtraction borrowed, which is when `other`
+    /// is greater than `self`.
     ///
-    /// The bytes are in little-endian byte order.
-    /// `radix` must be in the range `2...256`.
+    /// Unlike `u64::overflowing_sub`, the difference does not wrap: a `BigUint` has no fixed
+    /// width to wrap around.  The magnitude and the borrow flag together are the signed
+    /// difference, which is what multi-precision code built from `BigUint` limbs usually
+    /// needs; see [`WrappingBigUint`](struct.WrappingBigUint.html) for wrapping arithmetic.
     ///
     /// # Examples
     ///
     /// ```
-    /// use num_bigint::{BigUint};
+    /// use num_bigint::BigUint;
     ///
-    /// let inbase190 = &[14, 12, 125, 33, 15];
-    /// let a = BigUint::from_radix_be(inbase190, 190).unwrap();
-    /// assert_eq!(a.to_radix_be(190), inbase190);
+    /// let (a, b) = (BigUint::from(3u32), BigUint::from(5u32));
+    /// assert_eq!(a.overflowing_sub(&b), (BigUint::from(2u32), true));
+    /// assert_eq!(b.overflowing_sub(&a), (BigUint::from(2u32), false));
+    /// assert_eq!(a.overflowing_sub(&a), (BigUint::from(0u32), false));
     /// ```
-    pub fn from_radix_le(buf: &[u8], radix: u32) -> Option<BigUint> {
-        assert!(
-            2 <= radix && radix <= 256,
-            "The radix must be within 2...256"
-        );
-
-        if radix != 256 && buf.iter().any(|&b| b >= radix as u8) {
-            return None;
+    pub fn overflowing_sub(&self, other: &BigUint) -> (BigUint, bool) {
+        match self.cmp(other) {
+            Less => (other - self, true),
+            Equal => (BigUint::zero(), false),
+            Greater => (self - other, false),
         }
-
-        let res = if radix.is_power_of_two() {
-            // Powers of two can use bitwise masks and shifting instead of multiplication
-            let bits = ilog2(radix);
-            if big_digit::BITS % bits == 0 {
-                from_bitwise_digits_le(buf, bits)
-            } else {
-                from_inexact_bitwise_digits_le(buf, bits)
-            }
-        } else {
-            let mut v = Vec::from(buf);
-            v.reverse();
-            from_radix_digits_be(&v, radix)
-        };
-
-        Some(res)
     }
 
-    /// Returns the byte representation of the `BigUint` in big-endian byte order.
+    /// Divides `self` by `other` in place, leaving the quotient in `self` and returning the
+    /// remainder.
+    ///
+    /// This is equivalent to `Integer::div_rem`, but does not need to clone `self` first, which
+    /// saves an allocation per step in loops like the Euclidean algorithm.
+    ///
+    /// Panics if `other` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
-    /// assert_eq!(i.to_bytes_be(), vec![4, 101]);
+    /// let mut n = BigUint::from(100u32);
+    /// let r = n.div_rem_assign(&BigUint::from(7u32));
+    /// assert_eq!(n, BigUint::from(14u32));
+    /// assert_eq!(r, BigUint::from(2u32));
     /// ```
     #[inline]
-    pub fn to_bytes_be(&self) -> Vec<u8> {
-        let mut v = self.to_bytes_le();
-        v.reverse();
-        v
+    pub fn div_rem_assign(&mut self, other: &BigUint) -> BigUint {
+        div_rem_assign(self, other)
     }
 
-    /// Returns the byte representation of the `BigUint` in little-endian byte order.
+    /// Returns `self / other` rounded up, like `u64::div_ceil`.
+    ///
+    /// Panics if `other` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// let i = BigUint::parse_bytes(b"1125", 10).unwrap();
-    /// assert_eq!(i.to_bytes_le(), vec![101, 4]);
+    /// assert_eq!(BigUint::from(12u32).div_ceil(&BigUint::from(4u32)), BigUint::from(3u32));
+    /// assert_eq!(BigUint::from(13u32).div_ceil(&BigUint::from(4u32)), BigUint::from(4u32));
     /// ```
-    #[inline]
-    pub fn to_bytes_le(&self) -> Vec<u8> {
-        if self.is_zero() {
-            vec![0]
+    pub fn div_ceil(&self, other: &BigUint) -> BigUint {
+        let (mut q, r) = self.div_rem(other);
+        if !r.is_zero() {
+            q += 1u32;
+        }
+        q
+    }
+
+    /// Returns the smallest multiple of `other` that is at least `self`, like
+    /// `u64::next_multiple_of`.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let eight = BigUint::from(8u32);
+    /// assert_eq!(BigUint::from(16u32).next_multiple_of(&eight), BigUint::from(16u32));
+    /// assert_eq!(BigUint::from(23u32).next_multiple_of(&eight), BigUint::from(24u32));
+    /// ```
+    pub fn next_multiple_of(&self, other: &BigUint) -> BigUint {
+        let r = self % other;
+        if r.is_zero() {
+            self.clone()
         } else {
-            to_bitwise_digits_le(self, 8)
+            self + (other - r)
         }
     }
 
-    /// Returns the integer formatted as a string in the given radix.
-    /// `radix` must be in the range `2...36`.
+    /// Returns `self % other` as a `u32`, without allocating a `BigUint` remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// let i = BigUint::parse_bytes(b"ff", 16).unwrap();
-    /// assert_eq!(i.to_str_radix(16), "ff");
+    /// let n = BigUint::from(1u32) << 100;
+    /// assert_eq!(n.rem_u32(1000), 376);
     /// ```
     #[inline]
-    pub fn to_str_radix(&self, radix: u32) -> String {
-        let mut v = to_str_radix_reversed(self, radix);
-        v.reverse();
-        unsafe { String::from_utf8_unchecked(v) }
+    pub fn rem_u32(&self, other: u32) -> u32 {
+        assert!(other != 0, "divide by zero!");
+        rem_digit(self, other as BigDigit)
     }
 
-    /// Returns the integer in the requested base in big-endian digit order.
-    /// The output is not given in a human readable alphabet but as a zero
-    /// based u8 number.
-    /// `radix` must be in the range `2...256`.
+    /// Returns `self % other` as a `u64`, without allocating a `BigUint` remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_be(159),
-    ///            vec![2, 94, 27]);
-    /// // 0xFFFF = 65535 = 2*(159^2) + 94*159 + 27
+    /// let n = BigUint::from(1u32) << 100;
+    /// assert_eq!(n.rem_u64(10_000_000_000), 6_703_205_376);
     /// ```
     #[inline]
-    pub fn to_radix_be(&self, radix: u32) -> Vec<u8> {
-        let mut v = to_radix_le(self, radix);
-        v.reverse();
-        v
+    pub fn rem_u64(&self, other: u64) -> u64 {
+        assert!(other != 0, "divide by zero!");
+        if other <= u64::from(BigDigit::max_value()) {
+            u64::from(rem_digit(self, other as BigDigit))
+        } else {
+            rem_u64_wide(self, other)
+        }
     }
 
-    /// Returns the integer in the requested base in little-endian digit order.
-    /// The output is not given in a human readable alphabet but as a zero
-    /// based u8 number.
-    /// `radix` must be in the range `2...256`.
+    /// Returns `b` if `choice` is true, or `a` if it is false, in time that does not depend
+    /// on `choice`.
+    ///
+    /// Both inputs are padded to the same number of digits and every digit of each is read
+    /// and combined through a mask, so the only things that affect the running time are the
+    /// lengths of `a` and `b`, and of the result when it is normalized.
     ///
     /// # Examples
     ///
     /// ```
     /// use num_bigint::BigUint;
     ///
-    /// assert_eq!(BigUint::from(0xFFFFu64).to_radix_le(159),
-    ///            vec![27, 94, 2]);
-    /// // 0xFFFF = 65535 = 27 + 94*159 + 2*(159^2)
+    /// let a = BigUint::from(1u32);
+    /// let b = BigUint::from(1u32) << 100usize;
+    /// assert_eq!(BigUint::conditional_select(&a, &b, false), a);
+    /// assert_eq!(BigUint::conditional_select(&a, &b, true), b);
     /// ```
-    #[inline]
-    pub fn to_radix_le(&self, radix: u32) -> Vec<u8> {
-        to_radix_le(self, radix)
+    pub fn conditional_select(a: &BigUint, b: &BigUint, choice: bool) -> BigUint {
+        let mask = (0 as BigDigit).wrapping_sub(choice as BigDigit);
+        let len = cmp::max(a.data.len(), b.data.len());
+        let digit = |n: &BigUint, i: usize| n.data.get(i).cloned().unwrap_or(0);
+        let data = (0..len)
+            .map(|i| digit(a, i) ^ ((digit(a, i) ^ digit(b, i)) & mask))
+            .collect();
+        BigUint::new(data)
     }
 
-    /// Determines the fewest bits necessary to express the `BigUint`.
-    #[inline]
-    pub fn bits(&self) -> usize {
-        if self.is_zero() {
-            return 0;
+    /// Swaps `a` and `b` if `choice` is true, in time that does not depend on `choice`.
+    ///
+    /// As with [`conditional_select`](#method.conditional_select), both values are padded to
+    /// the same number of digits, and every digit is rewritten whether or not they swap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut a = BigUint::from(1u32);
+    /// let mut b = BigUint::from(1u32) << 100usize;
+    /// BigUint::conditional_swap(&mut a, &mut b, true);
+    /// assert_eq!(a, BigUint::from(1u32) << 100usize);
+    /// assert_eq!(b, BigUint::from(1u32));
+    /// ```
+    pub fn conditional_swap(a: &mut BigUint, b: &mut BigUint, choice: bool) {
+        let mask = (0 as BigDigit).wrapping_sub(choice as BigDigit);
+        let len = cmp::max(a.data.len(), b.data.len());
+        a.data.resize(len, 0);
+        b.data.resize(len, 0);
+        for (x, y) in a.data.iter_mut().zip(b.data.iter_mut()) {
+            let t = (*x ^ *y) & mask;
+            *x ^= t;
+            *y ^= t;
         }
-        let zeros = self.data.last().unwrap().leading_zeros();
-        return self.data.len() * big_digit::BITS - zeros as usize;
+        a.normalize();
+        b.normalize();
     }
 
     /// Strips off trailing zero bigdigits - comparisons require the last element in the vector to
@@ -2240,6 +4516,159 @@ impl BigUint {
     pub fn nth_root(&self, n: u32) -> Self {
         Roots::nth_root(self, n)
     }
+
+    /// Returns the base 2 logarithm of `self`, rounded down, or `None` if `self` is zero.
+    #[inline]
+    pub fn checked_ilog2(&self) -> Option<u64> {
+        match self.bit_len() {
+            0 => None,
+            bits => Some(bits - 1),
+        }
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` is zero.
+    pub fn ilog2(&self) -> u64 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is zero.
+    ///
+    /// This is one less than the number of decimal digits in `self`.
+    #[inline]
+    pub fn checked_ilog10(&self) -> Option<u64> {
+        self.checked_ilog(10)
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` is zero.
+    pub fn ilog10(&self) -> u64 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the logarithm of `self` in `base`, rounded down, or `None` if `self` is zero
+    /// or `base` is less than 2.
+    ///
+    /// The result is found from a table of the powers `base^(2^k)`, so it takes a few
+    /// multiplications of numbers up to the size of `self`, and no division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = (0..100).fold(BigUint::from(1u32), |n, _| n * 10u32);
+    /// assert_eq!(n.checked_ilog(10), Some(100));
+    /// assert_eq!((n - 1u32).checked_ilog(10), Some(99));
+    /// assert_eq!(BigUint::from(80u32).checked_ilog(3), Some(3));
+    /// assert_eq!(BigUint::from(0u32).checked_ilog(3), None);
+    /// ```
+    pub fn checked_ilog(&self, base: u32) -> Option<u64> {
+        if base < 2 || self.is_zero() {
+            return None;
+        }
+        if base.is_power_of_two() {
+            let log2 = self.bit_len() - 1;
+            return Some(log2 / u64::from(base.trailing_zeros()));
+        }
+        if let Some(mut n) = self.to_u64() {
+            let mut log = 0;
+            while n >= u64::from(base) {
+                n /= u64::from(base);
+                log += 1;
+            }
+            return Some(log);
+        }
+
+        // powers[k] == base^(2^k), for every such power that is at most `self`.
+        let mut powers = vec![BigUint::from(base)];
+        loop {
+            let next = {
+                let last = powers.last().unwrap();
+                last * last
+            };
+            if next > *self {
+                break;
+            }
+            powers.push(next);
+        }
+
+        // Multiply in the powers from the largest down, keeping the product at most `self`.
+        let mut acc = BigUint::one();
+        let mut log = 0;
+        for (k, power) in powers.iter().enumerate().rev() {
+            let next = &acc * power;
+            if next <= *self {
+                acc = next;
+                log += 1 << k;
+            }
+        }
+        Some(log)
+    }
+
+    /// Returns the logarithm of `self` in `base`, rounded down -- see
+    /// [`checked_ilog`](#method.checked_ilog).
+    ///
+    /// Panics if `self` is zero or `base` is less than 2.
+    pub fn ilog(&self, base: u32) -> u64 {
+        assert!(base >= 2, "base of integer logarithm must be at least 2");
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the product of the values, multiplied in a balanced binary tree, or one if
+    /// there are none.
+    ///
+    /// Each multiplication then has operands of similar size, which makes long products
+    /// like factorials much faster than multiplying in order as `Product` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let factorial = BigUint::product_tree(1..1001u32);
+    /// assert_eq!(factorial, (1..1001u32).map(BigUint::from).product());
+    /// ```
+    pub fn product_tree<I>(iter: I) -> BigUint
+    where
+        I: IntoIterator,
+        I::Item: Into<BigUint>,
+    {
+        product_tree(iter.into_iter().map(Into::into))
+    }
+}
+
+/// Multiplies the values pairwise like the carries of a binary counter, so that each
+/// product at level `k` covers `2^k` values.
+pub fn product_tree<T, I>(iter: I) -> T
+where
+    T: One + Mul<T, Output = T>,
+    I: Iterator<Item = T>,
+{
+    let mut stack: Vec<(T, u32)> = Vec::new();
+    for x in iter {
+        let mut x = x;
+        let mut level = 0;
+        while stack.last().map_or(false, |&(_, l)| l == level) {
+            let (y, _) = stack.pop().unwrap();
+            x = y * x;
+            level += 1;
+        }
+        stack.push((x, level));
+    }
+    let mut product = match stack.pop() {
+        Some((x, _)) => x,
+        None => return T::one(),
+    };
+    while let Some((x, _)) = stack.pop() {
+        product = x * product;
+    }
+    product
 }
 
 fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> BigUint {
@@ -2341,6 +4770,32 @@ fn test_plain_modpow() {
     );
 }
 
+// Reverses the bits of a digit, without `u32::reverse_bits` from Rust 1.37.
+fn reverse_digit(mut d: BigDigit) -> BigDigit {
+    d = (d >> 1) & 0x5555_5555 | (d & 0x5555_5555) << 1;
+    d = (d >> 2) & 0x3333_3333 | (d & 0x3333_3333) << 2;
+    d = (d >> 4) & 0x0f0f_0f0f | (d & 0x0f0f_0f0f) << 4;
+    d.swap_bytes()
+}
+
+/// Returns the number of ones in the low `bits` bits of `u`.
+pub fn count_ones_low(u: &BigUint, bits: u64) -> u64 {
+    let digit_bits = big_digit::BITS as u64;
+    let full = cmp::min(bits / digit_bits, u.data.len() as u64) as usize;
+    let mut count: u64 = u.data[..full]
+        .iter()
+        .map(|d| u64::from(d.count_ones()))
+        .sum();
+    let rem = bits % digit_bits;
+    if let Some(&hi) = u.data.get(full) {
+        if rem != 0 {
+            let mask = (1 << rem) - 1;
+            count += u64::from((hi & mask).count_ones());
+        }
+    }
+    count
+}
+
 /// Returns the number of least-significant bits that are zero,
 /// or `None` if the entire number is zero.
 pub fn trailing_zeros(u: &BigUint) -> Option<usize> {
@@ -2355,7 +4810,6 @@ impl_sum_iter_type!(BigUint);
 impl_product_iter_type!(BigUint);
 
 pub trait IntDigits {
-    fn digits(&self) -> &[BigDigit];
     fn digits_mut(&mut self) -> &mut Vec<BigDigit>;
     fn normalize(&mut self);
     fn capacity(&self) -> usize;
@@ -2363,10 +4817,6 @@ pub trait IntDigits {
 }
 
 impl IntDigits for BigUint {
-    #[inline]
-    fn digits(&self) -> &[BigDigit] {
-        &self.data
-    }
     #[inline]
     fn digits_mut(&mut self) -> &mut Vec<BigDigit> {
         &mut self.data
@@ -2404,6 +4854,22 @@ fn u32_from_u128(n: u128) -> (u32, u32, u32, u32) {
     )
 }
 
+/// Returns `a % b` for a `b` wider than one digit.
+#[cfg(has_i128)]
+fn rem_u64_wide(a: &BigUint, b: u64) -> u64 {
+    let mut rem: u128 = 0;
+    for &digit in a.data.iter().rev() {
+        rem = ((rem << big_digit::BITS) | u128::from(digit)) % u128::from(b);
+    }
+    rem as u64
+}
+
+/// Returns `a % b` for a `b` wider than one digit.
+#[cfg(not(has_i128))]
+fn rem_u64_wide(a: &BigUint, b: u64) -> u64 {
+    (a % b).to_u64().unwrap()
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for BigUint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -2413,8 +4879,7 @@ impl serde::Serialize for BigUint {
         // Note: do not change the serialization format, or it may break forward
         // and backward compatibility of serialized data!  If we ever change the
         // internal representation, we should still serialize in base-`u32`.
-        let data: &Vec<u32> = &self.data;
-        data.serialize(serializer)
+        serializer.collect_seq(self.iter_u32_digits())
     }
 }
 
@@ -3026,6 +5491,32 @@ fn test_assign_from_slice() {
     check(&[-1i32 as BigDigit], &[-1i32 as BigDigit]);
 }
 
+#[test]
+fn test_from_slice_u64() {
+    fn check(slice: &[u64], data: &[BigDigit]) {
+        assert!(BigUint::from_slice_u64(slice).data == data);
+    }
+    check(&[1], &[1]);
+    check(&[0, 0, 0], &[]);
+    check(&[1 << 32, 2, 0], &[0, 1, 2]);
+    check(&[0, 0, 1, 2], &[0, 0, 0, 0, 1, 0, 2]);
+    check(&[-1i64 as u64], &[-1i32 as BigDigit, -1i32 as BigDigit]);
+}
+
+#[test]
+fn test_assign_from_slice_u64() {
+    fn check(slice: &[u64], data: &[BigDigit]) {
+        let mut p = BigUint::from_slice(&[2627_u32, 0_u32, 9182_u32, 42_u32]);
+        p.assign_from_slice_u64(slice);
+        assert!(p.data == data);
+    }
+    check(&[1], &[1]);
+    check(&[0, 0, 0], &[]);
+    check(&[1 << 32, 2, 0], &[0, 1, 2]);
+    check(&[0, 0, 1, 2], &[0, 0, 0, 0, 1, 0, 2]);
+    check(&[-1i64 as u64], &[-1i32 as BigDigit, -1i32 as BigDigit]);
+}
+
 #[cfg(has_i128)]
 #[test]
 fn test_u32_u128() {
@@ -0,0 +1,191 @@
+//! CBOR bignum encoding, as described in RFC 8949 section 3.4.3.
+//!
+//! A bignum is a byte string holding the big-endian magnitude, wrapped in tag 2 for an
+//! unsigned value `n`, or in tag 3 for a negative value `-1 - n`.
+
+use BigInt;
+use BigUint;
+use Sign::{self, Minus, NoSign, Plus};
+
+use bigint::magnitude;
+
+use traits::{One, Zero};
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TAG: u8 = 6;
+
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// Appends an item head with the shortest encoding of `arg`.
+fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = major << 5;
+    if arg < 24 {
+        out.push(major | arg as u8);
+    } else if arg <= u64::from(u8::max_value()) {
+        out.push(major | 24);
+        out.push(arg as u8);
+    } else if arg <= u64::from(u16::max_value()) {
+        out.push(major | 25);
+        out.extend((0..2).rev().map(|i| (arg >> (i * 8)) as u8));
+    } else if arg <= u64::from(u32::max_value()) {
+        out.push(major | 26);
+        out.extend((0..4).rev().map(|i| (arg >> (i * 8)) as u8));
+    } else {
+        out.push(major | 27);
+        out.extend((0..8).rev().map(|i| (arg >> (i * 8)) as u8));
+    }
+}
+
+/// Reads an item head, returning its major type, argument, and the remaining input.
+///
+/// Indefinite lengths and the reserved additional information values are rejected.
+fn read_head(input: &[u8]) -> Option<(u8, u64, &[u8])> {
+    let (&initial, rest) = match input.split_first() {
+        Some(split) => split,
+        None => return None,
+    };
+    let major = initial >> 5;
+    let len = match initial & 0x1f {
+        info if info < 24 => return Some((major, u64::from(info), rest)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return None,
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (arg, rest) = rest.split_at(len);
+    let arg = arg.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    Some((major, arg, rest))
+}
+
+/// Encodes a tagged bignum with the given tag and magnitude.
+fn encode(tag: u64, n: &BigUint) -> Vec<u8> {
+    let bytes = if n.is_zero() {
+        Vec::new()
+    } else {
+        n.to_bytes_be()
+    };
+    let mut out = Vec::with_capacity(bytes.len() + 11);
+    write_head(&mut out, MAJOR_TAG, tag);
+    write_head(&mut out, MAJOR_BYTES, bytes.len() as u64);
+    out.extend(bytes);
+    out
+}
+
+/// Decodes a single CBOR integer or bignum, which must span the entire input.
+///
+/// The result is the sign of the item along with `n` for an unsigned value, or with
+/// `-1 - value` for a negative value.
+fn decode(input: &[u8]) -> Option<(Sign, BigUint)> {
+    let (major, arg, rest) = match read_head(input) {
+        Some(head) => head,
+        None => return None,
+    };
+    let (sign, rest) = match major {
+        MAJOR_UNSIGNED if rest.is_empty() => return Some((Plus, BigUint::from(arg))),
+        MAJOR_NEGATIVE if rest.is_empty() => return Some((Minus, BigUint::from(arg))),
+        MAJOR_TAG if arg == TAG_POSITIVE_BIGNUM => (Plus, rest),
+        MAJOR_TAG if arg == TAG_NEGATIVE_BIGNUM => (Minus, rest),
+        _ => return None,
+    };
+    match read_head(rest) {
+        Some((MAJOR_BYTES, len, bytes)) if bytes.len() as u64 == len => {
+            Some((sign, BigUint::from_bytes_be(bytes)))
+        }
+        _ => None,
+    }
+}
+
+impl BigUint {
+    /// Encodes the `BigUint` as a CBOR bignum: a byte string of the big-endian magnitude
+    /// without leading zeros, wrapped in tag 2 (RFC 8949 section 3.4.3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::from(1u32) << 64;
+    /// assert_eq!(
+    ///     i.to_cbor_bignum(),
+    ///     vec![0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0]
+    /// );
+    /// ```
+    pub fn to_cbor_bignum(&self) -> Vec<u8> {
+        encode(TAG_POSITIVE_BIGNUM, self)
+    }
+
+    /// Decodes a CBOR bignum with tag 2, or a plain CBOR unsigned integer, as produced by
+    /// encoders using the preferred serialization for small values.
+    ///
+    /// The input must hold exactly one item, and the byte string must have a definite
+    /// length.  Returns `None` if the input is malformed or the value is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::from_cbor_bignum(&[0xc2, 0x42, 0x01, 0x00]).unwrap();
+    /// assert_eq!(i, BigUint::from(256u32));
+    /// assert_eq!(BigUint::from_cbor_bignum(&[0x19, 0x01, 0x00]), Some(i));
+    /// assert_eq!(BigUint::from_cbor_bignum(&[0xc3, 0x40]), None);
+    /// ```
+    pub fn from_cbor_bignum(bytes: &[u8]) -> Option<BigUint> {
+        match decode(bytes) {
+            Some((Plus, n)) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl BigInt {
+    /// Encodes the `BigInt` as a CBOR bignum.  A non-negative value `n` is written as a
+    /// byte string of `n` in tag 2, and a negative value `-1 - n` as a byte string of `n`
+    /// in tag 3 (RFC 8949 section 3.4.3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(256).to_cbor_bignum(), vec![0xc2, 0x42, 0x01, 0x00]);
+    /// assert_eq!(BigInt::from(-256).to_cbor_bignum(), vec![0xc3, 0x41, 0xff]);
+    /// assert_eq!(BigInt::from(-1).to_cbor_bignum(), vec![0xc3, 0x40]);
+    /// ```
+    pub fn to_cbor_bignum(&self) -> Vec<u8> {
+        match self.sign() {
+            Minus => encode(TAG_NEGATIVE_BIGNUM, &(magnitude(self) - 1u32)),
+            NoSign | Plus => encode(TAG_POSITIVE_BIGNUM, magnitude(self)),
+        }
+    }
+
+    /// Decodes a CBOR bignum with tag 2 or 3, or a plain CBOR integer, as produced by
+    /// encoders using the preferred serialization for small values.
+    ///
+    /// The input must hold exactly one item, and the byte string must have a definite
+    /// length.  Returns `None` if the input is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let i = BigInt::from_cbor_bignum(&[0xc3, 0x41, 0xff]).unwrap();
+    /// assert_eq!(i, BigInt::from(-256));
+    /// assert_eq!(BigInt::from_cbor_bignum(&[0x39, 0x00, 0xff]), Some(i));
+    /// ```
+    pub fn from_cbor_bignum(bytes: &[u8]) -> Option<BigInt> {
+        match decode(bytes) {
+            Some((Minus, n)) => Some(BigInt::from_biguint(Minus, n + BigUint::one())),
+            Some((_, n)) => Some(BigInt::from(n)),
+            None => None,
+        }
+    }
+}
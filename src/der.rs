@@ -0,0 +1,153 @@
+//! ASN.1 DER encoding of INTEGER values, as described in ITU-T X.690.
+//!
+//! An INTEGER is written as the tag `0x02`, a definite length, and the minimal
+//! big-endian two's complement content octets of the value.
+
+use BigInt;
+use BigUint;
+
+const TAG_INTEGER: u8 = 0x02;
+
+/// Encodes a complete INTEGER from its content octets.
+fn encode(content: Vec<u8>) -> Vec<u8> {
+    let len = content.len();
+    let mut out = Vec::with_capacity(len + 10);
+    out.push(TAG_INTEGER);
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let len_bytes = (0..::std::mem::size_of::<usize>())
+            .rev()
+            .map(|i| (len >> (i * 8)) as u8)
+            .skip_while(|&b| b == 0)
+            .collect::<Vec<u8>>();
+        out.push(0x80 | len_bytes.len() as u8);
+        out.extend(len_bytes);
+    }
+    out.extend(content);
+    out
+}
+
+/// Decodes a complete INTEGER, returning its content octets.
+///
+/// The encoding must be the entire input and follow the DER rules: a minimal definite
+/// length, and minimal non-empty content octets.
+fn decode(input: &[u8]) -> Option<&[u8]> {
+    let rest = match input.split_first() {
+        Some((&TAG_INTEGER, rest)) => rest,
+        _ => return None,
+    };
+    let (&first, rest) = match rest.split_first() {
+        Some(split) => split,
+        None => return None,
+    };
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let n = (first & 0x7f) as usize;
+        if n == 0 || n > ::std::mem::size_of::<usize>() || rest.len() < n {
+            return None;
+        }
+        let (len_bytes, rest) = rest.split_at(n);
+        if len_bytes[0] == 0 {
+            return None;
+        }
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        if len < 0x80 {
+            return None;
+        }
+        (len, rest)
+    };
+    if rest.len() != len {
+        return None;
+    }
+    match (rest.first(), rest.get(1)) {
+        (None, _) => None,
+        (Some(&0x00), Some(&b)) if b < 0x80 => None,
+        (Some(&0xff), Some(&b)) if b >= 0x80 => None,
+        _ => Some(rest),
+    }
+}
+
+impl BigUint {
+    /// Encodes the `BigUint` as an ASN.1 DER INTEGER, including the tag and length.
+    ///
+    /// The content octets are the minimal two's complement representation, so a leading
+    /// zero byte is added when the most significant bit of the magnitude is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(127u32).to_der_integer(), vec![0x02, 0x01, 0x7f]);
+    /// assert_eq!(BigUint::from(128u32).to_der_integer(), vec![0x02, 0x02, 0x00, 0x80]);
+    /// ```
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        let mut content = self.to_bytes_be();
+        if content[0] >= 0x80 {
+            content.insert(0, 0);
+        }
+        encode(content)
+    }
+
+    /// Decodes an ASN.1 DER INTEGER, including the tag and length, which must span the
+    /// entire input.
+    ///
+    /// Returns `None` if the encoding is not valid DER, such as a non-minimal length or
+    /// redundant leading `0x00` or `0xff` content octets, or if the value is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let i = BigUint::from_der_integer(&[0x02, 0x02, 0x00, 0x80]).unwrap();
+    /// assert_eq!(i, BigUint::from(128u32));
+    /// assert_eq!(BigUint::from_der_integer(&[0x02, 0x01, 0x80]), None);
+    /// assert_eq!(BigUint::from_der_integer(&[0x02, 0x02, 0x00, 0x7f]), None);
+    /// ```
+    pub fn from_der_integer(bytes: &[u8]) -> Option<BigUint> {
+        match decode(bytes) {
+            Some(content) if content[0] < 0x80 => Some(BigUint::from_bytes_be(content)),
+            _ => None,
+        }
+    }
+}
+
+impl BigInt {
+    /// Encodes the `BigInt` as an ASN.1 DER INTEGER, including the tag and length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-128).to_der_integer(), vec![0x02, 0x01, 0x80]);
+    /// assert_eq!(BigInt::from(-129).to_der_integer(), vec![0x02, 0x02, 0xff, 0x7f]);
+    /// ```
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        encode(self.to_signed_bytes_be())
+    }
+
+    /// Decodes an ASN.1 DER INTEGER, including the tag and length, which must span the
+    /// entire input.
+    ///
+    /// Returns `None` if the encoding is not valid DER, such as a non-minimal length or
+    /// redundant leading `0x00` or `0xff` content octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let i = BigInt::from_der_integer(&[0x02, 0x02, 0xff, 0x7f]).unwrap();
+    /// assert_eq!(i, BigInt::from(-129));
+    /// assert_eq!(BigInt::from_der_integer(&[0x02, 0x02, 0xff, 0x80]), None);
+    /// ```
+    pub fn from_der_integer(bytes: &[u8]) -> Option<BigInt> {
+        decode(bytes).map(BigInt::from_signed_bytes_be)
+    }
+}
@@ -0,0 +1,135 @@
+//! A C-compatible representation of big integers.
+
+use std::mem;
+use std::slice;
+
+use BigInt;
+use BigUint;
+use Sign::{self, Minus, NoSign, Plus};
+
+use bigint::magnitude;
+use traits::Zero;
+
+/// A big integer laid out for C: a sign and a pointer to the magnitude as little-endian
+/// 64-bit words, least significant first.
+///
+/// The C equivalent is:
+///
+/// ```c
+/// struct RawBigInt {
+///     int32_t sign;          /* -1, 0, or 1 */
+///     size_t len;            /* number of words */
+///     const uint64_t *words; /* least significant first */
+/// };
+/// ```
+///
+/// A `RawBigInt` made by [`BigInt::into_raw`](struct.BigInt.html#method.into_raw) or
+/// [`BigUint::into_raw`](struct.BigUint.html#method.into_raw) owns its words, which must
+/// be released by passing it back to [`BigInt::from_raw`](struct.BigInt.html#method.from_raw).
+/// One describing words owned by C can be read with [`to_bigint`](#method.to_bigint).
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigInt, RawBigInt};
+///
+/// #[no_mangle]
+/// pub extern "C" fn factorial(n: u32) -> RawBigInt {
+///     (1..n + 1).map(BigInt::from).product::<BigInt>().into_raw()
+/// }
+///
+/// #[no_mangle]
+/// pub unsafe extern "C" fn free_bigint(raw: RawBigInt) {
+///     drop(BigInt::from_raw(raw));
+/// }
+///
+/// let raw = factorial(25);
+/// assert_eq!(raw.sign, 1);
+/// assert_eq!(unsafe { raw.words() }, &[0x619f_b090_7bc0_0000, 0xc_d4a0]);
+/// unsafe { free_bigint(raw) };
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RawBigInt {
+    /// The sign: `-1` if negative, `0` if zero, or `1` if positive.
+    pub sign: i32,
+    /// The number of words in the magnitude.
+    pub len: usize,
+    /// The words of the magnitude, least significant first.
+    pub words: *const u64,
+}
+
+impl RawBigInt {
+    fn new(sign: Sign, n: &BigUint) -> RawBigInt {
+        let words = n.to_u64_digits().into_boxed_slice();
+        let raw = RawBigInt {
+            sign: i32::from(sign.to_i8()),
+            len: words.len(),
+            words: words.as_ptr(),
+        };
+        mem::forget(words);
+        raw
+    }
+
+    /// Returns the words of the magnitude, least significant first.
+    ///
+    /// # Safety
+    ///
+    /// `words` must point to `len` initialized words, unless `len` is zero, and they must
+    /// not be modified or freed while the slice is in use.
+    pub unsafe fn words(&self) -> &[u64] {
+        if self.len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(self.words, self.len)
+        }
+    }
+
+    /// Copies the value into a new `BigInt`, leaving the words untouched.
+    ///
+    /// Any positive `sign` is taken as positive and any negative one as negative, and a
+    /// zero magnitude is zero regardless of `sign`.
+    ///
+    /// # Safety
+    ///
+    /// The words must be valid as for [`words`](#method.words).
+    pub unsafe fn to_bigint(&self) -> BigInt {
+        let sign = if self.sign < 0 { Minus } else { Plus };
+        BigInt::from_biguint(sign, BigUint::from_slice_u64(self.words()))
+    }
+}
+
+impl BigUint {
+    /// Converts the `BigUint` into a C-compatible [`RawBigInt`](struct.RawBigInt.html),
+    /// transferring ownership of its words.
+    ///
+    /// The words must be released with [`BigInt::from_raw`](struct.BigInt.html#method.from_raw).
+    pub fn into_raw(self) -> RawBigInt {
+        let sign = if self.is_zero() { NoSign } else { Plus };
+        RawBigInt::new(sign, &self)
+    }
+}
+
+impl BigInt {
+    /// Converts the `BigInt` into a C-compatible [`RawBigInt`](struct.RawBigInt.html),
+    /// transferring ownership of its words.
+    ///
+    /// The words must be released with [`BigInt::from_raw`](#method.from_raw).
+    pub fn into_raw(self) -> RawBigInt {
+        RawBigInt::new(self.sign(), magnitude(&self))
+    }
+
+    /// Takes back a [`RawBigInt`](struct.RawBigInt.html) made by `into_raw`, releasing
+    /// its words.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must have come from `BigInt::into_raw` or `BigUint::into_raw`, with its fields
+    /// unchanged, and must not be used again afterwards.
+    pub unsafe fn from_raw(raw: RawBigInt) -> BigInt {
+        let n = raw.to_bigint();
+        let words = slice::from_raw_parts_mut(raw.words as *mut u64, raw.len);
+        drop(Box::from_raw(words as *mut [u64]));
+        n
+    }
+}
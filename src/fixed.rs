@@ -0,0 +1,294 @@
+//! Fixed-width unsigned integers, like `u256`, with wrapping and checked arithmetic.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use big_digit::{self, BigDigit};
+
+use super::algorithms::{__add2, __sub2rev, mac_with_carry};
+use super::BigUint;
+
+/// An unsigned integer of exactly `LIMBS` 32-bit limbs, so its arithmetic wraps modulo
+/// 2^(32 · `LIMBS`), like a primitive unsigned integer.
+///
+/// The limbs are stored inline, least significant first, and the type is `Copy`.  Each
+/// operation comes in a `wrapping_`, `checked_` and `overflowing_` form, as for the
+/// primitive types, and conversions to and from `BigUint` reach the rest of this crate.
+///
+/// This type needs Rust 1.51 or later, for const generics.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, FixedBigUint};
+///
+/// type U256 = FixedBigUint<8>;
+///
+/// let x = U256::MAX;
+/// assert_eq!(x.wrapping_add(&U256::from_u64(2)), U256::from_u64(1));
+/// assert_eq!(x.checked_add(&U256::from_u64(2)), None);
+///
+/// let big = BigUint::from(1u32) << 200usize;
+/// let y = U256::from_biguint(&big).unwrap();
+/// assert_eq!(y.wrapping_mul(&y), U256::ZERO);
+/// assert_eq!(y.to_biguint(), big);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBigUint<const LIMBS: usize> {
+    limbs: [BigDigit; LIMBS],
+}
+
+impl<const LIMBS: usize> FixedBigUint<LIMBS> {
+    /// The number of bits, 32 · `LIMBS`.
+    pub const BITS: usize = big_digit::BITS * LIMBS;
+
+    /// Zero.
+    pub const ZERO: Self = FixedBigUint { limbs: [0; LIMBS] };
+
+    /// The largest value, 2^(32 · `LIMBS`) - 1.
+    pub const MAX: Self = FixedBigUint { limbs: [!0; LIMBS] };
+
+    /// Creates an integer from its limbs, least significant first.
+    #[inline]
+    pub fn from_limbs(limbs: [BigDigit; LIMBS]) -> Self {
+        FixedBigUint { limbs }
+    }
+
+    /// Returns the limbs, least significant first.
+    #[inline]
+    pub fn as_limbs(&self) -> &[BigDigit; LIMBS] {
+        &self.limbs
+    }
+
+    /// Creates an integer from a `u64`, which is truncated if `LIMBS` is less than two.
+    #[inline]
+    pub fn from_u64(n: u64) -> Self {
+        let (hi, lo) = big_digit::from_doublebigdigit(n);
+        let mut limbs = [0; LIMBS];
+        for (limb, &digit) in limbs.iter_mut().zip(&[lo, hi]) {
+            *limb = digit;
+        }
+        FixedBigUint { limbs }
+    }
+
+    /// Copies a `BigUint`, or returns `None` if it needs more than `LIMBS` limbs.
+    pub fn from_biguint(n: &BigUint) -> Option<Self> {
+        if n.bit_len() > Self::BITS as u64 {
+            return None;
+        }
+        Some(Self::from_biguint_wrapping(n))
+    }
+
+    /// Copies a `BigUint` modulo 2^(32 · `LIMBS`), dropping any higher limbs.
+    pub fn from_biguint_wrapping(n: &BigUint) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (limb, &digit) in limbs.iter_mut().zip(&n.data) {
+            *limb = digit;
+        }
+        FixedBigUint { limbs }
+    }
+
+    /// Copies this integer into a `BigUint`.
+    #[inline]
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::from_slice(&self.limbs)
+    }
+
+    /// Returns `true` if this integer is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&limb| limb == 0)
+    }
+
+    /// Determines the fewest bits necessary to express this integer.
+    pub fn bits(&self) -> usize {
+        match self.limbs.iter().rposition(|&limb| limb != 0) {
+            Some(i) => big_digit::BITS * (i + 1) - self.limbs[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns `self + other` and whether it overflowed, in which case the sum has wrapped.
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = self.limbs;
+        let carry = __add2(&mut limbs, &other.limbs);
+        (FixedBigUint { limbs }, carry != 0)
+    }
+
+    /// Returns `self - other` and whether it overflowed, in which case the difference has
+    /// wrapped.
+    pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = other.limbs;
+        let borrow = __sub2rev(&self.limbs, &mut limbs);
+        (FixedBigUint { limbs }, borrow != 0)
+    }
+
+    /// Returns `self * other` and whether it overflowed, in which case the product has
+    /// wrapped.
+    pub fn overflowing_mul(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+        for (i, &a) in self.limbs.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            // Only the low `LIMBS - i` limbs of `other` stay in range in this row.
+            let (lo, hi) = other.limbs.split_at(LIMBS - i);
+            let mut carry = 0;
+            for (acc, &b) in limbs[i..].iter_mut().zip(lo) {
+                *acc = mac_with_carry(*acc, b, a, &mut carry);
+            }
+            overflow |= carry != 0 || hi.iter().any(|&b| b != 0);
+        }
+        (FixedBigUint { limbs }, overflow)
+    }
+
+    /// Returns `self + other`, wrapping around on overflow.
+    #[inline]
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Returns `self - other`, wrapping around on overflow.
+    #[inline]
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Returns `self * other`, wrapping around on overflow.
+    #[inline]
+    pub fn wrapping_mul(&self, other: &Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    /// Returns `-self`, wrapping around, which is 2^(32 · `LIMBS`) - `self` unless `self`
+    /// is zero.
+    #[inline]
+    pub fn wrapping_neg(&self) -> Self {
+        Self::ZERO.wrapping_sub(self)
+    }
+
+    /// Returns `self + other`, or `None` on overflow.
+    #[inline]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        checked(self.overflowing_add(other))
+    }
+
+    /// Returns `self - other`, or `None` on overflow.
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        checked(self.overflowing_sub(other))
+    }
+
+    /// Returns `self * other`, or `None` on overflow.
+    #[inline]
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        checked(self.overflowing_mul(other))
+    }
+
+    /// Returns the quotient and remainder of `self / other`, or `None` if `other` is zero.
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        if *self < *other {
+            return Some((Self::ZERO, *self));
+        }
+
+        // Plain binary long division, which needs no room beyond the two results.
+        let mut q = [0; LIMBS];
+        let mut r = Self::ZERO;
+        for i in (0..self.bits()).rev() {
+            let mut carry = self.limbs[i / big_digit::BITS] >> (i % big_digit::BITS) & 1;
+            for limb in &mut r.limbs {
+                let hi = *limb >> (big_digit::BITS - 1);
+                *limb = *limb << 1 | carry;
+                carry = hi;
+            }
+            if carry != 0 || r >= *other {
+                r = r.wrapping_sub(other);
+                q[i / big_digit::BITS] |= 1 << (i % big_digit::BITS);
+            }
+        }
+        Some((FixedBigUint { limbs: q }, r))
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(q, _)| q)
+    }
+
+    /// Returns `self % other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(_, r)| r)
+    }
+}
+
+fn checked<T>((value, overflow): (T, bool)) -> Option<T> {
+    if overflow {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+impl<const LIMBS: usize> Default for FixedBigUint<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for FixedBigUint<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for FixedBigUint<LIMBS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for FixedBigUint<LIMBS> {
+    #[inline]
+    fn from(n: u64) -> Self {
+        Self::from_u64(n)
+    }
+}
+
+impl<const LIMBS: usize> From<FixedBigUint<LIMBS>> for BigUint {
+    #[inline]
+    fn from(n: FixedBigUint<LIMBS>) -> BigUint {
+        n.to_biguint()
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for FixedBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_biguint(), f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Display for FixedBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for FixedBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.to_biguint(), f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for FixedBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.to_biguint(), f)
+    }
+}
@@ -0,0 +1,280 @@
+//! Human-readable decimal formatting.
+
+use std::cmp;
+use std::str;
+
+use BigInt;
+use BigUint;
+
+use bigint::magnitude;
+use traits::{Pow, Signed};
+
+/// Inserts `separator` between each group of `size` digits, counting from the right.
+fn group_digits(digits: &str, separator: char, size: usize) -> String {
+    assert!(size != 0, "The group size must not be zero");
+    let len = digits.len();
+    let mut s = String::with_capacity(len + len / size * separator.len_utf8());
+    for (i, c) in digits.chars().enumerate() {
+        if i != 0 && (len - i) % size == 0 {
+            s.push(separator);
+        }
+        s.push(c);
+    }
+    s
+}
+
+/// Returns at least `count` leading decimal digits of `n`, or all of them if it has fewer,
+/// and how many digits follow them.
+///
+/// The digits that are not needed are divided away before converting to decimal, so a
+/// huge number is not written out in full only to be rounded.
+fn leading_digits(n: &BigUint, count: usize) -> (String, usize) {
+    let bits = n.bit_len().saturating_sub(1);
+    // `n` has more than `bits * log10(2)` digits, and `log10(2) > 0.30102`
+    let len = bits / 100_000 * 30_102 + bits % 100_000 * 30_102 / 100_000 + 1;
+    let dropped = len.saturating_sub(count as u64) as usize;
+    if dropped == 0 {
+        (n.to_str_radix(10), 0)
+    } else {
+        let m = n / BigUint::from(10u32).pow(dropped);
+        (m.to_str_radix(10), dropped)
+    }
+}
+
+/// Rounds the leading decimal digits of a number, followed by `dropped` more digits, to
+/// `sig` significant digits, half away from zero, returning those digits and the power of
+/// ten of the first.
+///
+/// `digits` must have more than `sig` digits if any were dropped.
+fn round_significant(digits: &str, dropped: usize, sig: usize) -> (Vec<u8>, usize) {
+    assert!(
+        sig != 0,
+        "The number of significant digits must not be zero"
+    );
+    let bytes = digits.as_bytes();
+    let mut exp = bytes.len() - 1 + dropped;
+    if bytes.len() <= sig {
+        let mut v = bytes.to_vec();
+        v.resize(sig, b'0');
+        return (v, exp);
+    }
+    let mut v = bytes[..sig].to_vec();
+    if bytes[sig] >= b'5' {
+        match v.iter().rposition(|&d| d != b'9') {
+            Some(i) => {
+                v[i] += 1;
+                for d in &mut v[i + 1..] {
+                    *d = b'0';
+                }
+            }
+            None => {
+                // all nines carry into a new leading digit
+                v[0] = b'1';
+                for d in &mut v[1..] {
+                    *d = b'0';
+                }
+                exp += 1;
+            }
+        }
+    }
+    (v, exp)
+}
+
+/// Writes a number in engineering notation with `sig` significant digits.
+fn engineering(n: &BigUint, sig: usize) -> String {
+    let (digits, dropped) = leading_digits(n, sig.saturating_add(1));
+    let (mut v, exp) = round_significant(&digits, dropped, sig);
+    let int_len = exp % 3 + 1;
+    if v.len() < int_len {
+        v.resize(int_len, b'0');
+    }
+    let mut s = String::with_capacity(v.len() + 8);
+    s.push_str(str::from_utf8(&v[..int_len]).unwrap());
+    if v.len() > int_len {
+        s.push('.');
+        s.push_str(str::from_utf8(&v[int_len..]).unwrap());
+    }
+    s.push('e');
+    s.push_str(&(exp - exp % 3).to_string());
+    s
+}
+
+/// Lists the 32-bit digits of a number in hex, least significant first.
+fn hex_limbs(n: &BigUint) -> String {
+    let limbs: Vec<String> = n
+        .iter_u32_digits()
+        .map(|d| format!("{:#010x}", d))
+        .collect();
+    format!("[{}]", limbs.join(", "))
+}
+
+/// Returns the number in decimal for `Display`.
+///
+/// With a precision, a number with more digits than that is rounded to `precision`
+/// significant digits and written in scientific notation, whose exponent shows that
+/// digits were dropped.  A precision of zero is taken as one.
+pub fn to_str_decimal(n: &BigUint, precision: Option<usize>) -> String {
+    let p = match precision {
+        Some(p) => p,
+        None => return n.to_str_radix(10),
+    };
+    let sig = cmp::max(p, 1);
+    // one more digit than is kept decides the rounding
+    let (digits, dropped) = leading_digits(n, sig.saturating_add(1));
+    if dropped == 0 && digits.len() <= p {
+        return digits;
+    }
+    let (v, exp) = round_significant(&digits, dropped, sig);
+    let mut s = String::with_capacity(sig + 8);
+    s.push(v[0] as char);
+    if sig > 1 {
+        s.push('.');
+        s.push_str(str::from_utf8(&v[1..]).unwrap());
+    }
+    s.push('e');
+    s.push_str(&exp.to_string());
+    s
+}
+
+impl BigUint {
+    /// Returns the number in decimal, with `separator` between each group of `size`
+    /// digits, counting from the least significant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1234567u32);
+    /// assert_eq!(n.format_grouped(',', 3), "1,234,567");
+    /// assert_eq!(n.format_grouped('_', 4), "123_4567");
+    /// ```
+    pub fn format_grouped(&self, separator: char, size: usize) -> String {
+        group_digits(&self.to_str_radix(10), separator, size)
+    }
+
+    /// Returns the number in engineering notation: a mantissa from 1 up to 1000, rounded
+    /// half away from zero to `digits` significant digits, and an exponent of ten that is
+    /// a multiple of three.
+    ///
+    /// When `digits` is too few to reach the units of the mantissa, it is padded with
+    /// zeros, so the result may show more than `digits` digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1234567u32);
+    /// assert_eq!(n.format_engineering(3), "1.23e6");
+    /// assert_eq!((n * 100u32).format_engineering(4), "123.5e6");
+    /// assert_eq!(BigUint::from(42u32).format_engineering(3), "42.0e0");
+    /// ```
+    pub fn format_engineering(&self, digits: usize) -> String {
+        engineering(self, digits)
+    }
+
+    /// Returns the bit length and the internal 32-bit digits in hex, least significant
+    /// first, for debugging the representation rather than the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = (BigUint::from(1u32) << 64usize) + 0xabcu32;
+    /// assert_eq!(
+    ///     n.debug_limbs(),
+    ///     "BigUint { bits: 65, limbs: [0x00000abc, 0x00000000, 0x00000001] }"
+    /// );
+    /// ```
+    pub fn debug_limbs(&self) -> String {
+        format!(
+            "BigUint {{ bits: {}, limbs: {} }}",
+            self.bit_len(),
+            hex_limbs(self)
+        )
+    }
+}
+
+impl BigInt {
+    /// Returns the number in decimal, with `separator` between each group of `size`
+    /// digits, counting from the least significant, and a leading `-` if it is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(-1234567);
+    /// assert_eq!(n.format_grouped(',', 3), "-1,234,567");
+    /// ```
+    pub fn format_grouped(&self, separator: char, size: usize) -> String {
+        let s = magnitude(self).format_grouped(separator, size);
+        if self.is_negative() {
+            format!("-{}", s)
+        } else {
+            s
+        }
+    }
+
+    /// Returns the number in engineering notation, with a leading `-` if it is negative.
+    ///
+    /// See [`BigUint::format_engineering`](struct.BigUint.html#method.format_engineering).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-98765).format_engineering(2), "-99e3");
+    /// ```
+    pub fn format_engineering(&self, digits: usize) -> String {
+        let s = magnitude(self).format_engineering(digits);
+        if self.is_negative() {
+            format!("-{}", s)
+        } else {
+            s
+        }
+    }
+
+    /// Returns the sign, the bit length of the magnitude, and its internal 32-bit digits in
+    /// hex, least significant first.
+    ///
+    /// See [`BigUint::debug_limbs`](struct.BigUint.html#method.debug_limbs).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(
+    ///     BigInt::from(-0x1_0000_0002i64).debug_limbs(),
+    ///     "BigInt { sign: Minus, bits: 33, limbs: [0x00000002, 0x00000001] }"
+    /// );
+    /// ```
+    pub fn debug_limbs(&self) -> String {
+        format!(
+            "BigInt {{ sign: {:?}, bits: {}, limbs: {} }}",
+            self.sign(),
+            self.bit_len(),
+            hex_limbs(magnitude(self))
+        )
+    }
+}
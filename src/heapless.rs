@@ -0,0 +1,381 @@
+//! A fixed-capacity `BigUint` that never allocates.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use big_digit::{self, BigDigit, DoubleBigDigit, SignedDoubleBigDigit};
+
+use super::algorithms::{__add2, cmp_slice, mac_with_carry, sbb, sub2};
+use super::monty::inv_mod_u32;
+use super::BigUint;
+
+/// An unsigned integer of at most `N` 32-bit digits, stored inline in an array.
+///
+/// Every operation works on the inline array alone, so nothing is ever allocated on the
+/// heap, which suits targets without an allocator, or code that must not touch it.  A
+/// result that would need more than `N` digits is an error rather than growing the
+/// storage, so the arithmetic methods are all fallible and return `None` on overflow.
+///
+/// Only formatting, and the conversions to and from `BigUint`, allocate.
+///
+/// This type needs Rust 1.51 or later, for const generics.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::HeaplessBigUint;
+///
+/// // Room for 2048-bit values.
+/// type U2048 = HeaplessBigUint<64>;
+///
+/// let m = U2048::from_slice(&[1_000_000_007]).unwrap();
+/// let x = U2048::from_slice(&[3]).unwrap();
+/// let e = U2048::from_slice(&[1_000_000_006]).unwrap();
+/// assert_eq!(x.modpow(&e, &m), U2048::from_slice(&[1]));
+///
+/// let max = HeaplessBigUint::<2>::from_slice(&[!0, !0]).unwrap();
+/// assert_eq!(max.checked_add(&max), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeaplessBigUint<const N: usize> {
+    data: [BigDigit; N],
+    // The digits past `len` are always zero, and `data[len - 1]` is not.
+    len: usize,
+}
+
+impl<const N: usize> HeaplessBigUint<N> {
+    /// Returns zero.
+    #[inline]
+    pub fn zero() -> Self {
+        HeaplessBigUint {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Creates an integer from its 32-bit digits, least significant first, or `None` if
+    /// it needs more than `N` of them.  Trailing zero digits are ignored.
+    pub fn from_slice(digits: &[BigDigit]) -> Option<Self> {
+        let len = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+        if len > N {
+            return None;
+        }
+        let mut data = [0; N];
+        data[..len].copy_from_slice(&digits[..len]);
+        Some(HeaplessBigUint { data, len })
+    }
+
+    /// Copies a `BigUint`, or returns `None` if it needs more than `N` digits.
+    #[inline]
+    pub fn from_biguint(n: &BigUint) -> Option<Self> {
+        Self::from_slice(&n.data)
+    }
+
+    /// Copies this integer into a `BigUint`.
+    #[inline]
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::from_slice(self.as_slice())
+    }
+
+    /// Returns the 32-bit digits, least significant first, without trailing zeros.
+    #[inline]
+    pub fn as_slice(&self) -> &[BigDigit] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the greatest number of 32-bit digits this type can hold, `N`.
+    #[inline]
+    pub fn capacity() -> usize {
+        N
+    }
+
+    /// Returns `true` if this integer is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Determines the fewest bits necessary to express this integer.
+    pub fn bits(&self) -> usize {
+        match self.as_slice().last() {
+            Some(&hi) => self.len * big_digit::BITS - hi.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns `self + other`, or `None` if the sum needs more than `N` digits.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let (long, short) = if self.len >= other.len {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut data = long.data;
+        if __add2(&mut data, short.as_slice()) != 0 {
+            return None;
+        }
+        Some(normalized(data))
+    }
+
+    /// Returns `self - other`, or `None` if `other` is greater than `self`.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if *self < *other {
+            return None;
+        }
+        let mut data = self.data;
+        sub2(&mut data[..self.len], other.as_slice());
+        Some(normalized(data))
+    }
+
+    /// Returns `self * other`, or `None` if the product needs more than `N` digits.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::zero());
+        }
+        // The product has at least `self.len + other.len - 1` digits.
+        if self.len + other.len > N + 1 {
+            return None;
+        }
+        let b = other.as_slice();
+        let mut data = [0; N];
+        for (i, &a) in self.as_slice().iter().enumerate() {
+            let mut carry = 0;
+            for (acc, &bj) in data[i..].iter_mut().zip(b) {
+                *acc = mac_with_carry(*acc, bj, a, &mut carry);
+            }
+            if carry != 0 {
+                let hi = i + b.len();
+                if hi >= N || __add2(&mut data[hi..], &[carry as BigDigit]) != 0 {
+                    return None;
+                }
+            }
+        }
+        Some(normalized(data))
+    }
+
+    /// Returns the quotient and remainder of `self / other`, or `None` if `other` is zero.
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        if *self < *other {
+            return Some((Self::zero(), *self));
+        }
+
+        // Plain binary long division, which needs no room beyond the two results.
+        let m = other.as_slice();
+        let mut q = [0; N];
+        let mut r = [0; N];
+        for i in (0..self.bits()).rev() {
+            let carry = shl1(&mut r[..m.len()]);
+            r[0] |= self.bit(i) as BigDigit;
+            if carry || cmp_digits(&r[..m.len()], m) != Ordering::Less {
+                sub_wrapping(&mut r[..m.len()], m);
+                q[i / big_digit::BITS] |= 1 << (i % big_digit::BITS);
+            }
+        }
+        Some((normalized(q), normalized(r)))
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(q, _)| q)
+    }
+
+    /// Returns `self % other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(_, r)| r)
+    }
+
+    /// Returns `(self ^ exponent) % modulus`, or `None` if the modulus is zero.
+    ///
+    /// An odd modulus, as in RSA, uses Montgomery multiplication.  An even modulus falls
+    /// back to multiplying by shifts and adds, which is much slower.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Option<Self> {
+        let base = self.checked_rem(modulus)?;
+        let m = modulus.as_slice();
+        if m == [1] {
+            return Some(Self::zero());
+        }
+
+        let data = if m[0] % 2 == 1 {
+            let mu = inv_mod_u32(m[0]).wrapping_neg();
+
+            // R = 2^(32 * m.len()) is reduced by doubling one, and R^2 by doubling that.
+            let mut r = [0; N];
+            r[0] = 1;
+            for _ in 0..m.len() * big_digit::BITS {
+                double_mod(&mut r, m);
+            }
+            let mut r2 = r;
+            for _ in 0..m.len() * big_digit::BITS {
+                double_mod(&mut r2, m);
+            }
+
+            let b = monty_mul(&base.data, &r2, m, mu);
+            let mut acc = r;
+            for i in (0..exponent.bits()).rev() {
+                acc = monty_mul(&acc, &acc, m, mu);
+                if exponent.bit(i) {
+                    acc = monty_mul(&acc, &b, m, mu);
+                }
+            }
+            let mut one = [0; N];
+            one[0] = 1;
+            monty_mul(&acc, &one, m, mu)
+        } else {
+            let mut acc = [0; N];
+            acc[0] = 1;
+            for i in (0..exponent.bits()).rev() {
+                acc = mul_mod(&acc, &acc, m);
+                if exponent.bit(i) {
+                    acc = mul_mod(&acc, &base.data, m);
+                }
+            }
+            acc
+        };
+        Some(normalized(data))
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.data[i / big_digit::BITS] >> (i % big_digit::BITS) & 1 == 1
+    }
+}
+
+fn normalized<const N: usize>(data: [BigDigit; N]) -> HeaplessBigUint<N> {
+    let len = data.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    HeaplessBigUint { data, len }
+}
+
+// Compares digit slices of the same length, which may have high zero digits.
+fn cmp_digits(a: &[BigDigit], b: &[BigDigit]) -> Ordering {
+    debug_assert!(a.len() == b.len());
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+// Shifts left by one bit, returning the bit shifted out of the top.
+fn shl1(a: &mut [BigDigit]) -> bool {
+    let mut carry = 0;
+    for d in a {
+        let hi = *d >> (big_digit::BITS - 1);
+        *d = *d << 1 | carry;
+        carry = hi;
+    }
+    carry != 0
+}
+
+// a -= b, modulo 2^(32 * a.len()), for slices of the same length.
+fn sub_wrapping(a: &mut [BigDigit], b: &[BigDigit]) {
+    debug_assert!(a.len() == b.len());
+    let mut borrow: SignedDoubleBigDigit = 0;
+    for (a, &b) in a.iter_mut().zip(b) {
+        *a = sbb(*a, b, &mut borrow);
+    }
+}
+
+// x = 2x mod m, for x < m.
+fn double_mod(x: &mut [BigDigit], m: &[BigDigit]) {
+    let x = &mut x[..m.len()];
+    if shl1(x) || cmp_digits(x, m) != Ordering::Less {
+        sub_wrapping(x, m);
+    }
+}
+
+// a * b mod m, for a, b < m, by doubling and adding one bit of b at a time.
+fn mul_mod<const N: usize>(a: &[BigDigit; N], b: &[BigDigit; N], m: &[BigDigit]) -> [BigDigit; N] {
+    let s = m.len();
+    let mut acc = [0; N];
+    for i in (0..s * big_digit::BITS).rev() {
+        double_mod(&mut acc, m);
+        if b[i / big_digit::BITS] >> (i % big_digit::BITS) & 1 == 1 {
+            let carry = __add2(&mut acc[..s], &a[..s]) != 0;
+            if carry || cmp_digits(&acc[..s], m) != Ordering::Less {
+                sub_wrapping(&mut acc[..s], m);
+            }
+        }
+    }
+    acc
+}
+
+// Montgomery multiplication, a * b / R mod m for a, b < m, interleaving each row of the
+// product with a reduction step so that it never needs more than `m.len()` digits and a
+// high word.
+//
+// Reference:
+// Koç, Acar & Kaliski, Analyzing and Comparing Montgomery Multiplication Algorithms (CIOS)
+fn monty_mul<const N: usize>(
+    a: &[BigDigit; N],
+    b: &[BigDigit; N],
+    m: &[BigDigit],
+    mu: BigDigit,
+) -> [BigDigit; N] {
+    let s = m.len();
+    let mut t = [0; N];
+    let mut t_hi: BigDigit = 0;
+    for &ai in &a[..s] {
+        // t += ai * b
+        let mut carry = 0;
+        for (tj, &bj) in t[..s].iter_mut().zip(&b[..s]) {
+            *tj = mac_with_carry(*tj, bj, ai, &mut carry);
+        }
+        let sum = DoubleBigDigit::from(t_hi) + carry;
+        let (top, top_hi) = (sum as BigDigit, (sum >> big_digit::BITS) as BigDigit);
+
+        // t = (t + q * m) / 2^32, where q makes the low digit zero.
+        let q = t[0].wrapping_mul(mu);
+        let mut carry = 0;
+        mac_with_carry(t[0], m[0], q, &mut carry);
+        for j in 1..s {
+            t[j - 1] = mac_with_carry(t[j], m[j], q, &mut carry);
+        }
+        let sum = DoubleBigDigit::from(top) + carry;
+        t[s - 1] = sum as BigDigit;
+        t_hi = top_hi + (sum >> big_digit::BITS) as BigDigit;
+    }
+    if t_hi != 0 || cmp_digits(&t[..s], m) != Ordering::Less {
+        sub_wrapping(&mut t[..s], m);
+    }
+    t
+}
+
+impl<const N: usize> Default for HeaplessBigUint<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> PartialOrd for HeaplessBigUint<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for HeaplessBigUint<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_slice(self.as_slice(), other.as_slice())
+    }
+}
+
+impl<const N: usize> From<HeaplessBigUint<N>> for BigUint {
+    #[inline]
+    fn from(n: HeaplessBigUint<N>) -> BigUint {
+        n.to_biguint()
+    }
+}
+
+impl<const N: usize> fmt::Debug for HeaplessBigUint<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_biguint(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for HeaplessBigUint<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
@@ -0,0 +1,135 @@
+//! Conversions between `BigUint`/`BigInt` and `ibig`'s `UBig`/`IBig`.
+//!
+//! Values are transferred through their little-endian bytes rather than decimal
+//! strings, so conversions take linear time.
+
+use std::convert::TryFrom;
+
+use ibig::ops::UnsignedAbs;
+use ibig::{IBig, UBig};
+
+use BigInt;
+use BigUint;
+use Sign::{Minus, NoSign, Plus};
+use TryFromBigIntError;
+
+use bigint::magnitude;
+
+fn ubig_from(n: &BigUint) -> UBig {
+    UBig::from_le_bytes(&n.to_bytes_le())
+}
+
+fn biguint_from(n: &UBig) -> BigUint {
+    BigUint::from_bytes_le(&n.to_le_bytes())
+}
+
+fn is_negative(n: &IBig) -> bool {
+    *n < IBig::from(0u8)
+}
+
+impl<'a> From<&'a UBig> for BigUint {
+    #[inline]
+    fn from(n: &UBig) -> Self {
+        biguint_from(n)
+    }
+}
+
+impl From<UBig> for BigUint {
+    #[inline]
+    fn from(n: UBig) -> Self {
+        biguint_from(&n)
+    }
+}
+
+impl<'a> From<&'a BigUint> for UBig {
+    #[inline]
+    fn from(n: &BigUint) -> Self {
+        ubig_from(n)
+    }
+}
+
+impl From<BigUint> for UBig {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        ubig_from(&n)
+    }
+}
+
+impl<'a> From<&'a IBig> for BigInt {
+    fn from(n: &IBig) -> Self {
+        let sign = if is_negative(n) { Minus } else { Plus };
+        BigInt::from_biguint(sign, biguint_from(&n.unsigned_abs()))
+    }
+}
+
+impl From<IBig> for BigInt {
+    #[inline]
+    fn from(n: IBig) -> Self {
+        BigInt::from(&n)
+    }
+}
+
+impl<'a> From<&'a BigInt> for IBig {
+    fn from(n: &BigInt) -> Self {
+        let m = IBig::from(ubig_from(magnitude(n)));
+        match n.sign() {
+            Minus => -m,
+            NoSign | Plus => m,
+        }
+    }
+}
+
+impl From<BigInt> for IBig {
+    #[inline]
+    fn from(n: BigInt) -> Self {
+        IBig::from(&n)
+    }
+}
+
+impl<'a> TryFrom<&'a IBig> for BigUint {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(n: &IBig) -> Result<BigUint, TryFromBigIntError<()>> {
+        if is_negative(n) {
+            Err(TryFromBigIntError::new(()))
+        } else {
+            Ok(biguint_from(&n.unsigned_abs()))
+        }
+    }
+}
+
+impl TryFrom<IBig> for BigUint {
+    type Error = TryFromBigIntError<IBig>;
+
+    fn try_from(n: IBig) -> Result<BigUint, TryFromBigIntError<IBig>> {
+        if is_negative(&n) {
+            Err(TryFromBigIntError::new(n))
+        } else {
+            Ok(biguint_from(&n.unsigned_abs()))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a BigInt> for UBig {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(n: &BigInt) -> Result<UBig, TryFromBigIntError<()>> {
+        if n.sign() == Minus {
+            Err(TryFromBigIntError::new(()))
+        } else {
+            Ok(ubig_from(magnitude(n)))
+        }
+    }
+}
+
+impl TryFrom<BigInt> for UBig {
+    type Error = TryFromBigIntError<BigInt>;
+
+    fn try_from(n: BigInt) -> Result<UBig, TryFromBigIntError<BigInt>> {
+        if n.sign() == Minus {
+            Err(TryFromBigIntError::new(n))
+        } else {
+            Ok(ubig_from(magnitude(&n)))
+        }
+    }
+}
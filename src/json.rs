@@ -0,0 +1,96 @@
+//! Conversions between `serde_json::Number` and `BigInt`/`BigUint`.
+//!
+//! These require `serde_json`'s `arbitrary_precision` feature, which keeps the exact
+//! digits of a number instead of rounding it through `f64`, so enabling the `serde_json`
+//! feature of this crate turns it on.
+
+use std::convert::TryFrom;
+
+use serde_json::Number;
+
+use BigInt;
+use BigUint;
+use ParseBigIntError;
+
+/// Parses a JSON number that is written as an integer, with no fraction or exponent.
+///
+/// A valid JSON number has nothing else besides the sign and digits, so any other
+/// character means the number is written with a fraction or exponent.
+fn parse_integer(n: &Number) -> Result<BigInt, ParseBigIntError> {
+    let s = n.as_str();
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseBigIntError::fractional());
+    }
+    s.parse()
+}
+
+impl From<BigInt> for Number {
+    #[inline]
+    fn from(n: BigInt) -> Self {
+        Number::from(&n)
+    }
+}
+
+impl<'a> From<&'a BigInt> for Number {
+    fn from(n: &BigInt) -> Self {
+        n.to_string().parse().unwrap()
+    }
+}
+
+impl From<BigUint> for Number {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        Number::from(&n)
+    }
+}
+
+impl<'a> From<&'a BigUint> for Number {
+    fn from(n: &BigUint) -> Self {
+        n.to_string().parse().unwrap()
+    }
+}
+
+impl<'a> TryFrom<&'a Number> for BigInt {
+    type Error = ParseBigIntError;
+
+    /// Converts a JSON number to a `BigInt`, failing if it has a fraction or exponent,
+    /// even one like `1.0` or `1e3` with an integer value.
+    ///
+    /// That failure has the kind `BigIntErrorKind::Fractional`.
+    fn try_from(n: &Number) -> Result<BigInt, ParseBigIntError> {
+        parse_integer(n)
+    }
+}
+
+impl TryFrom<Number> for BigInt {
+    type Error = ParseBigIntError;
+
+    #[inline]
+    fn try_from(n: Number) -> Result<BigInt, ParseBigIntError> {
+        BigInt::try_from(&n)
+    }
+}
+
+impl<'a> TryFrom<&'a Number> for BigUint {
+    type Error = ParseBigIntError;
+
+    /// Converts a JSON number to a `BigUint`, failing if it is negative or has a fraction
+    /// or exponent, even one like `1.0` or `1e3` with an integer value.
+    ///
+    /// A negative number is reported as an invalid digit at its `-` sign.
+    fn try_from(n: &Number) -> Result<BigUint, ParseBigIntError> {
+        parse_integer(n)?
+            .to_biguint()
+            .ok_or_else(|| ParseBigIntError::invalid_at(n.as_str(), 0))
+    }
+}
+
+impl TryFrom<Number> for BigUint {
+    type Error = ParseBigIntError;
+
+    #[inline]
+    fn try_from(n: Number) -> Result<BigUint, ParseBigIntError> {
+        BigUint::try_from(&n)
+    }
+}
@@ -71,6 +71,20 @@
 //! # }
 //! ```
 //!
+//! ## Serialization
+//!
+//! With the `serde` feature, `BigUint` and `BigInt` implement `Serialize` and `Deserialize`
+//! using a stable format which does not depend on the internal digit size:
+//!
+//! - A `BigUint` is a sequence of `u32` digits, least significant first, with no trailing
+//!   zeros.  Zero is the empty sequence.
+//! - A `BigInt` is a tuple of its sign, as an `i8` of `-1`, `0` or `1`, and its magnitude
+//!   as a `BigUint`.
+//!
+//! Deserialization also accepts trailing zero digits.  The `serde_helpers` module provides
+//! decimal string and byte string alternatives, and the byte conversions such as
+//! `to_bytes_le` and `to_signed_bytes_le` are likewise independent of the digit size.
+//!
 //! ## Compatibility
 //!
 //! The `num-bigint` crate is tested for rustc 1.15 and greater.
@@ -80,10 +94,30 @@
 // reserving this ability with the "std" feature now, and compilation will fail without.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "diesel")]
+extern crate diesel;
+#[cfg(feature = "ibig")]
+extern crate ibig;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
 #[cfg(feature = "rand")]
 extern crate rand;
+#[cfg(feature = "rand_core_06")]
+extern crate rand_core_06;
+#[cfg(feature = "rand_core_09")]
+extern crate rand_core_09;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "rug")]
+extern crate rug;
 #[cfg(feature = "serde")]
 extern crate serde;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
 
 extern crate num_integer as integer;
 extern crate num_traits as traits;
@@ -99,9 +133,47 @@ mod macros;
 mod bigint;
 mod biguint;
 
+mod cbor;
+mod der;
+mod ffi;
+mod format;
+mod modint;
+mod nonzero;
+mod range;
+mod rounding;
+mod stream;
+mod wrapping;
+
+#[cfg(feature = "base-encodings")]
+mod radix_alphabets;
+
 #[cfg(feature = "rand")]
 mod bigrand;
 
+#[cfg(feature = "ibig")]
+mod ibig_impls;
+
+#[cfg(feature = "rug")]
+mod rug_impls;
+
+#[cfg(feature = "serde_json")]
+mod json;
+
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+mod postgres;
+
+#[cfg(feature = "pyo3")]
+mod python;
+
+#[cfg(feature = "rkyv")]
+mod archive;
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
+#[cfg(feature = "proptest")]
+pub mod proptest_strategies;
+
 #[cfg(target_pointer_width = "32")]
 type UsizePromotion = u32;
 #[cfg(target_pointer_width = "64")]
@@ -115,12 +187,26 @@ type IsizePromotion = i64;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseBigIntError {
     kind: BigIntErrorKind,
+    position: Option<usize>,
+    invalid: Option<char>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum BigIntErrorKind {
+/// The reason a big integer could not be parsed, from
+/// [`ParseBigIntError::kind`](struct.ParseBigIntError.html#method.kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigIntErrorKind {
+    /// The string was empty.
     Empty,
+    /// The string had a character that is not a digit in the radix.
     InvalidDigit,
+    /// The radix was out of range.
+    InvalidRadix,
+    /// The number had a fractional part.
+    Fractional,
+    /// The number had more bits than the parser allows.
+    TooLarge,
+    #[doc(hidden)]
+    __Nonexhaustive,
 }
 
 impl ParseBigIntError {
@@ -129,25 +215,125 @@ impl ParseBigIntError {
         match self.kind {
             Empty => "cannot parse integer from empty string",
             InvalidDigit => "invalid digit found in string",
+            InvalidRadix => "radix is out of range",
+            Fractional => "number has a fractional part",
+            TooLarge => "number is too large",
+            __Nonexhaustive => unreachable!(),
+        }
+    }
+
+    /// Returns the reason the number could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigIntErrorKind, BigUint};
+    ///
+    /// let err = BigUint::try_parse_bytes(b"", 10).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::Empty);
+    /// let err = BigUint::try_parse_bytes(b"12z", 10).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::InvalidDigit);
+    /// let err = BigUint::try_parse_bytes(b"12", 1).unwrap_err();
+    /// assert_eq!(err.kind(), BigIntErrorKind::InvalidRadix);
+    /// ```
+    pub fn kind(&self) -> BigIntErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the input of an invalid digit, if the error has one.
+    ///
+    /// This is the length of the input if it ended where a digit was expected, or the
+    /// start of invalid UTF-8 from `parse_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let err = "-123x5".parse::<BigInt>().unwrap_err();
+    /// assert_eq!(err.position(), Some(4));
+    /// assert_eq!(err.invalid_char(), Some('x'));
+    /// assert_eq!(err.to_string(), "invalid digit 'x' found at byte 4");
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the invalid character found at [`position`](#method.position), if there is
+    /// one.
+    pub fn invalid_char(&self) -> Option<char> {
+        self.invalid
+    }
+
+    fn new(kind: BigIntErrorKind) -> Self {
+        ParseBigIntError {
+            kind: kind,
+            position: None,
+            invalid: None,
         }
     }
 
     fn empty() -> Self {
+        ParseBigIntError::new(BigIntErrorKind::Empty)
+    }
+
+    /// An invalid digit at byte `position` of `s`, or its end.
+    fn invalid_at(s: &str, position: usize) -> Self {
+        ParseBigIntError {
+            kind: BigIntErrorKind::InvalidDigit,
+            position: Some(position),
+            invalid: s[position..].chars().next(),
+        }
+    }
+
+    /// An invalid digit where `parse_bytes` input stops being valid UTF-8.
+    fn invalid_utf8(position: usize) -> Self {
         ParseBigIntError {
-            kind: BigIntErrorKind::Empty,
+            kind: BigIntErrorKind::InvalidDigit,
+            position: Some(position),
+            invalid: None,
         }
     }
 
-    fn invalid() -> Self {
+    /// An invalid byte at `position` of a stream, which is shown if it is ASCII.
+    fn invalid_byte(position: usize, byte: u8) -> Self {
         ParseBigIntError {
             kind: BigIntErrorKind::InvalidDigit,
+            position: Some(position),
+            invalid: if byte < 0x80 {
+                Some(byte as char)
+            } else {
+                None
+            },
         }
     }
+
+    fn invalid_radix() -> Self {
+        ParseBigIntError::new(BigIntErrorKind::InvalidRadix)
+    }
+
+    fn fractional() -> Self {
+        ParseBigIntError::new(BigIntErrorKind::Fractional)
+    }
+
+    fn too_large() -> Self {
+        ParseBigIntError::new(BigIntErrorKind::TooLarge)
+    }
+
+    /// Moves the position past `n` bytes that were removed from the front of the input.
+    fn offset(mut self, n: usize) -> Self {
+        self.position = self.position.map(|p| p + n);
+        self
+    }
 }
 
 impl fmt::Display for ParseBigIntError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.__description().fmt(f)
+        match (self.position, self.invalid) {
+            (Some(p), Some(c)) => write!(f, "invalid digit {:?} found at byte {}", c, p),
+            (Some(p), None) => write!(f, "invalid digit found at byte {}", p),
+            _ => self.__description().fmt(f),
+        }
     }
 }
 
@@ -157,13 +343,82 @@ impl Error for ParseBigIntError {
     }
 }
 
+/// The error type returned when a checked conversion regarding big integer fails.
+#[cfg(has_try_from)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromBigIntError<T> {
+    original: T,
+}
+
+#[cfg(has_try_from)]
+impl<T> TryFromBigIntError<T> {
+    fn new(original: T) -> Self {
+        TryFromBigIntError { original: original }
+    }
+
+    fn __description(&self) -> &str {
+        "out of range conversion regarding big integer attempted"
+    }
+
+    /// Extract the original value, if available. The value will be available
+    /// if the conversion took its input by value, such as from [`BigInt`] or [`BigUint`].
+    ///
+    /// [`BigInt`]: struct.BigInt.html
+    /// [`BigUint`]: struct.BigUint.html
+    pub fn into_original(self) -> T {
+        self.original
+    }
+}
+
+#[cfg(has_try_from)]
+impl<T> Error for TryFromBigIntError<T>
+where
+    T: fmt::Debug,
+{
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+#[cfg(has_try_from)]
+impl<T> fmt::Display for TryFromBigIntError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
 pub use biguint::BigUint;
+pub use biguint::Endian;
+#[cfg(has_const_generics)]
+pub use biguint::FixedBigUint;
+#[cfg(has_const_generics)]
+pub use biguint::HeaplessBigUint;
+pub use biguint::OddBigUint;
 pub use biguint::ToBigUint;
+pub use biguint::{IterBits, U32Digits, U64Digits};
 
 pub use bigint::BigInt;
 pub use bigint::Sign;
 pub use bigint::ToBigInt;
 
+pub use ffi::RawBigInt;
+
+pub use modint::ModInt;
+pub use nonzero::NonZeroBigUint;
+
+pub use range::{range, range_inclusive, range_step, range_step_inclusive, BigRange};
+
+pub use rounding::RoundingMode;
+
+pub use wrapping::WrappingBigUint;
+
+#[cfg(feature = "rkyv")]
+pub use archive::{ArchivedBigInt, ArchivedBigUint, ArchivedU32Digits};
+
+#[cfg(feature = "rand-08")]
+pub use bigrand::RngCompat08;
+#[cfg(feature = "rand-09")]
+pub use bigrand::RngCompat09;
 #[cfg(feature = "rand")]
 pub use bigrand::{RandBigInt, RandomBits, UniformBigInt, UniformBigUint};
 
@@ -320,6 +320,26 @@ macro_rules! promote_scalars_assign {
     }
 }
 
+// Like `promote_scalars`, for operators only defined with the scalar on the right
+macro_rules! promote_scalars_rhs {
+    (impl $imp:ident<$promo:ty> for $res:ty, $method:ident, $( $scalar:ty ),*) => {
+        $(
+            forward_all_scalar_binop_rhs_to_val_val!(impl $imp<$scalar> for $res, $method);
+
+            impl $imp<$scalar> for $res {
+                type Output = $res;
+
+                #[cfg_attr(feature = "cargo-clippy", allow(renamed_and_removed_lints))]
+                #[cfg_attr(feature = "cargo-clippy", allow(cast_lossless))]
+                #[inline]
+                fn $method(self, other: $scalar) -> $res {
+                    $imp::$method(self, other as $promo)
+                }
+            }
+        )*
+    }
+}
+
 macro_rules! promote_unsigned_scalars {
     (impl $imp:ident for $res:ty, $method:ident) => {
         promote_scalars!(impl $imp<u32> for $res, $method, u8, u16);
@@ -344,7 +364,7 @@ macro_rules! promote_signed_scalars {
 macro_rules! promote_signed_scalars_assign {
     (impl $imp:ident for $res:ty, $method:ident) => {
         promote_scalars_assign!(impl $imp<i32> for $res, $method, i8, i16);
-        promote_scalars_assign!(impl $imp<UsizePromotion> for $res, $method, isize);
+        promote_scalars_assign!(impl $imp<IsizePromotion> for $res, $method, isize);
     }
 }
 
@@ -398,6 +418,39 @@ macro_rules! forward_all_scalar_binop_to_val_val_commutative {
     }
 }
 
+// Like `forward_all_scalar_binop_to_val_val`, for operators only defined with the scalar
+// on the right
+macro_rules! forward_all_scalar_binop_rhs_to_val_val {
+    (impl $imp:ident<$scalar:ty> for $res:ty, $method:ident) => {
+        impl<'a> $imp<&'a $scalar> for $res {
+            type Output = $res;
+
+            #[inline]
+            fn $method(self, other: &$scalar) -> $res {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a> $imp<$scalar> for &'a $res {
+            type Output = $res;
+
+            #[inline]
+            fn $method(self, other: $scalar) -> $res {
+                $imp::$method(self.clone(), other)
+            }
+        }
+
+        impl<'a, 'b> $imp<&'b $scalar> for &'a $res {
+            type Output = $res;
+
+            #[inline]
+            fn $method(self, other: &$scalar) -> $res {
+                $imp::$method(self.clone(), *other)
+            }
+        }
+    };
+}
+
 macro_rules! promote_all_scalars {
     (impl $imp:ident for $res:ty, $method:ident) => {
         promote_unsigned_scalars!(impl $imp for $res, $method);
@@ -443,3 +496,41 @@ macro_rules! impl_product_iter_type {
         }
     };
 }
+
+/// Implement `MulAdd` and `MulAddAssign` for all val/ref combinations, forwarding to a fused
+/// `fn(&$res, &$res, $res) -> $res` that can reuse the storage of an owned addend.
+macro_rules! impl_mul_add {
+    ($res:ty, $fused:path) => {
+        impl_mul_add!(@impl $res, $fused, $res, $res, $res, b => b);
+        impl_mul_add!(@impl $res, $fused, $res, $res, &'c $res, b => b.clone());
+        impl_mul_add!(@impl $res, $fused, $res, &'b $res, $res, b => b);
+        impl_mul_add!(@impl $res, $fused, $res, &'b $res, &'c $res, b => b.clone());
+        impl_mul_add!(@impl $res, $fused, &'a $res, $res, $res, b => b);
+        impl_mul_add!(@impl $res, $fused, &'a $res, $res, &'c $res, b => b.clone());
+        impl_mul_add!(@impl $res, $fused, &'a $res, &'b $res, $res, b => b);
+        impl_mul_add!(@impl $res, $fused, &'a $res, &'b $res, &'c $res, b => b.clone());
+
+        impl_mul_add!(@assign $res, $fused, $res, $res, b => b);
+        impl_mul_add!(@assign $res, $fused, $res, &'c $res, b => b.clone());
+        impl_mul_add!(@assign $res, $fused, &'b $res, $res, b => b);
+        impl_mul_add!(@assign $res, $fused, &'b $res, &'c $res, b => b.clone());
+    };
+    (@impl $res:ty, $fused:path, $slf:ty, $a:ty, $b:ty, $arg:ident => $owned:expr) => {
+        impl<'a, 'b, 'c> MulAdd<$a, $b> for $slf {
+            type Output = $res;
+
+            #[inline]
+            fn mul_add(self, a: $a, $arg: $b) -> $res {
+                $fused(&self, &a, $owned)
+            }
+        }
+    };
+    (@assign $res:ty, $fused:path, $a:ty, $b:ty, $arg:ident => $owned:expr) => {
+        impl<'b, 'c> MulAddAssign<$a, $b> for $res {
+            #[inline]
+            fn mul_add_assign(&mut self, a: $a, $arg: $b) {
+                *self = $fused(self, &a, $owned);
+            }
+        }
+    };
+}
@@ -0,0 +1,234 @@
+//! Integers modulo a runtime modulus, with arithmetic that reduces automatically.
+
+use std::fmt;
+use std::mem;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::Arc;
+
+use integer::Integer;
+use traits::{One, Zero};
+
+use {BigInt, BigUint};
+
+/// An integer modulo a `BigUint` chosen at run time.
+///
+/// The value is always kept reduced, in `0..modulus`, and the modulus is shared through an
+/// `Arc`, so cloning a `ModInt` or making another with the same modulus does not copy it.
+/// Addition, subtraction, multiplication and negation reduce their results, and
+/// [`inverse`](#method.inverse) and [`pow`](#method.pow) cover division and powers.
+///
+/// Combining two values with different moduli panics.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, ModInt};
+/// use std::sync::Arc;
+///
+/// let p = Arc::new(BigUint::from(1_000_000_007u32));
+/// let a = ModInt::new(BigUint::from(123_456_789u32), p.clone());
+/// let b = a.with_value(BigUint::from(987_654_321u32));
+///
+/// assert_eq!(*(&a + &b).value(), BigUint::from(111_111_103u32));
+/// assert_eq!(*(&a - &b).value(), BigUint::from(135_802_475u32));
+/// assert_eq!((&a * &a.inverse().unwrap()).value(), &BigUint::from(1u32));
+/// assert_eq!(a.pow(&BigUint::from(1_000_000_006u32)), a.with_value(BigUint::from(1u32)));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ModInt {
+    value: BigUint,
+    modulus: Arc<BigUint>,
+}
+
+impl ModInt {
+    /// Creates `value % modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(value: BigUint, modulus: Arc<BigUint>) -> ModInt {
+        assert!(!modulus.is_zero(), "ModInt modulus must not be zero");
+        let value = if value < *modulus {
+            value
+        } else {
+            value % &*modulus
+        };
+        ModInt {
+            value: value,
+            modulus: modulus,
+        }
+    }
+
+    /// Creates `value % modulus` with the same modulus as `self`.
+    #[inline]
+    pub fn with_value(&self, value: BigUint) -> ModInt {
+        ModInt::new(value, self.modulus.clone())
+    }
+
+    /// Returns the value, which is less than the modulus.
+    #[inline]
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Unwraps the value, which is less than the modulus.
+    #[inline]
+    pub fn into_value(self) -> BigUint {
+        self.value
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns the shared modulus, for creating other values with it.
+    #[inline]
+    pub fn shared_modulus(&self) -> &Arc<BigUint> {
+        &self.modulus
+    }
+
+    /// Returns `self ^ exponent`.
+    pub fn pow(&self, exponent: &BigUint) -> ModInt {
+        ModInt {
+            value: self.value.modpow(exponent, &self.modulus),
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Returns the multiplicative inverse of `self`, or `None` if `self` and the modulus are
+    /// not coprime.
+    pub fn inverse(&self) -> Option<ModInt> {
+        // The extended Euclidean algorithm, keeping only the coefficient of `self`.
+        let mut r0 = (*self.modulus).clone();
+        let mut r1 = self.value.clone();
+        let mut t0 = BigInt::zero();
+        let mut t1 = BigInt::one();
+        while !r1.is_zero() {
+            let (q, r2) = r0.div_rem(&r1);
+            r0 = mem::replace(&mut r1, r2);
+            let t2 = &t0 - BigInt::from(q) * &t1;
+            t0 = mem::replace(&mut t1, t2);
+        }
+        if !r0.is_one() {
+            return None;
+        }
+        let m = BigInt::from((*self.modulus).clone());
+        let value = t0.mod_floor(&m).to_biguint().unwrap();
+        Some(ModInt {
+            value: value,
+            modulus: self.modulus.clone(),
+        })
+    }
+
+    fn check_modulus(&self, other: &ModInt) {
+        assert!(
+            self.modulus == other.modulus,
+            "ModInt operands have different moduli"
+        );
+    }
+}
+
+impl<'a, 'b> Add<&'b ModInt> for &'a ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: &ModInt) -> ModInt {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<'a> AddAssign<&'a ModInt> for ModInt {
+    fn add_assign(&mut self, other: &ModInt) {
+        self.check_modulus(other);
+        self.value += &other.value;
+        if self.value >= *self.modulus {
+            self.value -= &*self.modulus;
+        }
+    }
+}
+
+impl<'a, 'b> Sub<&'b ModInt> for &'a ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: &ModInt) -> ModInt {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a> SubAssign<&'a ModInt> for ModInt {
+    fn sub_assign(&mut self, other: &ModInt) {
+        self.check_modulus(other);
+        if self.value < other.value {
+            self.value += &*self.modulus;
+        }
+        self.value -= &other.value;
+    }
+}
+
+impl<'a, 'b> Mul<&'b ModInt> for &'a ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: &ModInt) -> ModInt {
+        self.check_modulus(other);
+        ModInt {
+            value: (&self.value * &other.value) % &*self.modulus,
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+impl<'a> MulAssign<&'a ModInt> for ModInt {
+    fn mul_assign(&mut self, other: &ModInt) {
+        *self = &*self * other;
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Add for ModInt, add);
+forward_all_binop_to_ref_ref!(impl Sub for ModInt, sub);
+forward_all_binop_to_ref_ref!(impl Mul for ModInt, mul);
+
+forward_val_assign!(impl AddAssign for ModInt, add_assign);
+forward_val_assign!(impl SubAssign for ModInt, sub_assign);
+forward_val_assign!(impl MulAssign for ModInt, mul_assign);
+
+impl Neg for ModInt {
+    type Output = ModInt;
+
+    #[inline]
+    fn neg(self) -> ModInt {
+        -&self
+    }
+}
+
+impl<'a> Neg for &'a ModInt {
+    type Output = ModInt;
+
+    fn neg(self) -> ModInt {
+        let value = if self.value.is_zero() {
+            BigUint::zero()
+        } else {
+            &*self.modulus - &self.value
+        };
+        ModInt {
+            value: value,
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+impl fmt::Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
@@ -1,8 +1,14 @@
+use std::fmt;
+use std::mem;
+
 use integer::Integer;
 use traits::Zero;
 
+use big_digit;
 use biguint::BigUint;
 
+use super::algorithms::{mac_with_carry, sbb};
+
 struct MontyReducer<'a> {
     n: &'a BigUint,
     n0inv: u32,
@@ -12,7 +18,7 @@ struct MontyReducer<'a> {
 //
 // Reference:
 // Brent & Zimmermann, Modern Computer Arithmetic, v0.5.9, Algorithm 1.20
-fn inv_mod_u32(num: u32) -> u32 {
+pub fn inv_mod_u32(num: u32) -> u32 {
     // num needs to be relatively prime to 2**32 -- i.e. it must be odd.
     assert!(num % 2 != 0);
 
@@ -102,28 +108,276 @@ fn monty_sqr(a: BigUint, mr: &MontyReducer) -> BigUint {
     monty_redc(&a * &a, mr)
 }
 
-pub fn monty_modpow(a: &BigUint, exp: &BigUint, modulus: &BigUint) -> BigUint {
-    let mr = MontyReducer::new(modulus);
-
-    // Calculate the Montgomery parameter
+// The Montgomery parameter R = β^n reduced modulo `n`, where n is the size of `modulus`
+fn monty_r(modulus: &BigUint) -> BigUint {
     let mut v = vec![0; modulus.data.len()];
     v.push(1);
-    let r = BigUint::new(v);
+    BigUint::new(v) % modulus
+}
 
+fn monty_modpow_reduced(a: &BigUint, exp: &BigUint, mr: &MontyReducer, r: &BigUint) -> BigUint {
     // Map the base to the Montgomery domain
-    let mut apri = a * &r % modulus;
+    let mut apri = a * r % mr.n;
 
     // Binary exponentiation
-    let mut ans = &r % modulus;
+    let mut ans = r.clone();
     let mut e = exp.clone();
     while !e.is_zero() {
         if e.is_odd() {
-            ans = monty_mult(ans, &apri, &mr);
+            ans = monty_mult(ans, &apri, mr);
         }
-        apri = monty_sqr(apri, &mr);
+        apri = monty_sqr(apri, mr);
         e = e >> 1;
     }
 
     // Map the result back to the residues domain
-    monty_redc(ans, &mr)
+    monty_redc(ans, mr)
+}
+
+pub fn monty_modpow(a: &BigUint, exp: &BigUint, modulus: &BigUint) -> BigUint {
+    let mr = MontyReducer::new(modulus);
+    monty_modpow_reduced(a, exp, &mr, &monty_r(modulus))
+}
+
+/// An odd `BigUint`, for use as the modulus of Montgomery multiplication.
+///
+/// The oddness check and the constants Montgomery reduction needs are computed once, when
+/// the value is constructed, and then reused by every
+/// [`BigUint::modpow_odd`](struct.BigUint.html#method.modpow_odd) with this modulus.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, OddBigUint};
+///
+/// let m = OddBigUint::new(BigUint::from(1_000_000_007u32)).unwrap();
+/// let base = BigUint::from(3u32);
+/// let exp = BigUint::from(1_000_000_006u32);
+/// assert_eq!(base.modpow_odd(&exp, &m), BigUint::from(1u32));
+///
+/// assert!(OddBigUint::new(BigUint::from(10u32)).is_none());
+/// ```
+#[derive(Clone)]
+pub struct OddBigUint {
+    n: BigUint,
+    n0inv: u32,
+    r: BigUint,
+}
+
+impl OddBigUint {
+    /// Wraps `n` if it is odd.
+    pub fn new(n: BigUint) -> Option<OddBigUint> {
+        if n.is_even() {
+            return None;
+        }
+        let n0inv = inv_mod_u32(n.data[0]);
+        let r = monty_r(&n);
+        Some(OddBigUint {
+            n: n,
+            n0inv: n0inv,
+            r: r,
+        })
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &BigUint {
+        &self.n
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        self.n
+    }
+
+    fn reducer<'a>(&'a self) -> MontyReducer<'a> {
+        MontyReducer {
+            n: &self.n,
+            n0inv: self.n0inv,
+        }
+    }
+}
+
+impl AsRef<BigUint> for OddBigUint {
+    #[inline]
+    fn as_ref(&self) -> &BigUint {
+        &self.n
+    }
+}
+
+impl From<OddBigUint> for BigUint {
+    #[inline]
+    fn from(n: OddBigUint) -> BigUint {
+        n.n
+    }
+}
+
+impl PartialEq for OddBigUint {
+    #[inline]
+    fn eq(&self, other: &OddBigUint) -> bool {
+        self.n == other.n
+    }
+}
+
+impl Eq for OddBigUint {}
+
+impl fmt::Debug for OddBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.n, f)
+    }
+}
+
+impl fmt::Display for OddBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.n, f)
+    }
+}
+
+impl BigUint {
+    /// Returns `(self ^ exponent) % modulus`, using the constants already computed for
+    /// `modulus`.
+    ///
+    /// This is the same as [`modpow`](#method.modpow) with an odd modulus, but cannot panic
+    /// and skips the setup when many powers share a modulus.
+    pub fn modpow_odd(&self, exponent: &BigUint, modulus: &OddBigUint) -> BigUint {
+        monty_modpow_reduced(self, exponent, &modulus.reducer(), &modulus.r)
+    }
+
+    /// Returns `(self ^ exponent) % modulus`, in time that does not depend on the value of
+    /// `exponent`, for use with secret exponents as in RSA decryption or signing.
+    ///
+    /// The running time and memory access pattern depend only on the number of digits in
+    /// `exponent` and `modulus`, and on how many times `self` must be reduced when it is
+    /// not already less than `modulus`.  The exponent is scanned in fixed four-bit windows,
+    /// every step reads the whole table of powers, and the Montgomery products keep a fixed
+    /// length without normalizing, so there is no branch or index on a secret.
+    ///
+    /// This is slower than [`modpow_odd`](#method.modpow_odd), which skips the zero bits of
+    /// the exponent and should be preferred when the exponent is public.  Pad a secret
+    /// exponent to a fixed number of digits if its length should not leak either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, OddBigUint};
+    ///
+    /// let m = OddBigUint::new(BigUint::from(1_000_000_007u32)).unwrap();
+    /// let base = BigUint::from(3u32);
+    /// let exp = BigUint::from(1_000_000_006u32);
+    /// assert_eq!(base.modpow_ct(&exp, &m), BigUint::from(1u32));
+    /// ```
+    pub fn modpow_ct(&self, exponent: &BigUint, modulus: &OddBigUint) -> BigUint {
+        let m = &modulus.n.data;
+        let s = m.len();
+        let mu = 0u32.wrapping_sub(modulus.n0inv);
+        let fixed = |n: &BigUint| {
+            let mut v = n.data.clone();
+            v.resize(s, 0);
+            v
+        };
+
+        // Map the base to the Montgomery domain, multiplying by R^2 / R.
+        let r2 = fixed(&(&modulus.r * &modulus.r % &modulus.n));
+        let base = fixed(&(self % &modulus.n));
+        let mut scratch = vec![0; s];
+        let mut base_r = vec![0; s];
+        ct_monty_mul(&base, &r2, m, mu, &mut scratch, &mut base_r);
+
+        // table[i] = base^i, in the Montgomery domain.
+        let mut table = Vec::with_capacity(1 << CT_WINDOW);
+        table.push(fixed(&modulus.r));
+        for i in 1..1 << CT_WINDOW {
+            let mut next = vec![0; s];
+            ct_monty_mul(&table[i - 1], &base_r, m, mu, &mut scratch, &mut next);
+            table.push(next);
+        }
+
+        let mut acc = table[0].clone();
+        let mut tmp = vec![0; s];
+        let mut window = vec![0; s];
+        for &digit in exponent.data.iter().rev() {
+            for j in (0..big_digit::BITS / CT_WINDOW).rev() {
+                for _ in 0..CT_WINDOW {
+                    ct_monty_mul(&acc, &acc, m, mu, &mut scratch, &mut tmp);
+                    mem::swap(&mut acc, &mut tmp);
+                }
+
+                // Read every entry, keeping only the one this window selects.
+                let w = (digit >> (j * CT_WINDOW)) & ((1 << CT_WINDOW) - 1);
+                for x in window.iter_mut() {
+                    *x = 0;
+                }
+                for (i, entry) in table.iter().enumerate() {
+                    let mask = ct_eq_mask(i as u32, w);
+                    for (x, &e) in window.iter_mut().zip(entry) {
+                        *x |= e & mask;
+                    }
+                }
+                ct_monty_mul(&acc, &window, m, mu, &mut scratch, &mut tmp);
+                mem::swap(&mut acc, &mut tmp);
+            }
+        }
+
+        // Map the result back to the residues domain
+        let mut one = vec![0; s];
+        one[0] = 1;
+        ct_monty_mul(&acc, &one, m, mu, &mut scratch, &mut tmp);
+        BigUint::new(tmp)
+    }
+}
+
+// The number of exponent bits `modpow_ct` handles per multiplication.
+const CT_WINDOW: usize = 4;
+
+// All ones if `a == b`, else zero, without branching.
+#[inline]
+fn ct_eq_mask(a: u32, b: u32) -> u32 {
+    let x = a ^ b;
+    ((x | x.wrapping_neg()) >> 31).wrapping_sub(1)
+}
+
+// Montgomery multiplication in constant time, out = a * b / R mod m, using `t` as scratch
+// space.  All of the slices have the length of `m`, and `a` and `b` must be less than `m`.
+// The product is reduced row by row, and the final subtraction of `m` is always computed
+// and then selected by a mask, so the work is the same whatever the values.
+//
+// Reference:
+// Koç, Acar & Kaliski, Analyzing and Comparing Montgomery Multiplication Algorithms (CIOS)
+fn ct_monty_mul(a: &[u32], b: &[u32], m: &[u32], mu: u32, t: &mut [u32], out: &mut [u32]) {
+    let s = m.len();
+    for x in t.iter_mut() {
+        *x = 0;
+    }
+    let mut t_hi = 0u32;
+    for &ai in a {
+        // t += ai * b
+        let mut carry = 0;
+        for (tj, &bj) in t.iter_mut().zip(b) {
+            *tj = mac_with_carry(*tj, bj, ai, &mut carry);
+        }
+        let sum = u64::from(t_hi) + carry;
+        let (top, top_hi) = (sum as u32, (sum >> big_digit::BITS) as u32);
+
+        // t = (t + q * m) / 2^32, where q makes the low digit zero.
+        let q = t[0].wrapping_mul(mu);
+        let mut carry = 0;
+        mac_with_carry(t[0], m[0], q, &mut carry);
+        for j in 1..s {
+            t[j - 1] = mac_with_carry(t[j], m[j], q, &mut carry);
+        }
+        let sum = u64::from(top) + carry;
+        t[s - 1] = sum as u32;
+        t_hi = top_hi + (sum >> big_digit::BITS) as u32;
+    }
+
+    // out = t - m, kept if t overflowed into t_hi or the subtraction didn't borrow.
+    let mut borrow = 0;
+    for ((o, &tj), &mj) in out.iter_mut().zip(t.iter()).zip(m) {
+        *o = sbb(tj, mj, &mut borrow);
+    }
+    let mask = 0u32.wrapping_sub(t_hi | (borrow as u32).wrapping_add(1));
+    for (o, &tj) in out.iter_mut().zip(t.iter()) {
+        *o = (*o & mask) | (tj & !mask);
+    }
 }
@@ -0,0 +1,163 @@
+//! A `BigUint` that is known not to be zero.
+
+use std::fmt;
+use std::ops::{Div, Rem};
+
+#[cfg(has_try_from)]
+use std::convert::TryFrom;
+
+use BigUint;
+
+#[cfg(has_try_from)]
+use TryFromBigIntError;
+
+use integer::Integer;
+use traits::Zero;
+
+/// A `BigUint` that is known not to be zero.
+///
+/// The check is made once, when the value is constructed, so dividing by a
+/// `NonZeroBigUint` cannot panic, and an API can require a nonzero modulus in its
+/// signature rather than by a runtime assertion.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, NonZeroBigUint};
+///
+/// let m = NonZeroBigUint::new(BigUint::from(7u32)).unwrap();
+/// assert_eq!(BigUint::from(45u32) / &m, BigUint::from(6u32));
+/// assert_eq!(BigUint::from(45u32) % &m, BigUint::from(3u32));
+///
+/// assert!(NonZeroBigUint::new(BigUint::from(0u32)).is_none());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NonZeroBigUint(BigUint);
+
+impl NonZeroBigUint {
+    /// Wraps `n` if it is not zero.
+    #[inline]
+    pub fn new(n: BigUint) -> Option<NonZeroBigUint> {
+        if n.is_zero() {
+            None
+        } else {
+            Some(NonZeroBigUint(n))
+        }
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        self.0
+    }
+}
+
+impl AsRef<BigUint> for NonZeroBigUint {
+    #[inline]
+    fn as_ref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl From<NonZeroBigUint> for BigUint {
+    #[inline]
+    fn from(n: NonZeroBigUint) -> BigUint {
+        n.0
+    }
+}
+
+#[cfg(has_try_from)]
+impl TryFrom<BigUint> for NonZeroBigUint {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(n: BigUint) -> Result<NonZeroBigUint, TryFromBigIntError<BigUint>> {
+        if n.is_zero() {
+            Err(TryFromBigIntError::new(n))
+        } else {
+            Ok(NonZeroBigUint(n))
+        }
+    }
+}
+
+impl fmt::Debug for NonZeroBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for NonZeroBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Implements an operator on `BigUint` and `NonZeroBigUint` by value and by reference,
+/// all through the `&BigUint`, `&NonZeroBigUint` combination.
+macro_rules! impl_nonzero_op {
+    (impl $imp:ident, $method:ident) => {
+        impl<'a, 'b> $imp<&'b NonZeroBigUint> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, other: &NonZeroBigUint) -> BigUint {
+                $imp::$method(self, &other.0)
+            }
+        }
+
+        impl<'a> $imp<NonZeroBigUint> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, other: NonZeroBigUint) -> BigUint {
+                $imp::$method(self, &other)
+            }
+        }
+
+        impl<'b> $imp<&'b NonZeroBigUint> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, other: &NonZeroBigUint) -> BigUint {
+                $imp::$method(&self, other)
+            }
+        }
+
+        impl $imp<NonZeroBigUint> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn $method(self, other: NonZeroBigUint) -> BigUint {
+                $imp::$method(&self, &other)
+            }
+        }
+    };
+}
+
+impl_nonzero_op!(impl Div, div);
+impl_nonzero_op!(impl Rem, rem);
+
+impl BigUint {
+    /// Divides by a nonzero divisor, returning the quotient and remainder, which cannot
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, NonZeroBigUint};
+    ///
+    /// let d = NonZeroBigUint::new(BigUint::from(10u32)).unwrap();
+    /// let (q, r) = BigUint::from(1234u32).div_rem_nonzero(&d);
+    /// assert_eq!((q, r), (BigUint::from(123u32), BigUint::from(4u32)));
+    /// ```
+    #[inline]
+    pub fn div_rem_nonzero(&self, other: &NonZeroBigUint) -> (BigUint, BigUint) {
+        self.div_rem(&other.0)
+    }
+}
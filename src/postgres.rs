@@ -0,0 +1,362 @@
+//! Conversions to and from the PostgreSQL `NUMERIC` type, for `diesel` and `sqlx`.
+//!
+//! `NUMERIC` values are sent as base-10000 digits, most significant first, with a
+//! `weight` giving the power of 10000 of the first digit.  Only values without a
+//! fractional part can be converted to a `BigInt` or `BigUint`; anything else, including
+//! `NaN`, is reported as an error rather than rounded.
+
+use std::fmt::Write;
+
+use BigInt;
+use BigUint;
+use Sign::{self, Minus, Plus};
+
+use traits::Zero;
+
+const NBASE: i16 = 10000;
+
+/// The largest weight of a `NUMERIC`, which has at most 131072 digits before the point.
+const MAX_WEIGHT: usize = 131072 / 4 - 1;
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000;
+
+/// Splits a magnitude into base-10000 digits, returning the weight and digits without
+/// trailing zeros, as PostgreSQL stores them.
+fn to_base10000(n: &BigUint) -> Result<(i16, Vec<i16>), &'static str> {
+    if n.is_zero() {
+        return Ok((0, Vec::new()));
+    }
+    let decimal = n.to_str_radix(10);
+    let len = decimal.len();
+    let first = (len - 1) % 4 + 1;
+    let weight = (len - 1) / 4;
+    if weight > MAX_WEIGHT {
+        return Err("value is too large for NUMERIC");
+    }
+    let mut digits: Vec<i16> = Some(&decimal[..first])
+        .into_iter()
+        .chain((first..len).step_by(4).map(|i| &decimal[i..i + 4]))
+        .map(|s| s.parse().unwrap())
+        .collect();
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    Ok((weight as i16, digits))
+}
+
+/// Combines base-10000 digits into a magnitude, failing if there's a nonzero fractional
+/// part or an invalid digit.
+fn from_base10000(weight: i16, digits: &[i16]) -> Result<BigUint, &'static str> {
+    if digits.iter().any(|d| !(0..NBASE).contains(d)) {
+        return Err("invalid NUMERIC digit");
+    }
+    let int_len = if weight < 0 { 0 } else { weight as usize + 1 };
+    if digits.iter().skip(int_len).any(|&d| d != 0) {
+        return Err("NUMERIC value has a fractional part");
+    }
+    if digits.len().min(int_len) == 0 {
+        return Ok(BigUint::zero());
+    }
+    let mut decimal = String::with_capacity(int_len * 4);
+    for i in 0..int_len {
+        let d = digits.get(i).cloned().unwrap_or(0);
+        write!(decimal, "{:04}", d).unwrap();
+    }
+    Ok(BigUint::parse_bytes(decimal.as_bytes(), 10).unwrap())
+}
+
+/// Parses the text format of a `NUMERIC`, allowing a fractional part of zeros.
+fn from_text(s: &str) -> Result<BigInt, &'static str> {
+    let int = match s.find('.') {
+        Some(i) if s[i + 1..].bytes().all(|b| b == b'0') => &s[..i],
+        Some(_) => return Err("NUMERIC value has a fractional part"),
+        None => s,
+    };
+    int.parse().map_err(|_| "invalid NUMERIC value")
+}
+
+/// Writes the binary format of a `NUMERIC` with the given sign and magnitude.
+fn write_binary(sign: Sign, n: &BigUint, out: &mut Vec<u8>) -> Result<(), &'static str> {
+    let (weight, digits) = to_base10000(n)?;
+    let sign = if sign == Minus {
+        SIGN_NEGATIVE
+    } else {
+        SIGN_POSITIVE
+    };
+    let header = [digits.len() as u16, weight as u16, sign, 0];
+    let words = header
+        .iter()
+        .cloned()
+        .chain(digits.iter().map(|&d| d as u16));
+    out.reserve(8 + 2 * digits.len());
+    for word in words {
+        out.push((word >> 8) as u8);
+        out.push(word as u8);
+    }
+    Ok(())
+}
+
+/// Reads the binary format of a `NUMERIC`.
+fn read_binary(bytes: &[u8]) -> Result<BigInt, &'static str> {
+    if bytes.len() < 8 || bytes.len() % 2 != 0 {
+        return Err("invalid NUMERIC value");
+    }
+    let words: Vec<u16> = bytes
+        .chunks(2)
+        .map(|c| (u16::from(c[0]) << 8) | u16::from(c[1]))
+        .collect();
+    let (ndigits, weight, sign) = (words[0] as usize, words[1] as i16, words[2]);
+    let digits: Vec<i16> = words[4..].iter().map(|&d| d as i16).collect();
+    if digits.len() != ndigits {
+        return Err("invalid NUMERIC value");
+    }
+    let sign = match sign {
+        SIGN_POSITIVE => Plus,
+        SIGN_NEGATIVE => Minus,
+        SIGN_NAN => return Err("NUMERIC value is NaN"),
+        _ => return Err("invalid NUMERIC sign"),
+    };
+    Ok(BigInt::from_biguint(sign, from_base10000(weight, &digits)?))
+}
+
+fn nonnegative(n: BigInt) -> Result<BigUint, &'static str> {
+    n.to_biguint().ok_or("NUMERIC value is negative")
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use diesel::deserialize::FromSqlRow;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::expression::AsExpression;
+    use diesel::pg::data_types::PgNumeric;
+    use diesel::pg::{Pg, PgValue};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::Numeric;
+
+    use super::{from_base10000, nonnegative, to_base10000};
+    use bigint::magnitude;
+    use Sign::Minus;
+    use {BigInt, BigUint};
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Numeric)]
+    #[allow(dead_code)]
+    struct BigIntProxy(BigInt);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Numeric)]
+    #[allow(dead_code)]
+    struct BigUintProxy(BigUint);
+
+    fn to_numeric(n: &BigInt) -> Result<PgNumeric, &'static str> {
+        let (weight, digits) = to_base10000(magnitude(n))?;
+        Ok(if n.sign() == Minus {
+            PgNumeric::Negative {
+                weight,
+                scale: 0,
+                digits,
+            }
+        } else {
+            PgNumeric::Positive {
+                weight,
+                scale: 0,
+                digits,
+            }
+        })
+    }
+
+    fn from_numeric(numeric: PgNumeric) -> Result<BigInt, &'static str> {
+        match numeric {
+            PgNumeric::Positive { weight, digits, .. } => {
+                from_base10000(weight, &digits).map(BigInt::from)
+            }
+            PgNumeric::Negative { weight, digits, .. } => {
+                from_base10000(weight, &digits).map(|n| -BigInt::from(n))
+            }
+            PgNumeric::NaN => Err("NUMERIC value is NaN"),
+        }
+    }
+
+    impl ToSql<Numeric, Pg> for BigInt {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let numeric = to_numeric(self)?;
+            ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+        }
+    }
+
+    impl FromSql<Numeric, Pg> for BigInt {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            let numeric = PgNumeric::from_sql(value)?;
+            Ok(from_numeric(numeric)?)
+        }
+    }
+
+    impl ToSql<Numeric, Pg> for BigUint {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let numeric = to_numeric(&BigInt::from(self.clone()))?;
+            ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+        }
+    }
+
+    impl FromSql<Numeric, Pg> for BigUint {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            let numeric = PgNumeric::from_sql(value)?;
+            Ok(nonnegative(from_numeric(numeric)?)?)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use sqlx::decode::Decode;
+    use sqlx::encode::{Encode, IsNull};
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{
+        PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+    };
+    use sqlx::Type;
+
+    use super::{from_text, nonnegative, read_binary, write_binary};
+    use bigint::magnitude;
+    use Sign::Plus;
+    use {BigInt, BigUint};
+
+    const NUMERIC_OID: u32 = 1700;
+    const NUMERIC_ARRAY_OID: u32 = 1231;
+
+    fn decode(value: PgValueRef) -> Result<BigInt, BoxDynError> {
+        let n = match value.format() {
+            PgValueFormat::Binary => read_binary(value.as_bytes()?)?,
+            PgValueFormat::Text => from_text(value.as_str()?)?,
+        };
+        Ok(n)
+    }
+
+    impl Type<Postgres> for BigInt {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(Oid(NUMERIC_OID))
+        }
+    }
+
+    impl PgHasArrayType for BigInt {
+        fn array_type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(Oid(NUMERIC_ARRAY_OID))
+        }
+    }
+
+    impl<'q> Encode<'q, Postgres> for BigInt {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            write_binary(self.sign(), magnitude(self), buf)?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for BigInt {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            decode(value)
+        }
+    }
+
+    impl Type<Postgres> for BigUint {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(Oid(NUMERIC_OID))
+        }
+    }
+
+    impl PgHasArrayType for BigUint {
+        fn array_type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(Oid(NUMERIC_ARRAY_OID))
+        }
+    }
+
+    impl<'q> Encode<'q, Postgres> for BigUint {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            write_binary(Plus, self, buf)?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for BigUint {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(nonnegative(decode(value)?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bigint::magnitude;
+    use traits::pow;
+
+    fn binary(s: &str) -> Vec<u8> {
+        let n: BigInt = s.parse().unwrap();
+        let mut out = Vec::new();
+        write_binary(n.sign(), magnitude(&n), &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(binary("0"), [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(binary("-1"), [0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1]);
+        assert_eq!(binary("10000"), [0, 1, 0, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            binary("12345678"),
+            [0, 2, 0, 1, 0, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e]
+        );
+        assert_eq!(
+            binary("100000002"),
+            [0, 3, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2]
+        );
+
+        for s in &[
+            "0",
+            "1",
+            "-1",
+            "9999",
+            "10000",
+            "-123456789012345678901234567890",
+        ] {
+            let n: BigInt = s.parse().unwrap();
+            assert_eq!(read_binary(&binary(s)), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_read_binary_fraction() {
+        // 1.5, with weight 0 and digits [1, 5000]
+        let bytes = [0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0x13, 0x88];
+        assert!(read_binary(&bytes).is_err());
+        // 1.0000, with a zero fractional digit
+        let bytes = [0, 2, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0];
+        assert_eq!(read_binary(&bytes), Ok(BigInt::from(1)));
+        // 0.5
+        let bytes = [0, 1, 0xff, 0xff, 0, 0, 0, 1, 0x13, 0x88];
+        assert!(read_binary(&bytes).is_err());
+        // NaN
+        assert!(read_binary(&[0, 0, 0, 0, 0xc0, 0, 0, 0]).is_err());
+        // truncated
+        assert!(read_binary(&[0, 2, 0, 1, 0, 0, 0, 0, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(from_text("-42"), Ok(BigInt::from(-42)));
+        assert_eq!(from_text("42.000"), Ok(BigInt::from(42)));
+        assert!(from_text("42.5").is_err());
+        assert!(from_text("NaN").is_err());
+    }
+
+    #[test]
+    fn test_too_large() {
+        let limit = pow(BigUint::from(10u32), 131072);
+        assert!(to_base10000(&(&limit - 1u32)).is_ok());
+        assert!(to_base10000(&limit).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Strategies for generating `BigUint` and `BigInt` in `proptest` property tests.
+//!
+//! Values are built from a vector of at most `max_digits` 32-bit digits, so shrinking
+//! drops digits and shrinks the remaining ones, moving toward zero and fewer digits.
+//! Negative values shrink toward their positive counterparts.
+//!
+//! `BigUint` and `BigInt` also implement `proptest::arbitrary::Arbitrary`, with values of
+//! up to `DEFAULT_MAX_DIGITS` digits, so `any::<BigUint>()` works too.
+//!
+//! ```ignore
+//! use num_bigint::proptest_strategies::biguint;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn add_commutes(a in biguint(8), b in biguint(8)) {
+//!         prop_assert_eq!(&a + &b, &b + &a);
+//!     }
+//! }
+//! ```
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use {BigInt, BigUint, Sign};
+
+/// The number of digits in values from `any::<BigUint>()` and `any::<BigInt>()`.
+pub const DEFAULT_MAX_DIGITS: usize = 16;
+
+/// A strategy for a `BigUint` of at most `max_digits` 32-bit digits.
+pub fn biguint(max_digits: usize) -> BoxedStrategy<BigUint> {
+    collection::vec(any::<u32>(), 0..max_digits + 1)
+        .prop_map(BigUint::new)
+        .boxed()
+}
+
+/// A strategy for a `BigInt` whose magnitude has at most `max_digits` 32-bit digits.
+pub fn bigint(max_digits: usize) -> BoxedStrategy<BigInt> {
+    // `false` comes first when shrinking, which makes the value positive.
+    (any::<bool>(), biguint(max_digits))
+        .prop_map(|(negative, n)| {
+            let sign = if negative { Sign::Minus } else { Sign::Plus };
+            BigInt::from_biguint(sign, n)
+        })
+        .boxed()
+}
+
+impl Arbitrary for BigUint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<BigUint>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        biguint(DEFAULT_MAX_DIGITS)
+    }
+}
+
+impl Arbitrary for BigInt {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<BigInt>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        bigint(DEFAULT_MAX_DIGITS)
+    }
+}
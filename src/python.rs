@@ -0,0 +1,111 @@
+//! Conversions between Python's `int` and `BigInt`/`BigUint`, for `pyo3`.
+//!
+//! Values cross the boundary as little-endian byte strings, through `int.from_bytes`
+//! and `int.to_bytes`, so they are exact at any size and also work with the limited
+//! Python API.
+
+use pyo3::ffi;
+use pyo3::types::{PyAnyMethods, PyBytes, PyBytesMethods, PyDict, PyDictMethods, PyInt};
+use pyo3::{intern, Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, Python};
+
+use BigInt;
+use BigUint;
+
+/// Creates a Python `int` from little-endian bytes.
+fn int_from_le_bytes<'py>(
+    py: Python<'py>,
+    bytes: &[u8],
+    signed: bool,
+) -> PyResult<Bound<'py, PyInt>> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "signed"), signed)?;
+    let int = py.get_type::<PyInt>().call_method(
+        intern!(py, "from_bytes"),
+        (PyBytes::new(py, bytes), intern!(py, "little")),
+        Some(&kwargs),
+    )?;
+    Ok(int.cast_into::<PyInt>()?)
+}
+
+/// Returns the little-endian bytes of a Python object supporting `__index__`.
+///
+/// Without `signed`, a negative value raises `OverflowError`.
+fn int_to_le_bytes(ob: Borrowed<'_, '_, PyAny>, signed: bool) -> PyResult<Vec<u8>> {
+    let py = ob.py();
+    let int = unsafe { Bound::from_owned_ptr_or_err(py, ffi::PyNumber_Index(ob.as_ptr()))? };
+    let bits: usize = int.call_method0(intern!(py, "bit_length"))?.extract()?;
+    let len = if signed {
+        bits / 8 + 1
+    } else {
+        bits.div_ceil(8)
+    };
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "signed"), signed)?;
+    let bytes = int.call_method(
+        intern!(py, "to_bytes"),
+        (len, intern!(py, "little")),
+        Some(&kwargs),
+    )?;
+    Ok(bytes.cast_into::<PyBytes>()?.as_bytes().to_vec())
+}
+
+impl<'py> IntoPyObject<'py> for BigInt {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py> IntoPyObject<'py> for &'a BigInt {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        int_from_le_bytes(py, &self.to_signed_bytes_le(), true)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for BigUint {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py> IntoPyObject<'py> for &'a BigUint {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        int_from_le_bytes(py, &self.to_bytes_le(), false)
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for BigInt {
+    type Error = PyErr;
+
+    /// Extracts a `BigInt` from a Python `int`, or any object with `__index__`.
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<BigInt> {
+        int_to_le_bytes(ob, true).map(|bytes| BigInt::from_signed_bytes_le(&bytes))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for BigUint {
+    type Error = PyErr;
+
+    /// Extracts a `BigUint` from a non-negative Python `int`, or any object with
+    /// `__index__`.  A negative value raises `OverflowError`.
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<BigUint> {
+        int_to_le_bytes(ob, false).map(|bytes| BigUint::from_bytes_le(&bytes))
+    }
+}
@@ -0,0 +1,256 @@
+//! Base58, base32, and base64 string conversions.
+//!
+//! These treat the string as the digits of a number, most significant first, in the
+//! given alphabet.  This is how base58 is used for keys and amounts, but it differs from
+//! the usual *byte string* encodings of base32 and base64: there is no padding, and
+//! leading zero digits carry no meaning, so `"AAAB"` and `"B"` both decode to one.  To
+//! preserve leading zero bytes, encode the bytes with a byte-oriented codec instead.
+
+use BigInt;
+use BigUint;
+use Sign::{Minus, NoSign, Plus};
+
+use bigint::magnitude;
+
+/// The Bitcoin base58 alphabet, which omits `0`, `O`, `I`, and `l`.
+const BASE58: &'static [u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// The RFC 4648 base32 alphabet.
+const BASE32: &'static [u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+/// The RFC 4648 base64 alphabet.
+const BASE64: &'static [u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode(n: &BigUint, alphabet: &[u8]) -> String {
+    let digits = n.to_radix_be(alphabet.len() as u32);
+    let s = digits.iter().map(|&d| alphabet[d as usize]).collect();
+    // the alphabets are all ASCII
+    unsafe { String::from_utf8_unchecked(s) }
+}
+
+fn decode(s: &str, alphabet: &[u8]) -> Option<BigUint> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut digits = Vec::with_capacity(s.len());
+    for b in s.bytes() {
+        match alphabet.iter().position(|&a| a == b) {
+            Some(d) => digits.push(d as u8),
+            None => return None,
+        }
+    }
+    BigUint::from_radix_be(&digits, alphabet.len() as u32)
+}
+
+fn encode_signed(n: &BigInt, alphabet: &[u8]) -> String {
+    let s = encode(magnitude(n), alphabet);
+    match n.sign() {
+        Minus => format!("-{}", s),
+        NoSign | Plus => s,
+    }
+}
+
+fn decode_signed(s: &str, alphabet: &[u8]) -> Option<BigInt> {
+    if s.starts_with('-') {
+        decode(&s[1..], alphabet).map(|n| BigInt::from_biguint(Minus, n))
+    } else {
+        decode(s, alphabet).map(BigInt::from)
+    }
+}
+
+impl BigUint {
+    /// Returns the number as a string of digits in the Bitcoin base58 alphabet, most
+    /// significant first.  Zero is a single zero digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(3430008u32).to_base58(), "Jad5");
+    /// ```
+    pub fn to_base58(&self) -> String {
+        encode(self, BASE58)
+    }
+
+    /// Parses a string of digits in the Bitcoin base58 alphabet, most significant first.
+    ///
+    /// Leading zero digits are ignored.  Returns `None` if the string is empty or has a
+    /// character outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_base58("Jad5"), Some(BigUint::from(3430008u32)));
+    /// ```
+    pub fn from_base58(s: &str) -> Option<BigUint> {
+        decode(s, BASE58)
+    }
+
+    /// Returns the number as a string of digits in the RFC 4648 base32 alphabet, most
+    /// significant first.  Zero is a single zero digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1024u32).to_base32(), "BAA");
+    /// ```
+    pub fn to_base32(&self) -> String {
+        encode(self, BASE32)
+    }
+
+    /// Parses a string of digits in the RFC 4648 base32 alphabet, most significant first.
+    ///
+    /// Leading zero digits are ignored.  Returns `None` if the string is empty or has a
+    /// character outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_base32("BAA"), Some(BigUint::from(1024u32)));
+    /// ```
+    pub fn from_base32(s: &str) -> Option<BigUint> {
+        decode(s, BASE32)
+    }
+
+    /// Returns the number as a string of digits in the RFC 4648 base64 alphabet, most
+    /// significant first.  Zero is a single zero digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(4095u32).to_base64(), "//");
+    /// ```
+    pub fn to_base64(&self) -> String {
+        encode(self, BASE64)
+    }
+
+    /// Parses a string of digits in the RFC 4648 base64 alphabet, most significant first.
+    ///
+    /// Leading zero digits are ignored.  Returns `None` if the string is empty or has a
+    /// character outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from_base64("//"), Some(BigUint::from(4095u32)));
+    /// ```
+    pub fn from_base64(s: &str) -> Option<BigUint> {
+        decode(s, BASE64)
+    }
+}
+
+impl BigInt {
+    /// Returns the number as a string of digits in the Bitcoin base58 alphabet, most
+    /// significant first, with a leading `-` if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-3430008).to_base58(), "-Jad5");
+    /// assert_eq!(BigInt::from(3430008).to_base58(), "Jad5");
+    /// ```
+    pub fn to_base58(&self) -> String {
+        encode_signed(self, BASE58)
+    }
+
+    /// Parses a string of digits in the Bitcoin base58 alphabet, most significant first, with
+    /// an optional leading `-`.
+    ///
+    /// Returns `None` if there are no digits or a character is outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_base58("-Jad5"), Some(BigInt::from(-3430008)));
+    /// assert_eq!(BigInt::from_base58("Jad5"), Some(BigInt::from(3430008)));
+    ///
+    /// // a sign alone has no digits, and `+` is not a sign
+    /// assert_eq!(BigInt::from_base58("-"), None);
+    /// assert_eq!(BigInt::from_base58("+Jad5"), None);
+    /// ```
+    pub fn from_base58(s: &str) -> Option<BigInt> {
+        decode_signed(s, BASE58)
+    }
+
+    /// Returns the number as a string of digits in the RFC 4648 base32 alphabet, most
+    /// significant first, with a leading `-` if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1024).to_base32(), "-BAA");
+    /// assert_eq!(BigInt::from(1024).to_base32(), "BAA");
+    /// ```
+    pub fn to_base32(&self) -> String {
+        encode_signed(self, BASE32)
+    }
+
+    /// Parses a string of digits in the RFC 4648 base32 alphabet, most significant first, with
+    /// an optional leading `-`.
+    ///
+    /// Returns `None` if there are no digits or a character is outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_base32("-BAA"), Some(BigInt::from(-1024)));
+    /// assert_eq!(BigInt::from_base32("BAA"), Some(BigInt::from(1024)));
+    /// assert_eq!(BigInt::from_base32("-"), None);
+    /// ```
+    pub fn from_base32(s: &str) -> Option<BigInt> {
+        decode_signed(s, BASE32)
+    }
+
+    /// Returns the number as a string of digits in the RFC 4648 base64 alphabet, most
+    /// significant first, with a leading `-` if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-4095).to_base64(), "-//");
+    /// assert_eq!(BigInt::from(4095).to_base64(), "//");
+    /// ```
+    pub fn to_base64(&self) -> String {
+        encode_signed(self, BASE64)
+    }
+
+    /// Parses a string of digits in the RFC 4648 base64 alphabet, most significant first, with
+    /// an optional leading `-`.
+    ///
+    /// Returns `None` if there are no digits or a character is outside the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_base64("-//"), Some(BigInt::from(-4095)));
+    /// assert_eq!(BigInt::from_base64("//"), Some(BigInt::from(4095)));
+    ///
+    /// // `+` is a base64 digit, not a sign
+    /// assert_eq!(BigInt::from_base64("+/"), Some(BigInt::from(62 * 64 + 63)));
+    /// ```
+    pub fn from_base64(s: &str) -> Option<BigInt> {
+        decode_signed(s, BASE64)
+    }
+}
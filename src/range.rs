@@ -0,0 +1,125 @@
+//! Iterating over ranges of big integers.
+//!
+//! The standard `start..end` ranges only iterate over types implementing the unstable
+//! `Step` trait, so these functions provide the same for `BigUint` and `BigInt`.
+
+use std::ops::AddAssign;
+
+use traits::{One, Zero};
+
+/// An iterator over a range of numbers, made by [`range`](fn.range.html),
+/// [`range_inclusive`](fn.range_inclusive.html), [`range_step`](fn.range_step.html), or
+/// [`range_step_inclusive`](fn.range_step_inclusive.html).
+#[derive(Clone, Debug)]
+pub struct BigRange<T> {
+    next: Option<T>,
+    end: T,
+    step: T,
+    inclusive: bool,
+}
+
+impl<T> Iterator for BigRange<T>
+where
+    T: Clone + PartialOrd + for<'a> AddAssign<&'a T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let n = match self.next.take() {
+            Some(n) => n,
+            None => return None,
+        };
+        let in_range = if self.inclusive {
+            n <= self.end
+        } else {
+            n < self.end
+        };
+        if !in_range {
+            return None;
+        }
+        let mut next = n.clone();
+        next += &self.step;
+        self.next = Some(next);
+        Some(n)
+    }
+}
+
+fn new<T: PartialOrd + Zero>(start: T, end: T, step: T, inclusive: bool) -> BigRange<T> {
+    assert!(step > T::zero(), "The step must be positive");
+    BigRange {
+        next: Some(start),
+        end: end,
+        step: step,
+        inclusive: inclusive,
+    }
+}
+
+/// Returns an iterator over `start` up to but not including `end`, like `start..end`.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{range, BigUint};
+///
+/// let start = BigUint::from(1u32) << 100;
+/// let end = &start + 3u32;
+/// let v: Vec<_> = range(start.clone(), end).collect();
+/// assert_eq!(v, [start.clone(), &start + 1u32, &start + 2u32]);
+/// ```
+pub fn range<T>(start: T, end: T) -> BigRange<T>
+where
+    T: PartialOrd + Zero + One,
+{
+    new(start, end, T::one(), false)
+}
+
+/// Returns an iterator over `start` up to and including `end`, like `start..=end`.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{range_inclusive, BigInt};
+///
+/// let v: Vec<_> = range_inclusive(BigInt::from(-1), BigInt::from(1)).collect();
+/// assert_eq!(v, [BigInt::from(-1), BigInt::from(0), BigInt::from(1)]);
+/// ```
+pub fn range_inclusive<T>(start: T, end: T) -> BigRange<T>
+where
+    T: PartialOrd + Zero + One,
+{
+    new(start, end, T::one(), true)
+}
+
+/// Returns an iterator over `start` up to but not including `end`, going up by `step`.
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{range_step, BigUint};
+///
+/// let v: Vec<_> = range_step(BigUint::from(0u32), BigUint::from(10u32), BigUint::from(4u32))
+///     .collect();
+/// assert_eq!(v, [BigUint::from(0u32), BigUint::from(4u32), BigUint::from(8u32)]);
+/// ```
+pub fn range_step<T>(start: T, end: T, step: T) -> BigRange<T>
+where
+    T: PartialOrd + Zero,
+{
+    new(start, end, step, false)
+}
+
+/// Returns an iterator over `start` up to and including `end`, going up by `step`.
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+pub fn range_step_inclusive<T>(start: T, end: T, step: T) -> BigRange<T>
+where
+    T: PartialOrd + Zero,
+{
+    new(start, end, step, true)
+}
@@ -0,0 +1,224 @@
+//! Division and float conversion with a choice of rounding.
+
+use std::cmp::Ordering;
+use std::{f32, f64};
+
+use integer::Integer;
+use traits::{One, ToPrimitive, Zero};
+
+use bigint::{magnitude, Sign};
+use {BigInt, BigUint};
+
+/// How to round a result that is not exact, as in
+/// [`BigUint::div_round`](struct.BigUint.html#method.div_round),
+/// [`BigInt::div_round`](struct.BigInt.html#method.div_round) and the float conversions
+/// like [`BigInt::to_f64_round`](struct.BigInt.html#method.to_f64_round).
+///
+/// The `Half` modes round to the nearest integer, and differ only in how they break ties.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, which is what the `/` operator does.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest integer, and ties away from zero.
+    HalfUp,
+    /// Round to the nearest integer, and ties toward zero.
+    HalfDown,
+    /// Round to the nearest integer, and ties to the even one, as banks do.
+    HalfEven,
+}
+
+// Returns whether a truncated quotient `q` with the nonzero remainder `r` of division by
+// `d` should be rounded away from zero.  All three are magnitudes.
+fn round_away(mode: RoundingMode, negative: bool, q: &BigUint, r: &BigUint, d: &BigUint) -> bool {
+    use self::RoundingMode::*;
+    let half = || (r.clone() << 1usize).cmp(d);
+    match mode {
+        Floor => negative,
+        Ceil => !negative,
+        Down => false,
+        Up => true,
+        HalfUp => half() != Ordering::Less,
+        HalfDown => half() == Ordering::Greater,
+        HalfEven => match half() {
+            Ordering::Less => false,
+            Ordering::Equal => q.is_odd(),
+            Ordering::Greater => true,
+        },
+    }
+}
+
+// Rounds the magnitude `n` to at most `digits` significant bits, returning that mantissa
+// and the power of two to scale it by.
+fn round_mantissa(n: &BigUint, digits: u64, mode: RoundingMode, negative: bool) -> (u64, u64) {
+    let bits = n.bit_len();
+    if bits <= digits {
+        return (n.to_u64().unwrap(), 0);
+    }
+    let shift = bits - digits;
+    let q = n.shr_u64(shift);
+    let r = n - q.shl_u64(shift);
+    let mut m = q.to_u64().unwrap();
+    if !r.is_zero() && round_away(mode, negative, &q, &r, &BigUint::one().shl_u64(shift)) {
+        m += 1;
+        if m >> digits != 0 {
+            // carried into a new bit, which is a power of two, so drop the zero below it
+            return (m >> 1, shift + 1);
+        }
+    }
+    (m, shift)
+}
+
+// Returns whether a value too large for a float rounds to infinity, rather than to the
+// largest finite value.
+fn overflows_to_infinity(mode: RoundingMode, negative: bool) -> bool {
+    match mode {
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::Down => false,
+        _ => true,
+    }
+}
+
+macro_rules! impl_to_float_round {
+    ($name:ident, $f:ident) => {
+        fn $name(n: &BigUint, negative: bool, mode: RoundingMode) -> $f {
+            let digits = u64::from($f::MANTISSA_DIGITS);
+            let (m, exp) = round_mantissa(n, digits, mode, negative);
+            let x = if exp + digits > $f::MAX_EXP as u64 {
+                if overflows_to_infinity(mode, negative) {
+                    $f::INFINITY
+                } else {
+                    $f::MAX
+                }
+            } else {
+                // both factors are exact, and so is their product
+                (m as $f) * (2.0 as $f).powi(exp as i32)
+            };
+            if negative {
+                -x
+            } else {
+                x
+            }
+        }
+    };
+}
+
+impl_to_float_round!(to_f32_round, f32);
+impl_to_float_round!(to_f64_round, f64);
+
+impl BigUint {
+    /// Returns `self / other`, rounded as `mode` says.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, RoundingMode};
+    ///
+    /// let d = BigUint::from(10u32);
+    /// let n = BigUint::from(25u32);
+    /// assert_eq!(n.div_round(&d, RoundingMode::Down), BigUint::from(2u32));
+    /// assert_eq!(n.div_round(&d, RoundingMode::HalfUp), BigUint::from(3u32));
+    /// assert_eq!(n.div_round(&d, RoundingMode::HalfEven), BigUint::from(2u32));
+    /// ```
+    pub fn div_round(&self, other: &BigUint, mode: RoundingMode) -> BigUint {
+        let (mut q, r) = self.div_rem(other);
+        if !r.is_zero() && round_away(mode, false, &q, &r, other) {
+            q += 1u32;
+        }
+        q
+    }
+
+    /// Converts `self` to the nearest `f64` in the direction `mode` says.
+    ///
+    /// Unlike [`to_f64`](#method.to_f64), this never fails: a value beyond the largest
+    /// finite `f64` gives either that or infinity, as IEEE 754 rounding would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, RoundingMode};
+    ///
+    /// // 2^53 + 1 is halfway between two `f64` values.
+    /// let n = (BigUint::from(1u32) << 53usize) + 1u32;
+    /// assert_eq!(n.to_f64_round(RoundingMode::Floor), 9007199254740992.0);
+    /// assert_eq!(n.to_f64_round(RoundingMode::Ceil), 9007199254740994.0);
+    /// assert_eq!(n.to_f64_round(RoundingMode::HalfEven), 9007199254740992.0);
+    /// ```
+    pub fn to_f64_round(&self, mode: RoundingMode) -> f64 {
+        to_f64_round(self, false, mode)
+    }
+
+    /// Converts `self` to the nearest `f32` in the direction `mode` says.
+    ///
+    /// See [`to_f64_round`](#method.to_f64_round).
+    pub fn to_f32_round(&self, mode: RoundingMode) -> f32 {
+        to_f32_round(self, false, mode)
+    }
+}
+
+impl BigInt {
+    /// Returns `self / other`, rounded as `mode` says.
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, RoundingMode};
+    ///
+    /// let d = BigInt::from(10);
+    /// let n = BigInt::from(-25);
+    /// assert_eq!(n.div_round(&d, RoundingMode::Floor), BigInt::from(-3));
+    /// assert_eq!(n.div_round(&d, RoundingMode::Ceil), BigInt::from(-2));
+    /// assert_eq!(n.div_round(&d, RoundingMode::HalfUp), BigInt::from(-3));
+    /// assert_eq!(n.div_round(&d, RoundingMode::HalfEven), BigInt::from(-2));
+    /// ```
+    pub fn div_round(&self, other: &BigInt, mode: RoundingMode) -> BigInt {
+        let d = magnitude(other);
+        let (mut q, r) = magnitude(self).div_rem(d);
+        let negative = self.sign() != other.sign();
+        if !r.is_zero() && round_away(mode, negative, &q, &r, d) {
+            q += 1u32;
+        }
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        BigInt::from_biguint(sign, q)
+    }
+
+    /// Converts `self` to the nearest `f64` in the direction `mode` says.
+    ///
+    /// `Floor` and `Ceil` give certified lower and upper bounds on the value.  Unlike
+    /// [`to_f64`](#method.to_f64), this never fails: a value beyond the finite `f64`
+    /// range gives either the largest finite one or infinity, as IEEE 754 rounding would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, RoundingMode};
+    ///
+    /// let n: BigInt = -(BigInt::from(1) << 53usize) - 1;
+    /// assert_eq!(n.to_f64_round(RoundingMode::Floor), -9007199254740994.0);
+    /// assert_eq!(n.to_f64_round(RoundingMode::Ceil), -9007199254740992.0);
+    ///
+    /// let huge: BigInt = BigInt::from(1) << 1024usize;
+    /// assert_eq!(huge.to_f64_round(RoundingMode::Down), std::f64::MAX);
+    /// assert_eq!(huge.to_f64_round(RoundingMode::HalfEven), std::f64::INFINITY);
+    /// ```
+    pub fn to_f64_round(&self, mode: RoundingMode) -> f64 {
+        to_f64_round(magnitude(self), self.sign() == Sign::Minus, mode)
+    }
+
+    /// Converts `self` to the nearest `f32` in the direction `mode` says.
+    ///
+    /// See [`to_f64_round`](#method.to_f64_round).
+    pub fn to_f32_round(&self, mode: RoundingMode) -> f32 {
+        to_f32_round(magnitude(self), self.sign() == Sign::Minus, mode)
+    }
+}
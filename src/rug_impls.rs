@@ -0,0 +1,99 @@
+//! Conversions between `BigUint`/`BigInt` and `rug`'s `Integer`.
+//!
+//! Values are transferred through their 32-bit digits, which are the same size as our
+//! own, so conversions take linear time.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use rug::integer::Order;
+use rug::Integer;
+
+use BigInt;
+use BigUint;
+use Sign::{Minus, NoSign, Plus};
+use TryFromBigIntError;
+
+use bigint::magnitude;
+
+fn integer_from(n: &BigUint) -> Integer {
+    Integer::from_digits(&n.to_u32_digits(), Order::Lsf)
+}
+
+/// Returns the magnitude of `n`, as `to_digits` ignores the sign.
+fn biguint_from(n: &Integer) -> BigUint {
+    BigUint::new(n.to_digits::<u32>(Order::Lsf))
+}
+
+fn is_negative(n: &Integer) -> bool {
+    n.cmp0() == Ordering::Less
+}
+
+impl<'a> From<&'a BigUint> for Integer {
+    #[inline]
+    fn from(n: &BigUint) -> Self {
+        integer_from(n)
+    }
+}
+
+impl From<BigUint> for Integer {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        integer_from(&n)
+    }
+}
+
+impl<'a> From<&'a BigInt> for Integer {
+    fn from(n: &BigInt) -> Self {
+        let m = integer_from(magnitude(n));
+        match n.sign() {
+            Minus => -m,
+            NoSign | Plus => m,
+        }
+    }
+}
+
+impl From<BigInt> for Integer {
+    #[inline]
+    fn from(n: BigInt) -> Self {
+        Integer::from(&n)
+    }
+}
+
+impl<'a> From<&'a Integer> for BigInt {
+    fn from(n: &Integer) -> Self {
+        let sign = if is_negative(n) { Minus } else { Plus };
+        BigInt::from_biguint(sign, biguint_from(n))
+    }
+}
+
+impl From<Integer> for BigInt {
+    #[inline]
+    fn from(n: Integer) -> Self {
+        BigInt::from(&n)
+    }
+}
+
+impl<'a> TryFrom<&'a Integer> for BigUint {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(n: &Integer) -> Result<BigUint, TryFromBigIntError<()>> {
+        if is_negative(n) {
+            Err(TryFromBigIntError::new(()))
+        } else {
+            Ok(biguint_from(n))
+        }
+    }
+}
+
+impl TryFrom<Integer> for BigUint {
+    type Error = TryFromBigIntError<Integer>;
+
+    fn try_from(n: Integer) -> Result<BigUint, TryFromBigIntError<Integer>> {
+        if is_negative(&n) {
+            Err(TryFromBigIntError::new(n))
+        } else {
+            Ok(biguint_from(&n))
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Alternative serde representations of `BigUint` and `BigInt`, for use with
+//! `#[serde(with = "...")]`.
+//!
+//! The default `Serialize` and `Deserialize` implementations write the magnitude as a
+//! sequence of `u32` digits, which is neither readable in formats like JSON nor as compact
+//! as it could be in binary formats.  These modules offer the two common alternatives:
+//!
+//! - [`decimal`](decimal/index.html) writes the value as a decimal string, like `"-1234"`.
+//! - [`bytes`](bytes/index.html) writes the value as a little-endian byte string, using
+//!   two's complement for `BigInt`.
+//!
+//! For example, with `serde_derive`:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "num_bigint::serde_helpers::decimal")]
+//!     balance: BigInt,
+//!     #[serde(with = "num_bigint::serde_helpers::bytes")]
+//!     key: BigUint,
+//! }
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+
+mod sealed {
+    use {BigInt, BigUint};
+
+    /// Types supported by the helper modules in `serde_helpers`.
+    pub trait BigNum: Sized + ::std::fmt::Display + ::std::str::FromStr {
+        fn to_bytes(&self) -> Vec<u8>;
+        fn from_bytes(bytes: &[u8]) -> Self;
+    }
+
+    impl BigNum for BigUint {
+        fn to_bytes(&self) -> Vec<u8> {
+            self.to_bytes_le()
+        }
+        fn from_bytes(bytes: &[u8]) -> Self {
+            BigUint::from_bytes_le(bytes)
+        }
+    }
+
+    impl BigNum for BigInt {
+        fn to_bytes(&self) -> Vec<u8> {
+            self.to_signed_bytes_le()
+        }
+        fn from_bytes(bytes: &[u8]) -> Self {
+            BigInt::from_signed_bytes_le(bytes)
+        }
+    }
+}
+
+use self::sealed::BigNum;
+
+/// Serialize and deserialize a `BigUint` or `BigInt` as a decimal string.
+pub mod decimal {
+    use super::*;
+
+    /// Serializes `value` as a decimal string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: BigNum,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a value from a decimal string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: BigNum,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DecimalVisitor(PhantomData))
+    }
+
+    struct DecimalVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: BigNum> Visitor<'de> for DecimalVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a decimal integer string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<T, E>
+        where
+            E: de::Error,
+        {
+            v.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// Serialize and deserialize a `BigUint` or `BigInt` as a little-endian byte string.
+///
+/// A `BigUint` is written as its magnitude bytes, as by `to_bytes_le`, and a `BigInt` in
+/// two's complement, as by `to_signed_bytes_le`.
+pub mod bytes {
+    use super::*;
+
+    /// Serializes `value` as a little-endian byte string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: BigNum,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&value.to_bytes())
+    }
+
+    /// Deserializes a value from a little-endian byte string.
+    ///
+    /// A sequence of `u8` is accepted as well, for formats without native byte strings.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: BigNum,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: BigNum> Visitor<'de> for BytesVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a little-endian byte string")
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<T, E>
+        where
+            E: de::Error,
+        {
+            Ok(T::from_bytes(v))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                v.push(byte);
+            }
+            Ok(T::from_bytes(&v))
+        }
+    }
+}
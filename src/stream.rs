@@ -0,0 +1,381 @@
+//! Reading and writing numbers with `io::Read` and `io::Write`, or reading them from an
+//! iterator of bytes, without holding the whole string in memory.
+
+use std::io::{self, Read, Write};
+
+use BigInt;
+use BigUint;
+use ParseBigIntError;
+use Sign::{Minus, Plus};
+
+use bigint::magnitude;
+use biguint::{bits_to_usize, digit_value};
+use integer::Integer;
+use traits::{Pow, Signed, Zero};
+
+/// The number of digits parsed at once before they are combined.
+const CHUNK: usize = 4096;
+
+enum State {
+    /// Leading whitespace, before any sign.
+    Start,
+    /// After a sign, where a digit must follow.
+    Sign,
+    Digits,
+    /// Trailing whitespace, after the digits.
+    End,
+}
+
+/// Parses digits as they arrive, keeping only the value of those seen so far.
+///
+/// Every `CHUNK` digits are converted on their own, and chunks of the same size are
+/// combined in pairs, like the carries of a binary counter.  Each combining step
+/// multiplies numbers of equal size, which keeps the total cost near that of one big
+/// multiplication rather than quadratic in the length.
+struct Parser {
+    radix: u32,
+    signed: bool,
+    negative: bool,
+    state: State,
+    position: usize,
+    digits: Vec<u8>,
+    /// Combined chunks, most significant first, with their level: the value of
+    /// `CHUNK << level` digits.
+    stack: Vec<(BigUint, usize)>,
+    /// `powers[level]` is `radix` to the power of `CHUNK << level`.
+    powers: Vec<BigUint>,
+}
+
+impl Parser {
+    fn new(radix: u32, signed: bool) -> Parser {
+        assert!(2 <= radix && radix <= 62, "The radix must be within 2...62");
+        Parser {
+            radix: radix,
+            signed: signed,
+            negative: false,
+            state: State::Start,
+            position: 0,
+            digits: Vec::with_capacity(CHUNK),
+            stack: Vec::new(),
+            powers: Vec::new(),
+        }
+    }
+
+    fn feed<I: IntoIterator<Item = u8>>(&mut self, bytes: I) -> Result<(), ParseBigIntError> {
+        for b in bytes {
+            try!(self.push(b));
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, b: u8) -> Result<(), ParseBigIntError> {
+        let is_space = match b {
+            b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c => true,
+            _ => false,
+        };
+        match self.state {
+            State::Start if is_space => return Ok(()),
+            State::Start if b == b'+' || (b == b'-' && self.signed) => {
+                self.negative = b == b'-';
+                self.state = State::Sign;
+                return Ok(());
+            }
+            State::Digits if is_space => {
+                self.state = State::End;
+                return Ok(());
+            }
+            State::Digits if b == b'_' => return Ok(()),
+            State::End if is_space => return Ok(()),
+            State::End => return Err(ParseBigIntError::invalid_byte(self.position, b)),
+            _ => {}
+        }
+        match digit_value(b, self.radix) {
+            Some(d) => {
+                self.state = State::Digits;
+                self.digits.push(d);
+                if self.digits.len() == CHUNK {
+                    self.flush();
+                }
+                Ok(())
+            }
+            None => Err(ParseBigIntError::invalid_byte(self.position, b)),
+        }
+    }
+
+    /// Converts a full chunk of digits and combines it with any of the same size.
+    fn flush(&mut self) {
+        let mut n = BigUint::from_radix_be(&self.digits, self.radix).unwrap();
+        self.digits.clear();
+        let mut level = 0;
+        while self.stack.last().map_or(false, |&(_, l)| l == level) {
+            if self.powers.len() == level {
+                let power = match self.powers.last() {
+                    Some(p) => p * p,
+                    None => BigUint::from(self.radix).pow(CHUNK),
+                };
+                self.powers.push(power);
+            }
+            let (hi, _) = self.stack.pop().unwrap();
+            n = hi * &self.powers[level] + n;
+            level += 1;
+        }
+        self.stack.push((n, level));
+    }
+
+    fn finish(mut self) -> Result<(BigUint, bool), ParseBigIntError> {
+        match self.state {
+            State::Start | State::Sign => return Err(ParseBigIntError::empty()),
+            State::Digits | State::End => {}
+        }
+        let mut n = BigUint::from_radix_be(&self.digits, self.radix).unwrap();
+        let mut len = self.digits.len();
+        while let Some((hi, level)) = self.stack.pop() {
+            if !hi.is_zero() {
+                n = hi * BigUint::from(self.radix).pow(len) + n;
+            }
+            len += CHUNK << level;
+        }
+        Ok((n, self.negative))
+    }
+}
+
+/// Feeds everything from `reader` to `parser`.
+fn read_all<R: Read>(mut reader: R, parser: &mut Parser) -> io::Result<()> {
+    let mut buf = [0; 8192];
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        try!(parser
+            .feed(buf[..len].iter().cloned())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)));
+    }
+}
+
+/// Writes `n` with at least `width` digits, where `n` is less than the square of the last
+/// of `powers`, or than `powers[0]` itself if there are none.
+///
+/// Splitting `n` by the largest power keeps only the quotient and remainder alive at
+/// each level, rather than every digit.
+fn write_digits<W: Write>(
+    w: &mut W,
+    n: &BigUint,
+    radix: u32,
+    powers: &[BigUint],
+    width: usize,
+) -> io::Result<()> {
+    let (power, rest) = match powers.split_last() {
+        Some(split) => split,
+        None => {
+            let s = n.to_str_radix(radix);
+            for _ in s.len()..width {
+                try!(w.write_all(b"0"));
+            }
+            return w.write_all(s.as_bytes());
+        }
+    };
+    if width == 0 && n < power {
+        return write_digits(w, n, radix, rest, 0);
+    }
+    let digits = CHUNK << rest.len();
+    let (hi, lo) = n.div_rem(power);
+    let hi_width = width.saturating_sub(digits);
+    try!(write_digits(w, &hi, radix, rest, hi_width));
+    write_digits(w, &lo, radix, rest, digits)
+}
+
+/// Writes `n` in `radix` without leading zeros.
+fn write_unsigned<W: Write>(mut w: W, n: &BigUint, radix: u32) -> io::Result<()> {
+    assert!(2 <= radix && radix <= 62, "The radix must be within 2...62");
+    // `radix ^ CHUNK` is at least `2 ^ (CHUNK * floor(log2(radix)))`, so a number with no
+    // more bits than that fits in one chunk and needs no powers at all.
+    let radix_log2 = u64::from(31 - radix.leading_zeros());
+    if n.bit_len() <= CHUNK as u64 * radix_log2 {
+        return w.write_all(n.to_str_radix(radix).as_bytes());
+    }
+    // `powers[i]` is `radix` to the power of `CHUNK << i`, up to one whose square is
+    // larger than `n`.
+    let mut powers = vec![BigUint::from(radix).pow(CHUNK)];
+    while *n >= powers[powers.len() - 1] {
+        let next = {
+            let last = &powers[powers.len() - 1];
+            last * last
+        };
+        powers.push(next);
+    }
+    powers.pop();
+    write_digits(&mut w, n, radix, &powers, 0)
+}
+
+impl BigUint {
+    /// Reads a number in the given radix from `reader`, with an optional leading `+`.
+    ///
+    /// The digits are converted as they are read, so the whole string is never held in
+    /// memory, only the value.  The syntax is that of `from_str_radix`, except that
+    /// leading and trailing ASCII whitespace is skipped, such as the newline at the end
+    /// of a file.  `radix` must be in the range `2...62`.
+    ///
+    /// Invalid input is reported as an error of kind `InvalidData`, wrapping a
+    /// `ParseBigIntError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let input: &[u8] = b"123456789012345678901234567890\n";
+    /// let n = BigUint::read_str_radix(input, 10).unwrap();
+    /// assert_eq!(n.to_string(), "123456789012345678901234567890");
+    /// ```
+    pub fn read_str_radix<R: Read>(reader: R, radix: u32) -> io::Result<BigUint> {
+        let mut parser = Parser::new(radix, false);
+        try!(read_all(reader, &mut parser));
+        match parser.finish() {
+            Ok((n, _)) => Ok(n),
+            Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Reads a number in the given radix from an iterator of bytes, with the syntax of
+    /// [`read_str_radix`](#method.read_str_radix).
+    ///
+    /// Like `read_str_radix`, this keeps only the value of the digits seen so far, for
+    /// input that is produced a byte at a time, such as from a decoder.  `radix` must be in
+    /// the range `2...62`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let digits = "1234".bytes().chain(b"5678\n".iter().cloned());
+    /// let n = BigUint::read_str_radix_iter(digits, 10).unwrap();
+    /// assert_eq!(n, BigUint::from(12345678u32));
+    /// ```
+    pub fn read_str_radix_iter<I>(bytes: I, radix: u32) -> Result<BigUint, ParseBigIntError>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut parser = Parser::new(radix, false);
+        try!(parser.feed(bytes));
+        parser.finish().map(|(n, _)| n)
+    }
+
+    /// Writes the number in the given radix to `writer`, like `to_str_radix`.
+    ///
+    /// The digits are produced and written a chunk at a time, so the whole string is
+    /// never held in memory.  `radix` must be in the range `2...62`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut out = Vec::new();
+    /// BigUint::from(0xabcdu32).write_str_radix(16, &mut out).unwrap();
+    /// assert_eq!(out, b"abcd");
+    /// ```
+    pub fn write_str_radix<W: Write>(&self, radix: u32, writer: W) -> io::Result<()> {
+        write_unsigned(writer, self, radix)
+    }
+
+    /// Reads a number of at most `bits` bits from an extendable-output function, or any
+    /// other source of uniform bytes.
+    ///
+    /// This reads `bits / 8` bytes, rounded up, as a big-endian number and clears any high
+    /// bits beyond `bits`, so the same output always derives the same number.  To hash
+    /// into `0..m` with negligible bias, read `m.bit_len() + 128` bits and reduce them
+    /// modulo `m`.
+    ///
+    /// Running out of input is an error of kind `UnexpectedEof`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let output: &[u8] = &[0xff, 0x12, 0x34, 0x56];
+    /// let n = BigUint::from_xof(&mut &output[..], 12).unwrap();
+    /// assert_eq!(n, BigUint::from(0xf12u32));
+    /// ```
+    pub fn from_xof<R: Read>(reader: &mut R, bits: u64) -> io::Result<BigUint> {
+        let mut bytes = vec![0; bits_to_usize(bits / 8 + (bits % 8 != 0) as u64)];
+        try!(reader.read_exact(&mut bytes));
+        if bits % 8 != 0 {
+            bytes[0] &= (1 << (bits % 8)) - 1;
+        }
+        Ok(BigUint::from_bytes_be(&bytes))
+    }
+}
+
+impl BigInt {
+    /// Reads a number in the given radix from `reader`, with an optional leading `+` or
+    /// `-`.
+    ///
+    /// See [`BigUint::read_str_radix`](struct.BigUint.html#method.read_str_radix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let input: &[u8] = b"-ff_ff";
+    /// assert_eq!(BigInt::read_str_radix(input, 16).unwrap(), BigInt::from(-0xffff));
+    /// ```
+    pub fn read_str_radix<R: Read>(reader: R, radix: u32) -> io::Result<BigInt> {
+        let mut parser = Parser::new(radix, true);
+        try!(read_all(reader, &mut parser));
+        match parser.finish() {
+            Ok((n, negative)) => Ok(BigInt::from_biguint(if negative { Minus } else { Plus }, n)),
+            Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Reads a number in the given radix from an iterator of bytes, with an optional
+    /// leading `+` or `-`.
+    ///
+    /// See [`BigUint::read_str_radix_iter`](struct.BigUint.html#method.read_str_radix_iter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::read_str_radix_iter("-ff_ff".bytes(), 16).unwrap();
+    /// assert_eq!(n, BigInt::from(-0xffff));
+    /// ```
+    pub fn read_str_radix_iter<I>(bytes: I, radix: u32) -> Result<BigInt, ParseBigIntError>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut parser = Parser::new(radix, true);
+        try!(parser.feed(bytes));
+        let (n, negative) = try!(parser.finish());
+        Ok(BigInt::from_biguint(if negative { Minus } else { Plus }, n))
+    }
+
+    /// Writes the number in the given radix to `writer`, like `to_str_radix`, with a
+    /// leading `-` if it is negative.
+    ///
+    /// See [`BigUint::write_str_radix`](struct.BigUint.html#method.write_str_radix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut out = Vec::new();
+    /// BigInt::from(-1234).write_str_radix(10, &mut out).unwrap();
+    /// assert_eq!(out, b"-1234");
+    /// ```
+    pub fn write_str_radix<W: Write>(&self, radix: u32, mut writer: W) -> io::Result<()> {
+        if self.is_negative() {
+            try!(writer.write_all(b"-"));
+        }
+        write_unsigned(writer, magnitude(self), radix)
+    }
+}
@@ -0,0 +1,238 @@
+//! Unsigned integers of a width chosen at run time, with arithmetic that wraps.
+
+use std::fmt;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Not, Shl, Shr, Sub};
+
+use traits::{One, Zero};
+
+use bigint::{magnitude, Sign};
+use {BigInt, BigUint};
+
+/// An unsigned integer of `bits` bits, where `bits` is chosen at construction and all
+/// arithmetic wraps modulo 2^`bits`, like a machine word of that width.
+///
+/// This is useful for emulating registers wider than the primitive types, such as the
+/// 256-bit words of the EVM.  When the width is known at compile time and a multiple of
+/// 32, [`FixedBigUint`](struct.FixedBigUint.html) avoids allocation.
+///
+/// Negation and `!` act on all `bits` bits, and the value can also be read as a two's
+/// complement `BigInt` with [`to_bigint_signed`](#method.to_bigint_signed).  Combining two
+/// values of different widths panics.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigInt, BigUint, WrappingBigUint};
+///
+/// let max = WrappingBigUint::new(BigUint::from(255u32), 8);
+/// let one = max.with_value(BigUint::from(1u32));
+/// assert_eq!(*(&max + &one).value(), BigUint::from(0u32));
+/// assert_eq!(*(!&max).value(), BigUint::from(0u32));
+/// assert_eq!(max.to_bigint_signed(), BigInt::from(-1));
+///
+/// let word = WrappingBigUint::from_bigint(&BigInt::from(-2), 256);
+/// assert_eq!(word.value().bit_len(), 256);
+/// assert_eq!(*(&word * &word).value(), BigUint::from(4u32));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct WrappingBigUint {
+    value: BigUint,
+    bits: usize,
+}
+
+impl WrappingBigUint {
+    /// Creates `value` modulo 2^`bits`.
+    pub fn new(value: BigUint, bits: usize) -> WrappingBigUint {
+        WrappingBigUint {
+            value: value.into_low_bits(bits as u64),
+            bits: bits,
+        }
+    }
+
+    /// Creates `value` modulo 2^`bits`, which is its two's complement when negative.
+    pub fn from_bigint(value: &BigInt, bits: usize) -> WrappingBigUint {
+        let n = WrappingBigUint::new(magnitude(value).clone(), bits);
+        if value.sign() == Sign::Minus {
+            -n
+        } else {
+            n
+        }
+    }
+
+    /// Creates `value` modulo 2^`bits`, with the same width as `self`.
+    #[inline]
+    pub fn with_value(&self, value: BigUint) -> WrappingBigUint {
+        WrappingBigUint::new(value, self.bits)
+    }
+
+    /// Returns the value, which is less than 2^`bits`.
+    #[inline]
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Unwraps the value, which is less than 2^`bits`.
+    #[inline]
+    pub fn into_value(self) -> BigUint {
+        self.value
+    }
+
+    /// Returns the width in bits.
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the value read as a two's complement integer of `bits` bits, which is
+    /// negative when the top bit is set.
+    pub fn to_bigint_signed(&self) -> BigInt {
+        if self.bits != 0 && self.value.bit_len() == self.bits as u64 {
+            -BigInt::from((-self).value)
+        } else {
+            BigInt::from(self.value.clone())
+        }
+    }
+
+    fn check_bits(&self, other: &WrappingBigUint) {
+        assert!(
+            self.bits == other.bits,
+            "WrappingBigUint operands have different widths"
+        );
+    }
+}
+
+macro_rules! impl_wrapping_binop {
+    (impl $imp:ident, $method:ident, |$a:ident, $b:ident| $value:expr) => {
+        impl<'a, 'b> $imp<&'b WrappingBigUint> for &'a WrappingBigUint {
+            type Output = WrappingBigUint;
+
+            fn $method(self, other: &WrappingBigUint) -> WrappingBigUint {
+                self.check_bits(other);
+                let ($a, $b) = (&self.value, &other.value);
+                WrappingBigUint::new($value, self.bits)
+            }
+        }
+
+        forward_all_binop_to_ref_ref!(impl $imp for WrappingBigUint, $method);
+    };
+}
+
+impl_wrapping_binop!(impl Add, add, |a, b| a + b);
+impl_wrapping_binop!(impl Mul, mul, |a, b| a * b);
+impl_wrapping_binop!(impl BitAnd, bitand, |a, b| a & b);
+impl_wrapping_binop!(impl BitOr, bitor, |a, b| a | b);
+impl_wrapping_binop!(impl BitXor, bitxor, |a, b| a ^ b);
+
+impl<'a, 'b> Sub<&'b WrappingBigUint> for &'a WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    fn sub(self, other: &WrappingBigUint) -> WrappingBigUint {
+        self.check_bits(other);
+        if self.value >= other.value {
+            WrappingBigUint {
+                value: &self.value - &other.value,
+                bits: self.bits,
+            }
+        } else {
+            &-other + self
+        }
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Sub for WrappingBigUint, sub);
+
+impl<'a> Neg for &'a WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    fn neg(self) -> WrappingBigUint {
+        let value = if self.value.is_zero() {
+            BigUint::zero()
+        } else {
+            (BigUint::one() << self.bits) - &self.value
+        };
+        WrappingBigUint {
+            value: value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl Neg for WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    #[inline]
+    fn neg(self) -> WrappingBigUint {
+        -&self
+    }
+}
+
+impl<'a> Not for &'a WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    fn not(self) -> WrappingBigUint {
+        let max = (BigUint::one() << self.bits) - 1u32;
+        WrappingBigUint {
+            value: max - &self.value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl Not for WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    #[inline]
+    fn not(self) -> WrappingBigUint {
+        !&self
+    }
+}
+
+impl Shl<usize> for WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    fn shl(self, rhs: usize) -> WrappingBigUint {
+        if rhs >= self.bits {
+            return WrappingBigUint {
+                value: BigUint::zero(),
+                bits: self.bits,
+            };
+        }
+        WrappingBigUint::new(self.value << rhs, self.bits)
+    }
+}
+
+impl Shr<usize> for WrappingBigUint {
+    type Output = WrappingBigUint;
+
+    #[inline]
+    fn shr(self, rhs: usize) -> WrappingBigUint {
+        WrappingBigUint {
+            value: self.value >> rhs,
+            bits: self.bits,
+        }
+    }
+}
+
+impl fmt::Debug for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (u{})", self.value, self.bits)
+    }
+}
+
+impl fmt::Display for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl fmt::LowerHex for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value, f)
+    }
+}
+
+impl fmt::UpperHex for WrappingBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.value, f)
+    }
+}
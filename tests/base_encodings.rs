@@ -0,0 +1,72 @@
+#![cfg(feature = "base-encodings")]
+
+extern crate num_bigint;
+extern crate num_traits;
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+#[test]
+fn test_base58() {
+    assert_eq!(BigUint::zero().to_base58(), "1");
+    assert_eq!(BigUint::from(57u32).to_base58(), "z");
+    assert_eq!(BigUint::from(58u32).to_base58(), "21");
+    assert_eq!(BigUint::from_base58("1"), Some(BigUint::zero()));
+    assert_eq!(BigUint::from_base58("1112"), Some(BigUint::one()));
+
+    // "Hello World!" as a big-endian number
+    let n = BigUint::from_bytes_be(b"Hello World!");
+    assert_eq!(n.to_base58(), "2NEpo7TZRRrLZSi2U");
+    assert_eq!(BigUint::from_base58("2NEpo7TZRRrLZSi2U"), Some(n));
+
+    for s in &["", "0", "O", "I", "l", "+", " 2", "-2"] {
+        assert_eq!(BigUint::from_base58(s), None);
+    }
+}
+
+#[test]
+fn test_base32() {
+    assert_eq!(BigUint::zero().to_base32(), "A");
+    assert_eq!(BigUint::from(31u32).to_base32(), "7");
+    assert_eq!(BigUint::from(32u32).to_base32(), "BA");
+    assert_eq!(BigUint::from_base32("AAB"), Some(BigUint::one()));
+
+    // 2^100 = 32^20
+    let n = BigUint::one() << 100;
+    assert_eq!(n.to_base32(), "BAAAAAAAAAAAAAAAAAAAA");
+    assert_eq!(BigUint::from_base32("BAAAAAAAAAAAAAAAAAAAA"), Some(n));
+
+    for s in &["", "a", "1", "8", "A="] {
+        assert_eq!(BigUint::from_base32(s), None);
+    }
+}
+
+#[test]
+fn test_base64() {
+    assert_eq!(BigUint::zero().to_base64(), "A");
+    assert_eq!(BigUint::from(62u32).to_base64(), "+");
+    assert_eq!(BigUint::from(64u32).to_base64(), "BA");
+    assert_eq!(BigUint::from_base64("AAB"), Some(BigUint::one()));
+
+    let n = BigUint::from_bytes_be(b"Man");
+    assert_eq!(n.to_base64(), "TWFu");
+    assert_eq!(BigUint::from_base64("TWFu"), Some(n));
+
+    for s in &["", "-", "_", "A="] {
+        assert_eq!(BigUint::from_base64(s), None);
+    }
+}
+
+#[test]
+fn test_signed() {
+    let n = BigInt::from(-3430008);
+    assert_eq!(n.to_base58(), "-Jad5");
+    assert_eq!(BigInt::from_base58("-Jad5"), Some(n));
+    assert_eq!(BigInt::from(-1024).to_base32(), "-BAA");
+    assert_eq!(BigInt::from_base64("-//"), Some(BigInt::from(-4095)));
+    assert_eq!(BigInt::from_base64("//"), Some(BigInt::from(4095)));
+    assert_eq!(BigInt::zero().to_base58(), "1");
+    assert_eq!(BigInt::from_base58("-1"), Some(BigInt::zero()));
+    assert_eq!(BigInt::from_base58("-"), None);
+    assert_eq!(BigInt::from_base58("--2"), None);
+}
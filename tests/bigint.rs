@@ -5,10 +5,10 @@ extern crate num_traits;
 extern crate rand;
 
 use num_bigint::BigUint;
-use num_bigint::Sign::{Minus, NoSign, Plus};
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::Sign::{self, Minus, NoSign, Plus};
+use num_bigint::{BigInt, BigIntErrorKind, ParseBigIntError, RawBigInt, ToBigInt};
 
-use std::cmp::Ordering::{Equal, Greater, Less};
+use std::cmp::Ordering::{self, Equal, Greater, Less};
 use std::collections::hash_map::RandomState;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::iter::repeat;
@@ -28,6 +28,15 @@ use consts::*;
 #[macro_use]
 mod macros;
 
+#[test]
+#[cfg(has_const_vec_new)]
+fn test_const_zero() {
+    static ZERO: BigInt = BigInt::ZERO;
+    assert_eq!(ZERO, BigInt::zero());
+    assert_eq!(ZERO.sign(), NoSign);
+    assert_eq!(&ZERO - 1, -BigInt::one());
+}
+
 #[test]
 fn test_from_bytes_be() {
     fn check(s: &str, result: &str) {
@@ -98,6 +107,120 @@ fn test_to_bytes_le() {
     assert_eq!(b.to_bytes_le(), (Plus, vec![0, 2, 0, 0, 0, 0, 0, 0, 1]));
 }
 
+#[test]
+fn test_bytes_ne() {
+    let b = BigInt::parse_bytes(b"-22405534230753963835153736737", 10).unwrap();
+    let (sign, ne) = b.to_bytes_ne();
+    assert_eq!(sign, Minus);
+    assert_eq!(BigInt::from_bytes_ne(sign, &ne), b);
+    assert_eq!(BigInt::from_bytes_ne(Minus, &[]), BigInt::zero());
+
+    let (sign, digits) = b.to_u32_digits_ne();
+    assert_eq!(sign, Minus);
+    assert_eq!(BigInt::from_u32_digits_ne(sign, &digits), b);
+}
+
+#[test]
+fn test_cbor_bignum() {
+    // RFC 8949 appendix A
+    let n = BigInt::one() << 64;
+    let cbor = [0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(n.to_cbor_bignum(), cbor);
+    assert_eq!(BigInt::from_cbor_bignum(&cbor), Some(n.clone()));
+    let cbor = [0xc3, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!((-&n - 1i32).to_cbor_bignum(), cbor);
+    assert_eq!(BigInt::from_cbor_bignum(&cbor), Some(-&n - 1i32));
+    assert_eq!(
+        BigInt::from_cbor_bignum(&[0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Some(-n)
+    );
+
+    for i in -300i32..300 {
+        let x = BigInt::from(i);
+        assert_eq!(BigInt::from_cbor_bignum(&x.to_cbor_bignum()), Some(x));
+    }
+    assert_eq!(BigInt::from_cbor_bignum(&[0x20]), Some(-BigInt::one()));
+    assert_eq!(
+        BigInt::from_cbor_bignum(&[0xc3, 0x40]),
+        Some(-BigInt::one())
+    );
+    assert_eq!(BigInt::from_cbor_bignum(&[0xc4, 0x40]), None);
+    assert_eq!(BigInt::from_cbor_bignum(&[0xc3, 0x41]), None);
+}
+
+#[test]
+fn test_raw() {
+    let values = [
+        BigInt::zero(),
+        BigInt::one(),
+        -BigInt::one(),
+        BigInt::from(u64::MAX) + 1,
+        -(BigInt::one() << 200),
+    ];
+    for n in &values {
+        let raw = n.clone().into_raw();
+        let sign = match n.sign() {
+            Minus => -1,
+            NoSign => 0,
+            Plus => 1,
+        };
+        assert_eq!(raw.sign, sign);
+        assert_eq!(unsafe { raw.words() }, &n.to_u64_digits().1[..]);
+        assert_eq!(unsafe { raw.to_bigint() }, *n);
+        assert_eq!(unsafe { BigInt::from_raw(raw) }, *n);
+    }
+
+    let raw = BigUint::from(5u32).into_raw();
+    assert_eq!(raw.sign, 1);
+    assert_eq!(unsafe { BigInt::from_raw(raw) }, BigInt::from(5));
+    let raw = BigUint::zero().into_raw();
+    assert_eq!((raw.sign, raw.len), (0, 0));
+    assert_eq!(unsafe { BigInt::from_raw(raw) }, BigInt::zero());
+
+    // words owned by the caller
+    let words = [0, 0, 1u64];
+    let raw = RawBigInt {
+        sign: -5,
+        len: words.len(),
+        words: words.as_ptr(),
+    };
+    assert_eq!(unsafe { raw.to_bigint() }, -(BigInt::one() << 128));
+    let raw = RawBigInt {
+        sign: 1,
+        len: 0,
+        words: std::ptr::null(),
+    };
+    assert_eq!(unsafe { raw.to_bigint() }, BigInt::zero());
+}
+
+#[test]
+fn test_der_integer() {
+    fn check(n: i64, der: &[u8]) {
+        let n = BigInt::from(n);
+        assert_eq!(n.to_der_integer(), der);
+        assert_eq!(BigInt::from_der_integer(der), Some(n));
+    }
+
+    check(0, &[0x02, 0x01, 0x00]);
+    check(127, &[0x02, 0x01, 0x7f]);
+    check(128, &[0x02, 0x02, 0x00, 0x80]);
+    check(256, &[0x02, 0x02, 0x01, 0x00]);
+    check(-1, &[0x02, 0x01, 0xff]);
+    check(-128, &[0x02, 0x01, 0x80]);
+    check(-129, &[0x02, 0x02, 0xff, 0x7f]);
+    check(-256, &[0x02, 0x02, 0xff, 0x00]);
+    check(-0x8000_0000, &[0x02, 0x04, 0x80, 0, 0, 0]);
+
+    for i in -70000i32..70000 {
+        let n = BigInt::from(i);
+        assert_eq!(BigInt::from_der_integer(&n.to_der_integer()), Some(n));
+    }
+
+    assert_eq!(BigInt::from_der_integer(&[0x02, 0x02, 0xff, 0x80]), None);
+    assert_eq!(BigInt::from_der_integer(&[0x02, 0x02, 0x00, 0x7f]), None);
+    assert_eq!(BigInt::from_der_integer(&[0x02, 0x00]), None);
+}
+
 #[test]
 fn test_to_signed_bytes_le() {
     fn check(s: &str, result: Vec<u8>) {
@@ -247,6 +370,78 @@ fn hash<T: Hash>(x: &T) -> u64 {
     hasher.finish()
 }
 
+#[test]
+fn test_cmp_biguint() {
+    let vs: [&[u32]; 5] = [&[], &[2], &[1, 1], &[2, 1], &[1, 1, 1]];
+    for (i, si) in vs.iter().enumerate() {
+        for (j, sj) in vs.iter().enumerate() {
+            let u = BigUint::from_slice(sj);
+            let pos = BigInt::from_slice(Plus, si);
+            let neg = BigInt::from_slice(Minus, si);
+
+            assert_eq!(pos.cmp_biguint(&u), i.cmp(&j));
+            assert_eq!(u.cmp_bigint(&pos), j.cmp(&i));
+
+            let expected = if i == 0 && j == 0 { Equal } else { Less };
+            assert_eq!(neg.cmp_biguint(&u), expected);
+            assert_eq!(u.cmp_bigint(&neg), expected.reverse());
+        }
+    }
+}
+
+#[test]
+fn test_cmp_primitive() {
+    let n = BigInt::from(-1_000_000);
+    assert_eq!(n.cmp_i32(-1_000_000), Equal);
+    assert_eq!(n.cmp_i64(-1_000_000), Equal);
+    assert_eq!(n.cmp_u32(1_000_000), Less);
+    assert_eq!(n.cmp_u8(0), Less);
+    assert_eq!(n.cmp_i64(-999_999), Less);
+    assert_eq!(n.cmp_isize(-1_000_001), Greater);
+    assert_eq!(n.cmp_i16(i16::MIN), Less);
+    assert_eq!(BigInt::zero().cmp_u8(0), Equal);
+    assert_eq!(BigInt::zero().cmp_i8(0), Equal);
+
+    let big = BigInt::from(1) << 100;
+    assert_eq!(big.cmp_u64(u64::MAX), Greater);
+    assert_eq!(big.cmp_i64(i64::MAX), Greater);
+    assert_eq!((-&big).cmp_i64(i64::MIN), Less);
+    assert_eq!((-&big).cmp_u64(0), Less);
+    #[cfg(has_i128)]
+    assert_eq!((-&big).cmp_i128(i128::MIN), Greater);
+    #[cfg(has_i128)]
+    assert_eq!(big.cmp_u128(1 << 100), Equal);
+}
+
+#[test]
+fn test_cmp_float() {
+    let n = -(BigInt::from(1) << 64);
+    assert_eq!(n.partial_cmp_f64(-18446744073709551616.0), Some(Equal));
+    assert_eq!(
+        (&n - 1i32).partial_cmp_f64(-18446744073709551616.0),
+        Some(Less)
+    );
+    assert_eq!(
+        (&n + 1i32).partial_cmp_f32(-18446744073709551616.0),
+        Some(Greater)
+    );
+
+    let three = BigInt::from(-3);
+    assert_eq!(three.partial_cmp_f64(-2.5), Some(Less));
+    assert_eq!(three.partial_cmp_f32(-3.5), Some(Greater));
+    assert_eq!(three.partial_cmp_f64(-3.000001), Some(Greater));
+    assert_eq!(three.partial_cmp_f64(-0.0), Some(Less));
+    assert_eq!(three.partial_cmp_f64(1.0), Some(Less));
+    assert_eq!(BigInt::zero().partial_cmp_f64(0.0), Some(Equal));
+    assert_eq!(BigInt::zero().partial_cmp_f64(0.1), Some(Less));
+    assert_eq!(BigInt::zero().partial_cmp_f64(-0.1), Some(Greater));
+
+    let huge = BigInt::from(1) << 2000;
+    assert_eq!((-&huge).partial_cmp_f64(f64::MIN), Some(Less));
+    assert_eq!((-&huge).partial_cmp_f64(-f64::INFINITY), Some(Greater));
+    assert_eq!((-&huge).partial_cmp_f64(f64::NAN), None);
+}
+
 #[test]
 fn test_hash() {
     let a = BigInt::new(NoSign, vec![]);
@@ -378,6 +573,37 @@ fn test_convert_u128() {
     );
 }
 
+#[test]
+#[cfg(has_try_from)]
+fn test_convert_try_from() {
+    use std::convert::TryFrom;
+
+    let neg = BigInt::from(-129);
+    assert_eq!(i16::try_from(&neg), Ok(-129));
+    assert!(i8::try_from(&neg).is_err());
+    assert!(u32::try_from(&neg).is_err());
+    assert_eq!(u32::try_from(neg.clone()).unwrap_err().into_original(), neg);
+
+    let pos = BigInt::from(u32::MAX);
+    assert_eq!(u32::try_from(&pos), Ok(u32::MAX));
+    assert!(i32::try_from(&pos).is_err());
+    assert_eq!(i64::try_from(pos), Ok(u32::MAX as i64));
+
+    let big = BigInt::from_biguint(Minus, BigUint::new(vec![0, 0, 1]));
+    assert!(i64::try_from(&big).is_err());
+}
+
+#[test]
+#[cfg(all(has_try_from, has_i128))]
+fn test_convert_try_from_128() {
+    use std::convert::TryFrom;
+
+    let min = BigInt::from(i128::MIN);
+    assert_eq!(i128::try_from(&min), Ok(i128::MIN));
+    assert!(u128::try_from(&min).is_err());
+    assert!(i128::try_from(min - 1).is_err());
+}
+
 #[test]
 fn test_convert_f32() {
     fn check(b1: &BigInt, f: f32) {
@@ -563,6 +789,22 @@ fn test_convert_to_biguint() {
     assert_eq!(negative.to_biguint(), None);
 }
 
+#[test]
+#[cfg(has_try_from)]
+fn test_convert_try_from_biguint() {
+    use std::convert::TryFrom;
+
+    let pos = BigInt::from(1234);
+    assert_eq!(BigUint::try_from(&pos), Ok(BigUint::from(1234u32)));
+    assert_eq!(BigUint::try_from(pos), Ok(BigUint::from(1234u32)));
+    assert_eq!(BigUint::try_from(BigInt::zero()), Ok(BigUint::zero()));
+
+    let neg = BigInt::from(-1234);
+    assert!(BigUint::try_from(&neg).is_err());
+    let err = BigUint::try_from(neg.clone()).unwrap_err();
+    assert_eq!(err.into_original(), neg);
+}
+
 #[test]
 fn test_convert_from_uint() {
     macro_rules! check {
@@ -737,6 +979,38 @@ fn test_mul() {
     }
 }
 
+#[test]
+fn test_mul_add() {
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, c_vec, d_vec) = *elm;
+        let a = BigInt::from_slice(Plus, a_vec);
+        let b = BigInt::from_slice(Plus, b_vec);
+        let c = BigInt::from_slice(Plus, c_vec);
+        let d = BigInt::from_slice(Plus, d_vec);
+        let (na, nb, nc, nd) = (-&a, -&b, -&c, -&d);
+
+        assert_mul_add!(b * c + d == a);
+        assert_mul_add!(nb * nc + d == a);
+        assert_mul_add!(nb * c + nd == na);
+        assert_mul_add!(b * nc + nd == na);
+
+        // the product and addend have opposite signs
+        let e = &b * &c - &d;
+        assert_mul_add!(b * c + nd == e);
+        assert_mul_add!(nb * c + d == -&e);
+    }
+
+    // large enough for the Karatsuba and Toom-3 multiplication paths
+    for &digits in &[40usize, 300] {
+        let x = (BigInt::one() << (32 * digits)) - 1u32;
+        let y = -(&x >> 7usize);
+        let z = &x * 3u32;
+        let nz = -&z;
+        assert_mul_add!(x * y + z == &x * &y + &z);
+        assert_mul_add!(x * y + nz == &x * &y - &z);
+    }
+}
+
 #[test]
 fn test_div_mod_floor() {
     fn check_sub(a: &BigInt, b: &BigInt, ans_d: &BigInt, ans_m: &BigInt) {
@@ -804,6 +1078,10 @@ fn test_div_rem() {
         assert!(q == *ans_q);
         assert!(r == *ans_r);
 
+        let mut q = a.clone();
+        assert_eq!(q.div_rem_assign(b), *ans_r);
+        assert_eq!(q, *ans_q);
+
         let (a, b, ans_q, ans_r) = (a.clone(), b.clone(), ans_q.clone(), ans_r.clone());
         assert_op!(a / b == ans_q);
         assert_op!(a % b == ans_r);
@@ -932,6 +1210,24 @@ fn test_checked_div() {
     }
 }
 
+#[test]
+fn test_checked_rem_neg() {
+    use num_traits::{CheckedNeg, CheckedRem};
+
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, _, d_vec) = *elm;
+        let a = BigInt::from_slice(Plus, a_vec);
+        let b = BigInt::from_slice(Plus, b_vec);
+        let d = BigInt::from_slice(Plus, d_vec);
+
+        assert_eq!(a.checked_rem(&b), Some(d.clone()));
+        assert_eq!((-&a).checked_rem(&b), Some(-&d));
+        assert_eq!(a.checked_rem(&-&b), Some(d.clone()));
+        assert_eq!(a.checked_rem(&Zero::zero()), None);
+        assert_eq!(a.checked_neg(), Some(-&a));
+    }
+}
+
 #[test]
 fn test_gcd() {
     fn check(a: isize, b: isize, c: isize) {
@@ -973,6 +1269,17 @@ fn test_lcm() {
     check(11, 5, 55);
 }
 
+#[test]
+fn test_is_multiple_of() {
+    for a in -20i64..21 {
+        for b in -7i64..8 {
+            let expected = if b == 0 { a == 0 } else { a % b == 0 };
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(x.is_multiple_of(&y), expected, "{} | {}", b, a);
+        }
+    }
+}
+
 #[test]
 fn test_abs_sub() {
     let zero: BigInt = Zero::zero();
@@ -989,6 +1296,191 @@ fn test_abs_sub() {
     assert_eq!(one.abs_sub(&-&one), two);
 }
 
+#[test]
+fn test_to_str_radix_upper() {
+    let n = BigInt::from(-0xabcdef_i32);
+    assert_eq!(n.to_str_radix_upper(16), "-ABCDEF");
+    assert_eq!((-&n).to_str_radix_upper(16), "ABCDEF");
+    assert_eq!(n.to_str_radix_upper(36), "-6PBSF");
+    assert_eq!(n.to_str_radix_upper(10), n.to_string());
+    assert_eq!(format!("{:X}", n), "-ABCDEF");
+}
+
+#[test]
+fn test_format_grouped() {
+    assert_eq!(BigInt::zero().format_grouped(',', 3), "0");
+    assert_eq!(BigInt::from(-999).format_grouped(',', 3), "-999");
+    assert_eq!(BigInt::from(-1000).format_grouped(',', 3), "-1,000");
+    assert_eq!(BigInt::from(1234567).format_grouped('.', 3), "1.234.567");
+}
+
+#[test]
+fn test_debug_limbs() {
+    assert_eq!(
+        BigInt::zero().debug_limbs(),
+        "BigInt { sign: NoSign, bits: 0, limbs: [] }"
+    );
+    assert_eq!(
+        BigInt::from(255).debug_limbs(),
+        "BigInt { sign: Plus, bits: 8, limbs: [0x000000ff] }"
+    );
+    let n = BigInt::from_slice(Minus, &[1, 0xffff_ffff]);
+    assert_eq!(
+        n.debug_limbs(),
+        "BigInt { sign: Minus, bits: 64, limbs: [0x00000001, 0xffffffff] }"
+    );
+}
+
+#[test]
+fn test_format_engineering() {
+    assert_eq!(BigInt::zero().format_engineering(2), "0.0e0");
+    assert_eq!(BigInt::from(-1).format_engineering(1), "-1e0");
+    assert_eq!(BigInt::from(-1999).format_engineering(2), "-2.0e3");
+    assert_eq!(BigInt::from(54321).format_engineering(3), "54.3e3");
+}
+
+#[test]
+fn test_display_precision() {
+    let n = BigInt::from(-987654321);
+    assert_eq!(format!("{:.2}", n), "-9.9e8");
+    assert_eq!(format!("{:.9}", n), "-987654321");
+    assert_eq!(format!("{:08.2}", n), "-009.9e8");
+    assert_eq!(format!("{:.2}", -n), "9.9e8");
+}
+
+#[test]
+fn test_from_str_prefixed() {
+    let check = |s: &str, n: Option<i32>| {
+        assert_eq!(BigInt::from_str_prefixed(s).ok(), n.map(BigInt::from));
+    };
+    check("-0", Some(0));
+    check("-42", Some(-42));
+    check("+42", Some(42));
+    check("-0x10", Some(-16));
+    check("+0o10", Some(8));
+    check("-0b11", Some(-3));
+    check("0x-10", None);
+    check("-0x+10", None);
+    check("--1", None);
+    check("-+1", None);
+    check("+-1", None);
+    check("-", None);
+    check("-0x", None);
+}
+
+#[test]
+fn test_from_str_scientific() {
+    let check = |s: &str, n: Option<i32>| {
+        assert_eq!(BigInt::from_str_scientific(s).ok(), n.map(BigInt::from));
+    };
+    check("-1e3", Some(-1000));
+    check("+1e3", Some(1000));
+    check("-2.5e1", Some(-25));
+    check("-0.0", Some(0));
+    check("-0.5", None);
+    check("--1", None);
+    check("-", None);
+
+    let err = BigInt::from_str_scientific("-1e1000000000000").unwrap_err();
+    assert_eq!(err.kind(), num_bigint::BigIntErrorKind::TooLarge);
+    assert_eq!(
+        BigInt::from_str_scientific_max_bits("-1e3", 10),
+        Ok(BigInt::from(-1000))
+    );
+    assert!(BigInt::from_str_scientific_max_bits("-1e4", 10).is_err());
+}
+
+#[test]
+fn test_from_str_radix_separated() {
+    let check = |s: &str, n: Option<i64>| {
+        assert_eq!(
+            BigInt::from_str_radix_separated(s, 10, &[' ', ',']).ok(),
+            n.map(BigInt::from)
+        );
+    };
+    check("-1,000", Some(-1000));
+    check("- 1 000", Some(-1000));
+    check("+1 000", Some(1000));
+    check("1 - 000", None);
+    check("-", None);
+}
+
+#[test]
+fn test_try_parse_bytes() {
+    let kind = |buf: &[u8], radix: u32| BigInt::try_parse_bytes(buf, radix).unwrap_err().kind();
+    assert_eq!(
+        BigInt::try_parse_bytes(b"-1_000", 10),
+        Ok(BigInt::from(-1000))
+    );
+    assert_eq!(kind(b"", 10), BigIntErrorKind::Empty);
+    assert_eq!(kind(b"-", 10), BigIntErrorKind::Empty);
+    assert_eq!(kind(b"--1", 10), BigIntErrorKind::InvalidDigit);
+    assert_eq!(kind(b"-1", 99), BigIntErrorKind::InvalidRadix);
+    assert_eq!(BigInt::parse_bytes(b"-1", 99), None);
+}
+
+#[test]
+fn test_parse_error_position() {
+    let at = |r: Result<BigInt, ParseBigIntError>| {
+        let e = r.unwrap_err();
+        (e.position(), e.invalid_char())
+    };
+    assert_eq!(at("-12x4".parse()), (Some(3), Some('x')));
+    assert_eq!(at("-+1".parse()), (Some(0), Some('-')));
+    assert_eq!(at("--1".parse()), (Some(1), Some('-')));
+    assert_eq!(at(BigInt::from_str_prefixed("-0x1g")), (Some(4), Some('g')));
+    assert_eq!(
+        at(BigInt::from_str_scientific("-1e-x")),
+        (Some(4), Some('x'))
+    );
+    assert_eq!(
+        at(BigInt::from_str_radix_separated("- 1 x", 10, &[' '])),
+        (Some(4), Some('x'))
+    );
+}
+
+#[test]
+fn test_read_str_radix() {
+    let read = |s: &str| BigInt::read_str_radix(s.as_bytes(), 10);
+    assert_eq!(read("-12").unwrap(), BigInt::from(-12));
+    assert_eq!(read("  +12\n").unwrap(), BigInt::from(12));
+    assert_eq!(read("-0").unwrap(), BigInt::zero());
+    assert!(read("-").is_err());
+    assert!(read("--1").is_err());
+    assert!(read("- 1").is_err());
+
+    let n = -BigInt::from(3).pow(30_000u32);
+    assert_eq!(read(&n.to_string()).unwrap(), n);
+}
+
+#[test]
+fn test_read_str_radix_iter() {
+    let read = |s: &str| BigInt::read_str_radix_iter(s.bytes(), 10);
+    assert_eq!(read("-12"), Ok(BigInt::from(-12)));
+    assert_eq!(read("  +12\n"), Ok(BigInt::from(12)));
+    assert_eq!(read("-0"), Ok(BigInt::zero()));
+    assert!(read("-").is_err());
+    assert!(read("--1").is_err());
+    assert!(read("- 1").is_err());
+
+    let n = -BigInt::from(3).pow(30_000u32);
+    assert_eq!(read(&n.to_string()), Ok(n));
+}
+
+#[test]
+fn test_write_str_radix() {
+    let write = |n: &BigInt| {
+        let mut out = Vec::new();
+        n.write_str_radix(10, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    assert_eq!(write(&BigInt::zero()), "0");
+    assert_eq!(write(&BigInt::from(-12)), "-12");
+    let n = -BigInt::from(3).pow(30_000u32);
+    assert_eq!(write(&n), n.to_string());
+    assert_eq!(BigInt::read_str_radix(write(&n).as_bytes(), 10).unwrap(), n);
+}
+
 #[test]
 fn test_from_str_radix() {
     fn check(s: &str, ans: Option<isize>) {
@@ -1080,6 +1572,289 @@ fn test_neg() {
     assert_eq!(-&zero, zero);
 }
 
+#[test]
+fn test_neg_biguint() {
+    let n = BigUint::new(vec![1, 1, 1]);
+    assert_eq!(-&n, BigInt::new(Minus, vec![1, 1, 1]));
+    assert_eq!(-n, BigInt::new(Minus, vec![1, 1, 1]));
+    assert_eq!(-BigUint::zero(), BigInt::zero());
+    assert_eq!((-BigUint::zero()).sign(), NoSign);
+}
+
+#[test]
+fn test_shift_primitive_amounts() {
+    let n = BigInt::from_slice(Minus, &[1, 2, 3]);
+    macro_rules! check {
+        ($($t:ty: $shl:ident, $shr:ident;)*) => {$(
+            assert_eq!(n.$shl(37 as $t), &n << 37);
+            assert_eq!(n.$shr(37 as $t), &n >> 37);
+            assert_eq!(n.$shl(3 as $t).$shr(3 as $t), n);
+        )*};
+    }
+    check! {
+        u8: shl_u8, shr_u8;
+        u16: shl_u16, shr_u16;
+        u32: shl_u32, shr_u32;
+        u64: shl_u64, shr_u64;
+        i8: shl_i8, shr_i8;
+        i16: shl_i16, shr_i16;
+        i32: shl_i32, shr_i32;
+        i64: shl_i64, shr_i64;
+        isize: shl_isize, shr_isize;
+    }
+    #[cfg(has_i128)]
+    check! {
+        u128: shl_u128, shr_u128;
+        i128: shl_i128, shr_i128;
+    }
+
+    assert_eq!(n.shr_u64(u64::MAX), BigInt::from(-1));
+    assert_eq!((-&n).shr_u64(u64::MAX), BigInt::zero());
+}
+
+#[test]
+fn test_shift_biguint_amount() {
+    let n = BigInt::from_slice(Minus, &[1, 2, 3]);
+    let by = BigUint::from(37u32);
+    assert_eq!(&n << &by, &n << 37usize);
+    assert_eq!(&n >> &by, &n >> 37usize);
+    assert_eq!(n.clone() << by.clone(), &n << 37usize);
+    assert_eq!(n.clone() >> by.clone(), &n >> 37usize);
+
+    let mut x = n.clone();
+    x <<= &by;
+    x >>= by;
+    assert_eq!(x, n);
+
+    let huge = BigUint::one() << 100usize;
+    assert_eq!(&n >> &huge, BigInt::from(-1));
+    assert_eq!(-&n >> &huge, BigInt::zero());
+}
+
+#[test]
+fn test_checked_shl() {
+    let n = BigInt::from_slice(Minus, &[1, 2, 3]);
+    assert_eq!(n.checked_shl(37), Some(&n << 37usize));
+    assert_eq!(n.checked_shl(usize::MAX), None);
+    assert_eq!(n.checked_shl_max(10, n.bit_len() + 10), Some(&n << 10usize));
+    assert_eq!(n.checked_shl_max(11, n.bit_len() + 10), None);
+    assert_eq!(BigInt::zero().checked_shl(usize::MAX), Some(BigInt::zero()));
+}
+
+#[test]
+fn test_shr_floor_trunc() {
+    for &(n, shift, floor, trunc) in &[
+        (-1i64, 1usize, -1i64, 0i64),
+        (-3, 1, -2, -1),
+        (-4, 2, -1, -1),
+        (-5, 2, -2, -1),
+        (-5, 100, -1, 0),
+        (5, 2, 1, 1),
+        (0, 3, 0, 0),
+    ] {
+        let n = BigInt::from(n);
+        assert_eq!(n.shr_floor(shift), BigInt::from(floor));
+        assert_eq!(n.shr_floor(shift), &n >> shift);
+        assert_eq!(n.shr_trunc(shift), BigInt::from(trunc));
+    }
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    use num_traits::{CheckedEuclid, Euclid};
+
+    for a in -20i64..21 {
+        for b in (-7i64..8).filter(|&b| b != 0) {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            let r = (a % b + b.abs()) % b.abs();
+            let (q, r) = (BigInt::from((a - r) / b), BigInt::from(r));
+            assert_eq!(x.div_rem_euclid(&y), (q.clone(), r.clone()));
+            assert_eq!(x.div_euclid(&y), q);
+            assert_eq!(x.rem_euclid(&y), r);
+            assert_eq!(Euclid::div_euclid(&x, &y), q);
+            assert_eq!(Euclid::rem_euclid(&x, &y), r);
+            assert_eq!(x.checked_div_euclid(&y), Some(q));
+            assert_eq!(x.checked_rem_euclid(&y), Some(r));
+        }
+        let x = BigInt::from(a);
+        assert_eq!(x.checked_div_euclid(&BigInt::zero()), None);
+        assert_eq!(x.checked_rem_euclid(&BigInt::zero()), None);
+    }
+
+    let x = -(BigInt::from(1) << 100usize) - 1u32;
+    let y = BigInt::from(1) << 50usize;
+    let (q, r) = x.div_rem_euclid(&y);
+    assert_eq!(q, -(BigInt::from(1) << 50usize) - 1u32);
+    assert_eq!(r, &y - 1u32);
+}
+
+#[test]
+fn test_div_ceil_next_multiple_of() {
+    for a in -20i64..21 {
+        for b in (-7i64..8).filter(|&b| b != 0) {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            let m = a.mod_floor(&b);
+            let next = if m == 0 { a } else { a + b - m };
+            assert_eq!(x.div_ceil(&y), BigInt::from(-(-a).div_floor(&b)));
+            assert_eq!(x.next_multiple_of(&y), BigInt::from(next));
+        }
+    }
+}
+
+#[test]
+fn test_div_round() {
+    use num_bigint::RoundingMode::*;
+
+    // Quotients of -2.5, -2.4, -0.5, 0.4, 0.5, 0.6, 1.5, 2.5 and 3, in tenths.
+    let cases = [-25i64, -24, -5, 4, 5, 6, 15, 25, 30];
+    let expected = [
+        (Floor, [-3, -3, -1, 0, 0, 0, 1, 2, 3]),
+        (Ceil, [-2, -2, 0, 1, 1, 1, 2, 3, 3]),
+        (Down, [-2, -2, 0, 0, 0, 0, 1, 2, 3]),
+        (Up, [-3, -3, -1, 1, 1, 1, 2, 3, 3]),
+        (HalfUp, [-3, -2, -1, 0, 1, 1, 2, 3, 3]),
+        (HalfDown, [-2, -2, 0, 0, 0, 1, 1, 2, 3]),
+        (HalfEven, [-2, -2, 0, 0, 0, 1, 2, 2, 3]),
+    ];
+    for &(mode, ref quotients) in &expected {
+        for (&n, &q) in cases.iter().zip(quotients) {
+            let (n, q) = (BigInt::from(n), BigInt::from(q));
+            let (ten, neg_ten) = (BigInt::from(10), BigInt::from(-10));
+            assert_eq!(n.div_round(&ten, mode), q);
+            assert_eq!((-&n).div_round(&neg_ten, mode), q);
+            if n.sign() != Minus {
+                let (n, q, ten) = (
+                    n.to_biguint().unwrap(),
+                    q.to_biguint().unwrap(),
+                    ten.to_biguint().unwrap(),
+                );
+                assert_eq!(n.div_round(&ten, mode), q);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_to_float_round() {
+    use num_bigint::RoundingMode::{self, *};
+
+    fn mirror(mode: RoundingMode) -> RoundingMode {
+        match mode {
+            Floor => Ceil,
+            Ceil => Floor,
+            mode => mode,
+        }
+    }
+
+    // 2^53 + 1 and 2^53 + 3 are ties between the `f64` values 2^53, 2^53 + 2 and 2^53 + 4,
+    // and likewise from 2^24 for `f32`.
+    let two53: BigInt = BigInt::one() << 53usize;
+    let two24: BigInt = BigInt::one() << 24usize;
+    let expected = [
+        (Floor, [0u32, 2]),
+        (Ceil, [2, 4]),
+        (Down, [0, 2]),
+        (Up, [2, 4]),
+        (HalfUp, [2, 4]),
+        (HalfDown, [0, 2]),
+        (HalfEven, [0, 4]),
+    ];
+    for &(mode, ref offsets) in &expected {
+        assert_eq!(BigInt::zero().to_f64_round(mode), 0.0);
+        assert_eq!(BigInt::zero().to_f32_round(mode), 0.0);
+        for (&k, &off) in [1u32, 3].iter().zip(offsets) {
+            let n = &two53 + k;
+            let x = (&two53 + off).to_f64().unwrap();
+            assert_eq!(n.to_f64_round(mode), x);
+            assert_eq!((-&n).to_f64_round(mirror(mode)), -x);
+            assert_eq!(n.to_biguint().unwrap().to_f64_round(mode), x);
+            let n = &two24 + k;
+            let x = (&two24 + off).to_f32().unwrap();
+            assert_eq!(n.to_f32_round(mode), x);
+            assert_eq!((-&n).to_f32_round(mirror(mode)), -x);
+        }
+    }
+
+    // The largest `f64` plus half of its ulp overflows when rounding to nearest, and
+    // otherwise rounds like any tie.
+    let max = BigInt::from_f64(f64::MAX).unwrap();
+    let tie = &max + (BigInt::one() << 970usize);
+    let huge: BigInt = BigInt::one() << 1024usize;
+    let inf = f64::INFINITY;
+    for &(mode, at_tie, at_huge) in &[
+        (Floor, f64::MAX, f64::MAX),
+        (Ceil, inf, inf),
+        (Down, f64::MAX, f64::MAX),
+        (Up, inf, inf),
+        (HalfUp, inf, inf),
+        (HalfDown, f64::MAX, inf),
+        (HalfEven, inf, inf),
+    ] {
+        assert_eq!(max.to_f64_round(mode), f64::MAX);
+        assert_eq!(tie.to_f64_round(mode), at_tie);
+        assert_eq!(huge.to_f64_round(mode), at_huge);
+        assert_eq!((-&tie).to_f64_round(mirror(mode)), -at_tie);
+        assert_eq!((-&huge).to_f64_round(mirror(mode)), -at_huge);
+    }
+    let huge: BigInt = BigInt::one() << 128usize;
+    assert_eq!(huge.to_f32_round(Down), f32::MAX);
+    assert_eq!(huge.to_f32_round(HalfEven), f32::INFINITY);
+    assert_eq!((-huge).to_f32_round(Floor), f32::NEG_INFINITY);
+
+    // Floor and Ceil bound the value, and HalfEven matches `to_f64`.
+    let mut n = BigInt::from(-7);
+    for _ in 0..250 {
+        let lo = BigInt::from_f64(n.to_f64_round(Floor)).unwrap();
+        let hi = BigInt::from_f64(n.to_f64_round(Ceil)).unwrap();
+        assert!(lo <= n && n <= hi);
+        assert_eq!(lo == hi, lo == n);
+        assert_eq!(Some(n.to_f64_round(HalfEven)), n.to_f64());
+        n *= -13;
+    }
+}
+
+#[test]
+fn test_into_from_parts() {
+    for &(sign, n) in &[
+        (Plus, 5u32),
+        (Minus, 5),
+        (NoSign, 0),
+        (NoSign, 5),
+        (Minus, 0),
+    ] {
+        let x = BigInt::from_parts(sign, BigUint::from(n));
+        assert_eq!(x, BigInt::from_biguint(sign, BigUint::from(n)));
+        let (s, m) = x.clone().into_parts();
+        assert_eq!(s, x.sign());
+        assert_eq!(BigInt::from_parts(s, m), x);
+    }
+}
+
+#[test]
+fn test_magnitude() {
+    for &(sign, n) in &[(Plus, 5u32), (Minus, 5), (NoSign, 0)] {
+        let x = BigInt::from_biguint(sign, BigUint::from(n));
+        assert_eq!(x.magnitude(), &BigUint::from(n));
+        assert_eq!(x.magnitude().bit_len(), x.bit_len());
+        assert_eq!(*x.magnitude(), x.clone().into_parts().1);
+    }
+}
+
+#[test]
+fn test_digits_len() {
+    assert_eq!(BigInt::zero().digits_len(), 0);
+    let x: BigInt = BigInt::from(1) << 64usize;
+    assert_eq!(x.digits_len(), 3);
+    assert_eq!((-&x).digits_len(), 3);
+    assert_eq!((&x - 1u32).digits_len(), 2);
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.is_normalized());
+        assert!((&x - &x).is_normalized());
+        assert!(BigInt::from_biguint(Minus, BigUint::zero()).is_normalized());
+    }
+}
+
 #[test]
 fn test_negative_shr() {
     assert_eq!(BigInt::from(-1) >> 1, BigInt::from(-1));
@@ -1165,6 +1940,36 @@ fn test_iter_product_generic() {
     assert_eq!(result, data.into_iter().product());
 }
 
+#[test]
+fn test_product_tree() {
+    assert_eq!(BigInt::product_tree(Vec::<i32>::new()), BigInt::one());
+    for n in 0..20i32 {
+        let data: Vec<i32> = (-n..n).filter(|&i| i != 0).collect();
+        let expected: BigInt = data.iter().map(|&i| BigInt::from(i)).product();
+        assert_eq!(BigInt::product_tree(data), expected);
+    }
+    assert_eq!(BigInt::product_tree(vec![-2, -3, -5]), BigInt::from(-30));
+}
+
+#[test]
+fn test_range() {
+    use num_bigint::{range, range_inclusive, range_step_inclusive};
+
+    let big = |n: i32| BigInt::from(n);
+    let v: Vec<_> = range(big(-2), big(1)).collect();
+    assert_eq!(v, [big(-2), big(-1), big(0)]);
+    let v: Vec<_> = range_inclusive(big(-1), big(-1)).collect();
+    assert_eq!(v, [big(-1)]);
+    let v: Vec<_> = range_step_inclusive(big(-10), big(10), big(10)).collect();
+    assert_eq!(v, [big(-10), big(0), big(10)]);
+}
+
+#[test]
+#[should_panic]
+fn test_range_step_negative() {
+    num_bigint::range_step(BigInt::zero(), BigInt::one(), -BigInt::one());
+}
+
 #[test]
 fn test_pow() {
     let one = BigInt::from(1i32);
@@ -1191,3 +1996,20 @@ fn test_pow() {
     check!(u64);
     check!(usize);
 }
+
+#[test]
+fn test_sign_api() {
+    let signs = [Minus, NoSign, Plus];
+    let ords = [Less, Equal, Greater];
+    for (&sign, &ord) in signs.iter().zip(ords.iter()) {
+        assert_eq!(Sign::from(ord), sign);
+        assert_eq!(Ordering::from(sign), ord);
+        assert_eq!(Sign::from_i8(sign.to_i8()), sign);
+        assert_eq!((-sign).to_i8(), -sign.to_i8());
+        for &other in signs.iter() {
+            assert_eq!((sign * other).to_i8(), sign.to_i8() * other.to_i8());
+        }
+    }
+    assert_eq!(Sign::from_i8(i8::MIN), Minus);
+    assert_eq!(Sign::from_i8(i8::MAX), Plus);
+}
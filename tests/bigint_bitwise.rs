@@ -176,6 +176,94 @@ fn test_bitwise_i64() {
             assert_eq!(a.clone() & &b, and, "{:x} & {:x}", a, b);
             assert_eq!(a.clone() | &b, or, "{:x} | {:x}", a, b);
             assert_eq!(a.clone() ^ &b, xor, "{:x} ^ {:x}", a, b);
+            assert_eq!(&a & prim_b, and, "{:x} & {:x}", a, prim_b);
+            assert_eq!(&a | prim_b, or, "{:x} | {:x}", a, prim_b);
+            assert_eq!(&a ^ prim_b, xor, "{:x} ^ {:x}", a, prim_b);
+        }
+    }
+}
+
+// corner cases around the digit boundaries of multi-digit values
+#[cfg(has_i128)]
+const I128_VALUES: &'static [i128] = &[
+    i128::MIN,
+    i128::MIN + 1,
+    -(1 << 96) - 1,
+    -(1 << 96),
+    -(1 << 96) + 1,
+    -(1 << 64) - 1,
+    -(1 << 64),
+    -(1 << 64) + 1,
+    -(1 << 32) - 1,
+    -(1 << 32),
+    -(1 << 32) + 1,
+    -1,
+    0,
+    1,
+    (1 << 32) - 1,
+    1 << 32,
+    (1 << 64) - 1,
+    1 << 64,
+    (1 << 96) - 1,
+    1 << 96,
+    i128::MAX - 1,
+    i128::MAX,
+];
+
+#[test]
+#[cfg(has_i128)]
+fn test_not_i128() {
+    for &prim_a in I128_VALUES.iter() {
+        let a = BigInt::from(prim_a);
+        let not = BigInt::from(!prim_a);
+        assert_eq!(!&a, not, "!{:x}", a);
+        assert_eq!(!a.clone(), not, "!{:x}", a);
+        assert_eq!(!!a.clone(), a, "!!{:x}", a);
+    }
+}
+
+#[test]
+#[cfg(has_i128)]
+fn test_bitwise_i128() {
+    for &prim_a in I128_VALUES.iter() {
+        let a = BigInt::from(prim_a);
+        for &prim_b in I128_VALUES.iter() {
+            let b = BigInt::from(prim_b);
+            let and = BigInt::from(prim_a & prim_b);
+            let or = BigInt::from(prim_a | prim_b);
+            let xor = BigInt::from(prim_a ^ prim_b);
+            assert_eq!(&a & &b, and, "{:x} & {:x}", a, b);
+            assert_eq!(&a | &b, or, "{:x} | {:x}", a, b);
+            assert_eq!(&a ^ &b, xor, "{:x} ^ {:x}", a, b);
+            assert_eq!(a.clone() & b.clone(), and, "{:x} & {:x}", a, b);
+            assert_eq!(a.clone() | b.clone(), or, "{:x} | {:x}", a, b);
+            assert_eq!(a.clone() ^ b.clone(), xor, "{:x} ^ {:x}", a, b);
+            assert_eq!(&a & prim_b, and, "{:x} & {:x}", a, prim_b);
+            assert_eq!(&a | prim_b, or, "{:x} | {:x}", a, prim_b);
+            assert_eq!(&a ^ prim_b, xor, "{:x} ^ {:x}", a, prim_b);
+
+            let mut x = a.clone();
+            x &= &b;
+            assert_eq!(x, and, "{:x} &= {:x}", a, b);
+            let mut x = a.clone();
+            x |= &b;
+            assert_eq!(x, or, "{:x} |= {:x}", a, b);
+            let mut x = a.clone();
+            x ^= &b;
+            assert_eq!(x, xor, "{:x} ^= {:x}", a, b);
+            let mut x = a.clone();
+            x &= prim_b;
+            assert_eq!(x, and, "{:x} &= {:x}", a, prim_b);
+            let mut x = a.clone();
+            x |= prim_b;
+            assert_eq!(x, or, "{:x} |= {:x}", a, prim_b);
+            let mut x = a.clone();
+            x ^= prim_b;
+            assert_eq!(x, xor, "{:x} ^= {:x}", a, prim_b);
+
+            // De Morgan's laws hold for the infinite two's complement form
+            assert_eq!(!(&a & &b), !&a | !&b);
+            assert_eq!(!(&a | &b), !&a & !&b);
         }
     }
 }
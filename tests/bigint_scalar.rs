@@ -143,3 +143,68 @@ fn test_scalar_div_rem() {
         }
     }
 }
+
+#[test]
+fn test_scalar_bitwise() {
+    fn check(x: &BigInt, y: &BigInt) {
+        let (x, y) = (x.clone(), y.clone());
+        assert_signed_scalar_op!(x & y == &x & &y);
+        assert_signed_scalar_op!(x | y == &x | &y);
+        assert_signed_scalar_op!(x ^ y == &x ^ &y);
+    }
+
+    for elm in SUM_TRIPLES.iter() {
+        let (a_vec, b_vec, c_vec) = *elm;
+        let a = BigInt::from_slice(Plus, a_vec);
+        let b = BigInt::from_slice(Plus, b_vec);
+        let c = BigInt::from_slice(Plus, c_vec);
+
+        for &(x, y) in &[(&a, &b), (&b, &c), (&c, &a)] {
+            check(x, y);
+            check(x, &-y);
+            check(&-x, y);
+            check(&-x, &-y);
+        }
+    }
+}
+
+#[test]
+fn test_scalar_bitwise_assign() {
+    let big: BigInt = BigInt::from(1) << 100;
+    let neg = -&big;
+
+    let mut x = neg.clone();
+    x &= 0xffff_ffffu32;
+    assert!(x.is_zero());
+    let mut x = neg.clone() - 1;
+    x &= u64::max_value();
+    assert_eq!(x, BigInt::from(u64::max_value()));
+
+    let mut x = big.clone();
+    x |= -1i32;
+    assert_eq!(x, BigInt::from(-1));
+    let mut x = neg.clone();
+    x |= 0xffi64;
+    assert_eq!(x, &neg + 0xff);
+
+    let mut x = big.clone();
+    x ^= -1i64;
+    assert_eq!(x, &neg - 1);
+    x ^= -1isize;
+    assert_eq!(x, big);
+}
+
+#[test]
+fn test_scalar_assign_isize() {
+    let mut x = BigInt::from(100);
+    x += -30isize;
+    assert_eq!(x, BigInt::from(70));
+    x -= -5isize;
+    assert_eq!(x, BigInt::from(75));
+    x *= -2isize;
+    assert_eq!(x, BigInt::from(-150));
+    x /= -4isize;
+    assert_eq!(x, BigInt::from(37));
+    x %= -10isize;
+    assert_eq!(x, BigInt::from(7));
+}
@@ -3,8 +3,8 @@ extern crate num_integer;
 extern crate num_traits;
 
 use num_bigint::Sign::Plus;
-use num_bigint::{BigInt, ToBigInt};
-use num_bigint::{BigUint, ToBigUint};
+use num_bigint::{BigInt, BigIntErrorKind, ParseBigIntError, ToBigInt};
+use num_bigint::{BigUint, Endian, ToBigUint};
 use num_integer::Integer;
 
 use std::cmp::Ordering::{Equal, Greater, Less};
@@ -19,8 +19,8 @@ use std::{i128, u128};
 use std::{u16, u32, u64, u8, usize};
 
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, FromPrimitive, Num, One, Pow,
-    ToPrimitive, Zero,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Float, FromPrimitive,
+    Num, One, Pow, ToPrimitive, Zero,
 };
 
 mod consts;
@@ -29,6 +29,18 @@ use consts::*;
 #[macro_use]
 mod macros;
 
+#[test]
+#[cfg(has_const_vec_new)]
+fn test_const_zero() {
+    static ZERO: BigUint = BigUint::ZERO;
+    const ALSO_ZERO: BigUint = BigUint::ZERO;
+    assert_eq!(ZERO, BigUint::zero());
+    assert_eq!(ALSO_ZERO, BigUint::zero());
+    assert!(ZERO.is_zero());
+    assert_eq!(ZERO.bit_len(), 0);
+    assert_eq!(&ZERO + 1u32, BigUint::one());
+}
+
 #[test]
 fn test_from_bytes_be() {
     fn check(s: &str, result: &str) {
@@ -95,6 +107,249 @@ fn test_to_bytes_le() {
     assert_eq!(b.to_bytes_le(), [0, 2, 0, 0, 0, 0, 0, 0, 1]);
 }
 
+#[test]
+fn test_bytes_ne() {
+    let b = BigUint::from_bytes_be(b"Hello world!");
+    let ne = b.to_bytes_ne();
+    if cfg!(target_endian = "little") {
+        assert_eq!(ne, b.to_bytes_le());
+    } else {
+        assert_eq!(ne, b.to_bytes_be());
+    }
+    assert_eq!(BigUint::from_bytes_ne(&ne), b);
+    assert_eq!(BigUint::from_bytes_ne(&[]), BigUint::zero());
+
+    let x = BigUint::from(0x0123_4567_89ab_cdefu64);
+    let (bytes, digits) = if cfg!(target_endian = "little") {
+        (
+            [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01],
+            [0x89ab_cdef, 0x0123_4567],
+        )
+    } else {
+        (
+            [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+            [0x0123_4567, 0x89ab_cdef],
+        )
+    };
+    assert_eq!(BigUint::from_bytes_ne(&bytes), x);
+    assert_eq!(x.to_bytes_ne(), bytes);
+    assert_eq!(BigUint::from_u32_digits_ne(&digits), x);
+    assert_eq!(x.to_u32_digits_ne(), digits);
+    assert_eq!(BigUint::from_u32_digits_ne(&[]), BigUint::zero());
+}
+
+#[test]
+fn test_import_export() {
+    let orders = [Endian::Big, Endian::Little, Endian::Native];
+    let values = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(u64::MAX),
+        BigUint::from_bytes_be(b"Hello world!"),
+        BigUint::from_slice(&[1, 2, N1, 3, N2]),
+    ];
+    for n in values.iter() {
+        for &size in [1, 2, 3, 4, 8, 13].iter() {
+            for &nails in [0, 1, 7, 8].iter() {
+                if nails >= size * 8 {
+                    continue;
+                }
+                for &order in orders.iter() {
+                    for &endian in orders.iter() {
+                        let data = n.export(size, order, endian, nails);
+                        assert_eq!(data.len() % size, 0);
+                        assert_eq!(BigUint::import(&data, size, order, endian, nails), *n);
+                    }
+                }
+            }
+        }
+    }
+
+    // Byte layouts match the plain byte conversions.
+    let n = BigUint::from_bytes_be(b"Hello world!");
+    assert_eq!(n.export(1, Endian::Big, Endian::Big, 0), n.to_bytes_be());
+    assert_eq!(n.export(1, Endian::Little, Endian::Big, 0), n.to_bytes_le());
+    assert_eq!(n.export(12, Endian::Big, Endian::Big, 0), n.to_bytes_be());
+    assert_eq!(
+        n.export(12, Endian::Little, Endian::Little, 0),
+        n.to_bytes_le()
+    );
+
+    // Nail bits are ignored on import and zeroed on export.
+    let n = BigUint::import(&[0xff, 0xff], 1, Endian::Big, Endian::Big, 4);
+    assert_eq!(n, BigUint::from(0xffu32));
+    assert_eq!(n.export(1, Endian::Big, Endian::Big, 4), vec![0x0f, 0x0f]);
+}
+
+#[test]
+fn test_u32_u64_digits() {
+    fn check(n: BigUint, u32_digits: &[u32], u64_digits: &[u64]) {
+        assert_eq!(n.to_u32_digits(), u32_digits);
+        assert_eq!(n.to_u64_digits(), u64_digits);
+        assert_eq!(BigUint::new(n.digits().to_vec()), n);
+
+        let it = n.iter_u32_digits();
+        assert_eq!(it.len(), u32_digits.len());
+        assert_eq!(it.collect::<Vec<u32>>(), u32_digits);
+        let rev: Vec<u32> = n.iter_u32_digits().rev().collect();
+        assert!(rev.iter().eq(u32_digits.iter().rev()));
+
+        let it = n.iter_u64_digits();
+        assert_eq!(it.len(), u64_digits.len());
+        assert_eq!(it.collect::<Vec<u64>>(), u64_digits);
+        let rev: Vec<u64> = n.iter_u64_digits().rev().collect();
+        assert!(rev.iter().eq(u64_digits.iter().rev()));
+    }
+
+    check(BigUint::zero(), &[], &[]);
+    check(BigUint::one(), &[1], &[1]);
+    check(BigUint::from(u32::MAX), &[N1], &[u32::MAX as u64]);
+    check(BigUint::from(u64::MAX), &[N1, N1], &[u64::MAX]);
+    check(
+        BigUint::from_slice(&[1, 2, 3]),
+        &[1, 2, 3],
+        &[(2 << 32) | 1, 3],
+    );
+}
+
+#[test]
+fn test_from_byte_array() {
+    let mut bytes = [0u8; 16];
+    bytes[15] = 1;
+    bytes[0] = 0x80;
+    assert_eq!(BigUint::from(bytes), (BigUint::one() << 127) + 1u32);
+    assert_eq!(BigUint::from([0xffu8; 32]), (BigUint::one() << 256) - 1u32);
+    assert_eq!(BigUint::from([0u8; 48]), BigUint::zero());
+    let mut bytes = [0u8; 64];
+    bytes[0] = 1;
+    assert_eq!(BigUint::from(bytes), BigUint::one() << 504);
+
+    assert_eq!(BigUint::from_digest(&bytes[..]), BigUint::one() << 504);
+    assert_eq!(BigUint::from_digest([0u8; 0]), BigUint::zero());
+    assert_eq!(
+        BigUint::from_digest(b"Hello world!"),
+        BigUint::from_bytes_be(b"Hello world!")
+    );
+
+    let m = BigUint::from(1_000_003u32);
+    assert_eq!(
+        BigUint::from_digest_mod(&bytes[..], &m),
+        (BigUint::one() << 504usize) % &m
+    );
+    assert_eq!(BigUint::from_digest_mod([0u8; 0], &m), BigUint::zero());
+}
+
+#[test]
+fn test_to_bytes_padded() {
+    let b = BigUint::from_bytes_be(b"Hello world!");
+    for len in 12..20 {
+        let be = b.to_bytes_be_padded(len).unwrap();
+        assert_eq!(be.len(), len);
+        assert_eq!(&be[len - 12..], b"Hello world!");
+        assert!(be[..len - 12].iter().all(|&x| x == 0));
+        assert_eq!(BigUint::from_bytes_be(&be), b);
+
+        let le = b.to_bytes_le_padded(len).unwrap();
+        assert_eq!(le.len(), len);
+        assert_eq!(BigUint::from_bytes_le(&le), b);
+    }
+    assert_eq!(b.to_bytes_be_padded(11), None);
+    assert_eq!(b.to_bytes_le_padded(11), None);
+
+    let zero = BigUint::zero();
+    assert_eq!(zero.to_bytes_be_padded(0), Some(vec![]));
+    assert_eq!(zero.to_bytes_le_padded(3), Some(vec![0, 0, 0]));
+}
+
+#[test]
+fn test_cbor_bignum() {
+    // RFC 8949 appendix A
+    let n = BigUint::one() << 64;
+    let cbor = [0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(n.to_cbor_bignum(), cbor);
+    assert_eq!(BigUint::from_cbor_bignum(&cbor), Some(n.clone()));
+    assert_eq!(
+        BigUint::from_cbor_bignum(&[0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Some(n - 1u32)
+    );
+
+    assert_eq!(BigUint::zero().to_cbor_bignum(), [0xc2, 0x40]);
+    assert_eq!(
+        BigUint::from_cbor_bignum(&[0xc2, 0x40]),
+        Some(BigUint::zero())
+    );
+    assert_eq!(BigUint::from_cbor_bignum(&[0x00]), Some(BigUint::zero()));
+    // leading zeros are accepted
+    assert_eq!(
+        BigUint::from_cbor_bignum(&[0xc2, 0x42, 0, 7]),
+        Some(BigUint::from(7u8))
+    );
+
+    let long = BigUint::from_bytes_be(&[0xab; 300]);
+    let cbor = long.to_cbor_bignum();
+    assert_eq!(&cbor[..4], &[0xc2, 0x59, 0x01, 0x2c]);
+    assert_eq!(BigUint::from_cbor_bignum(&cbor), Some(long));
+
+    let bad: [&[u8]; 9] = [
+        &[],
+        &[0xc2],
+        &[0xc2, 0x41],
+        &[0xc2, 0x41, 1, 2],
+        &[0xc2, 0x5f, 0x41, 1, 0xff],
+        &[0xc4, 0x40],
+        &[0xc3, 0x40],
+        &[0x20],
+        &[0x00, 0x00],
+    ];
+    for b in &bad {
+        assert_eq!(BigUint::from_cbor_bignum(b), None);
+    }
+}
+
+#[test]
+fn test_der_integer() {
+    fn check(n: u64, der: &[u8]) {
+        let n = BigUint::from(n);
+        assert_eq!(n.to_der_integer(), der);
+        assert_eq!(BigUint::from_der_integer(der), Some(n));
+    }
+
+    check(0, &[0x02, 0x01, 0x00]);
+    check(1, &[0x02, 0x01, 0x01]);
+    check(0x7f, &[0x02, 0x01, 0x7f]);
+    check(0x80, &[0x02, 0x02, 0x00, 0x80]);
+    check(0xff, &[0x02, 0x02, 0x00, 0xff]);
+    check(0x100, &[0x02, 0x02, 0x01, 0x00]);
+    check(0x8000_0000, &[0x02, 0x05, 0x00, 0x80, 0, 0, 0]);
+
+    // long-form lengths
+    let n = BigUint::from_bytes_be(&[0xff; 200]);
+    let der = n.to_der_integer();
+    assert_eq!(&der[..4], &[0x02, 0x81, 0xc9, 0x00]);
+    assert_eq!(BigUint::from_der_integer(&der), Some(n));
+    let n = BigUint::from_bytes_be(&[0x7f; 300]);
+    let der = n.to_der_integer();
+    assert_eq!(&der[..5], &[0x02, 0x82, 0x01, 0x2c, 0x7f]);
+    assert_eq!(BigUint::from_der_integer(&der), Some(n));
+
+    let bad: [&[u8]; 11] = [
+        &[],
+        &[0x02],
+        &[0x02, 0x00],
+        &[0x03, 0x01, 0x01],
+        &[0x02, 0x01, 0x80],
+        &[0x02, 0x02, 0x00, 0x01],
+        &[0x02, 0x02, 0x01],
+        &[0x02, 0x01, 0x01, 0x00],
+        &[0x02, 0x80, 0x01, 0x00, 0x00],
+        &[0x02, 0x81, 0x01, 0x01],
+        &[0x02, 0x82, 0x00, 0x01, 0x01],
+    ];
+    for b in &bad {
+        assert_eq!(BigUint::from_der_integer(b), None);
+    }
+}
+
 #[test]
 fn test_cmp() {
     let data: [&[_]; 7] = [&[], &[1], &[2], &[!0], &[0, 1], &[2, 1], &[1, 1, 1]];
@@ -516,6 +771,162 @@ fn test_shr() {
     );
 }
 
+#[test]
+fn test_shift_primitive_amounts() {
+    let n = BigUint::from_slice(&[1, 2, N1, 3, N2]);
+    macro_rules! check {
+        ($($t:ty: $shl:ident, $shr:ident;)*) => {$(
+            assert_eq!(n.$shl(37 as $t), &n << 37);
+            assert_eq!(n.$shr(37 as $t), &n >> 37);
+        )*};
+    }
+    check! {
+        u8: shl_u8, shr_u8;
+        u16: shl_u16, shr_u16;
+        u32: shl_u32, shr_u32;
+        u64: shl_u64, shr_u64;
+        i8: shl_i8, shr_i8;
+        i16: shl_i16, shr_i16;
+        i32: shl_i32, shr_i32;
+        i64: shl_i64, shr_i64;
+        isize: shl_isize, shr_isize;
+    }
+    #[cfg(has_i128)]
+    check! {
+        u128: shl_u128, shr_u128;
+        i128: shl_i128, shr_i128;
+    }
+
+    assert_eq!(n.shr_u64(u64::MAX), BigUint::zero());
+    assert_eq!(BigUint::zero().shl_u64(u64::MAX), BigUint::zero());
+}
+
+#[test]
+fn test_shift_biguint_amount() {
+    let n = BigUint::from_slice(&[1, 2, N1, 3, N2]);
+    let by = BigUint::from(37u32);
+    assert_eq!(&n << &by, &n << 37usize);
+    assert_eq!(&n >> &by, &n >> 37usize);
+    assert_eq!(n.clone() << by.clone(), &n << 37usize);
+    assert_eq!(n.clone() >> by.clone(), &n >> 37usize);
+
+    let mut x = n.clone();
+    x <<= &by;
+    x >>= by;
+    assert_eq!(x, n);
+
+    let huge = BigUint::one() << 100usize;
+    assert_eq!(&n >> &huge, BigUint::zero());
+    assert_eq!(BigUint::zero() << &huge, BigUint::zero());
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_shl_huge_biguint_amount() {
+    let _ = BigUint::one() << (BigUint::one() << 100usize);
+}
+
+#[test]
+fn test_checked_shl() {
+    let n = BigUint::from_slice(&[1, 2, N1, 3, N2]);
+    assert_eq!(n.checked_shl(37), Some(&n << 37usize));
+    assert_eq!(n.checked_shl(usize::MAX), None);
+    assert_eq!(
+        BigUint::zero().checked_shl(usize::MAX),
+        Some(BigUint::zero())
+    );
+
+    let bits = n.bit_len();
+    assert_eq!(n.checked_shl_max(10, bits + 10), Some(&n << 10usize));
+    assert_eq!(n.checked_shl_max(11, bits + 10), None);
+    assert_eq!(n.checked_shl_max(0, bits - 1), None);
+    assert_eq!(
+        BigUint::zero().checked_shl_max(1000, 0),
+        Some(BigUint::zero())
+    );
+}
+
+#[test]
+fn test_overflowing_sub() {
+    for elm in SUM_TRIPLES.iter() {
+        let (a_vec, b_vec, c_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+
+        assert_eq!(c.overflowing_sub(&a), (b.clone(), false));
+        assert_eq!(c.overflowing_sub(&b), (a.clone(), false));
+        assert_eq!(a.overflowing_sub(&c), (b.clone(), !b.is_zero()));
+        assert_eq!(b.overflowing_sub(&c), (a.clone(), !a.is_zero()));
+    }
+}
+
+#[test]
+fn test_checked_pow() {
+    for base in 0u32..20 {
+        let n = BigUint::from(base);
+        for exp in 0u64..30 {
+            let expected = (&n).pow(exp);
+            assert_eq!(n.checked_pow(exp), Some(expected.clone()));
+            let bits = expected.bit_len();
+            assert_eq!(n.checked_pow_max(exp, bits), Some(expected.clone()));
+            if bits > 0 {
+                assert_eq!(n.checked_pow_max(exp, bits - 1), None);
+            }
+        }
+    }
+
+    let n = BigUint::from_slice(&[1, 2, 3]);
+    assert_eq!(n.checked_pow(u64::MAX), None);
+    assert_eq!(n.checked_pow_max(1 << 40, 1 << 20), None);
+    assert_eq!(BigUint::zero().checked_pow(u64::MAX), Some(BigUint::zero()));
+    assert_eq!(
+        BigUint::one().checked_pow_max(u64::MAX, 1),
+        Some(BigUint::one())
+    );
+
+    let i = BigInt::from(-3);
+    assert_eq!(i.checked_pow(3), Some(BigInt::from(-27)));
+    assert_eq!(i.checked_pow(4), Some(BigInt::from(81)));
+    assert_eq!(i.checked_pow_max(u64::MAX, 1000), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with negative")]
+fn test_shl_negative() {
+    let _ = BigUint::one().shl_i32(-1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift right with negative")]
+fn test_shr_negative() {
+    let _ = BigUint::one().shr_i64(-1);
+}
+
+#[test]
+fn test_shl_add_or_assign() {
+    let values = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(u32::MAX),
+        BigUint::from(u64::MAX),
+        BigUint::from_slice(&[1, 2, N1, 3, N2]),
+    ];
+    for a in values.iter() {
+        for b in values.iter() {
+            for &bits in [0, 1, 7, 31, 32, 33, 64, 100].iter() {
+                let mut x = a.clone();
+                x.shl_add_assign(bits, b);
+                assert_eq!(x, (a << bits) + b);
+
+                let mut x = a.clone();
+                x.shl_or_assign(bits, b);
+                assert_eq!(x, (a << bits) | b);
+            }
+        }
+    }
+}
+
 // `DoubleBigDigit` size dependent
 #[test]
 fn test_convert_i64() {
@@ -614,6 +1025,40 @@ fn test_convert_u128() {
     assert_eq!(BigUint::new(vec![N1, N1, N1, N1, N1]).to_u128(), None);
 }
 
+#[test]
+#[cfg(has_try_from)]
+fn test_convert_try_from() {
+    use std::convert::TryFrom;
+
+    let small = BigUint::from(200u8);
+    assert_eq!(u8::try_from(&small), Ok(200));
+    assert!(i8::try_from(&small).is_err());
+    assert_eq!(u64::try_from(small.clone()), Ok(200));
+
+    let big = BigUint::new(vec![0, 0, 1]);
+    assert!(u32::try_from(&big).is_err());
+    assert!(u64::try_from(&big).is_err());
+    assert_eq!(u64::try_from(big.clone()).unwrap_err().into_original(), big);
+    assert!(usize::try_from(&big).is_err());
+
+    let err = u8::try_from(&big).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "out of range conversion regarding big integer attempted"
+    );
+}
+
+#[test]
+#[cfg(all(has_try_from, has_i128))]
+fn test_convert_try_from_128() {
+    use std::convert::TryFrom;
+
+    let max = BigUint::from(u128::MAX);
+    assert_eq!(u128::try_from(&max), Ok(u128::MAX));
+    assert!(i128::try_from(&max).is_err());
+    assert!(u128::try_from(max + 1u32).is_err());
+}
+
 #[test]
 fn test_convert_f32() {
     fn check(b1: &BigUint, f: f32) {
@@ -862,6 +1307,29 @@ fn test_mul() {
     }
 }
 
+#[test]
+fn test_mul_add() {
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, c_vec, d_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+        let d = BigUint::from_slice(d_vec);
+
+        assert_mul_add!(b * c + d == a);
+        assert_mul_add!(c * b + d == a);
+    }
+
+    // large enough for the Karatsuba and Toom-3 multiplication paths
+    for &digits in &[40usize, 300] {
+        let x = (BigUint::one() << (32 * digits)) - 1u32;
+        let y = &x >> 7usize;
+        let z = &x * 3u32;
+        assert_mul_add!(x * y + z == &x * &y + &z);
+        assert_mul_add!(y * x + x == &x * &y + &x);
+    }
+}
+
 #[test]
 fn test_div_rem() {
     for elm in MUL_TRIPLES.iter() {
@@ -876,6 +1344,10 @@ fn test_div_rem() {
             assert_assign_op!(c /= a == b);
             assert_assign_op!(c %= a == Zero::zero());
             assert_eq!(c.div_rem(&a), (b.clone(), Zero::zero()));
+
+            let mut q = c.clone();
+            assert_eq!(q.div_rem_assign(&a), Zero::zero());
+            assert_eq!(q, b);
         }
         if !b.is_zero() {
             assert_op!(c / b == a);
@@ -883,6 +1355,10 @@ fn test_div_rem() {
             assert_assign_op!(c /= b == a);
             assert_assign_op!(c %= b == Zero::zero());
             assert_eq!(c.div_rem(&b), (a.clone(), Zero::zero()));
+
+            let mut q = c.clone();
+            assert_eq!(q.div_rem_assign(&b), Zero::zero());
+            assert_eq!(q, a);
         }
     }
 
@@ -898,7 +1374,11 @@ fn test_div_rem() {
             assert_op!(a % b == d);
             assert_assign_op!(a /= b == c);
             assert_assign_op!(a %= b == d);
-            assert!(a.div_rem(&b) == (c, d));
+            assert!(a.div_rem(&b) == (c.clone(), d.clone()));
+
+            let mut q = a.clone();
+            assert_eq!(q.div_rem_assign(&b), d);
+            assert_eq!(q, c);
         }
     }
 }
@@ -988,6 +1468,41 @@ fn test_checked_div() {
     }
 }
 
+#[test]
+fn test_checked_rem_neg() {
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, _, d_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let d = BigUint::from_slice(d_vec);
+
+        assert_eq!(a.checked_rem(&b), Some(d));
+        assert_eq!(a.checked_rem(&Zero::zero()), None);
+        assert_eq!(a.checked_neg(), None);
+    }
+    assert_eq!(BigUint::zero().checked_neg(), Some(BigUint::zero()));
+}
+
+#[test]
+fn test_euclid() {
+    use num_traits::{CheckedEuclid, Euclid};
+
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, c_vec, d_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+        let d = BigUint::from_slice(d_vec);
+
+        assert_eq!(Euclid::div_euclid(&a, &b), c);
+        assert_eq!(Euclid::rem_euclid(&a, &b), d);
+        assert_eq!(a.checked_div_euclid(&b), Some(c));
+        assert_eq!(a.checked_rem_euclid(&b), Some(d));
+        assert_eq!(a.checked_div_euclid(&Zero::zero()), None);
+        assert_eq!(a.checked_rem_euclid(&Zero::zero()), None);
+    }
+}
+
 #[test]
 fn test_gcd() {
     fn check(a: usize, b: usize, c: usize) {
@@ -1024,6 +1539,46 @@ fn test_lcm() {
     check(99, 17, 1683);
 }
 
+#[test]
+fn test_is_multiple_of() {
+    for elm in DIV_REM_QUADRUPLES.iter() {
+        let (a_vec, b_vec, c_vec, d_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+        let d = BigUint::from_slice(d_vec);
+
+        assert_eq!(a.is_multiple_of(&b), d.is_zero());
+        assert!((&a - &d).is_multiple_of(&b));
+        assert!(((&a - &d) << 70usize).is_multiple_of(&(&b << 33usize)));
+        if !c.is_zero() {
+            assert!((&a - &d).is_multiple_of(&c));
+            assert!(!(&a - &d).is_multiple_of(&(&c << 200usize)));
+        }
+    }
+
+    let zero = BigUint::zero();
+    let three = BigUint::from(3u32);
+    assert!(zero.is_multiple_of(&zero));
+    assert!(zero.is_multiple_of(&three));
+    assert!(!three.is_multiple_of(&zero));
+    assert!((BigUint::one() << 100usize).is_multiple_of(&(BigUint::one() << 64usize)));
+    assert!(!(BigUint::one() << 63usize).is_multiple_of(&(BigUint::one() << 64usize)));
+
+    // multi-digit divisors, with and without the normalizing shift
+    let x = (&three).pow(700u32);
+    for &k in &[100u32, 300, 699] {
+        let y = (&three).pow(k);
+        assert!(x.is_multiple_of(&y));
+        assert!((&x << 5usize).is_multiple_of(&(&y << 5usize)));
+        assert!(!x.is_multiple_of(&(&y + 1u32)));
+        assert!(!(&x + 1u32).is_multiple_of(&y));
+    }
+    let m = (BigUint::one() << 128usize) - 1u32;
+    assert!((&m * &x).is_multiple_of(&m));
+    assert!(!(&m * &x + 1u32).is_multiple_of(&m));
+}
+
 #[test]
 fn test_is_even() {
     let one: BigUint = FromStr::from_str("1").unwrap();
@@ -1159,10 +1714,129 @@ fn test_to_str_radix() {
         for str_pair in rs.iter() {
             let &(ref radix, ref str) = str_pair;
             assert_eq!(n.to_str_radix(*radix), *str);
+            assert_eq!(n.to_str_radix_upper(*radix), str.to_uppercase());
         }
     }
 }
 
+#[test]
+fn test_format_grouped() {
+    assert_eq!(BigUint::zero().format_grouped(',', 3), "0");
+    assert_eq!(BigUint::from(999u32).format_grouped(',', 3), "999");
+    assert_eq!(BigUint::from(1000u32).format_grouped(',', 3), "1,000");
+    assert_eq!(BigUint::from(123456u32).format_grouped(',', 3), "123,456");
+    assert_eq!(BigUint::from(12345u32).format_grouped('_', 2), "1_23_45");
+    assert_eq!(
+        BigUint::from(12345u32).format_grouped('\u{202f}', 1),
+        "1\u{202f}2\u{202f}3\u{202f}4\u{202f}5"
+    );
+    let n = BigUint::from(10u32).pow(30u32);
+    assert_eq!(
+        n.format_grouped(',', 3),
+        "1,000,000,000,000,000,000,000,000,000,000"
+    );
+    assert_eq!(n.format_grouped(',', 3).replace(",", "").parse(), Ok(n));
+}
+
+#[test]
+fn test_debug_limbs() {
+    assert_eq!(
+        BigUint::zero().debug_limbs(),
+        "BigUint { bits: 0, limbs: [] }"
+    );
+    assert_eq!(
+        BigUint::from(u32::max_value()).debug_limbs(),
+        "BigUint { bits: 32, limbs: [0xffffffff] }"
+    );
+    let n = BigUint::from_slice(&[0xdead_beef, 0, 0x10]);
+    assert_eq!(
+        n.debug_limbs(),
+        "BigUint { bits: 69, limbs: [0xdeadbeef, 0x00000000, 0x00000010] }"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_format_grouped_zero_size() {
+    BigUint::one().format_grouped(',', 0);
+}
+
+#[test]
+fn test_format_engineering() {
+    let check = |n: u64, digits: usize, s: &str| {
+        assert_eq!(BigUint::from(n).format_engineering(digits), s);
+    };
+    check(0, 1, "0e0");
+    check(0, 3, "0.00e0");
+    check(7, 1, "7e0");
+    check(1234, 3, "1.23e3");
+    check(1235, 3, "1.24e3");
+    check(12345, 3, "12.3e3");
+    check(123456, 3, "123e3");
+    check(123456, 1, "100e3");
+    check(999_999, 3, "1.00e6");
+    check(999_499, 3, "999e3");
+    check(999_500, 3, "1.00e6");
+    check(1_000_000, 8, "1.0000000e6");
+
+    let n = BigUint::from(10u32).pow(100u32) - 1u32;
+    assert_eq!(n.format_engineering(5), "10.000e99");
+    let mut s = String::from("9.");
+    s.extend(repeat('9').take(99));
+    s.extend(repeat('0').take(20));
+    s.push_str("e99");
+    assert_eq!(n.format_engineering(120), s);
+
+    let n = BigUint::from(10u32).pow(100u32) * 54321u32;
+    assert_eq!(n.format_engineering(3), "543e102");
+    assert_eq!((n - 1u32).format_engineering(6), "543.210e102");
+}
+
+#[test]
+#[should_panic]
+fn test_format_engineering_zero_digits() {
+    BigUint::one().format_engineering(0);
+}
+
+#[test]
+fn test_display_precision() {
+    let n = BigUint::from(987654321u32);
+    assert_eq!(format!("{:.0}", n), "1e9");
+    assert_eq!(format!("{:.1}", n), "1e9");
+    assert_eq!(format!("{:.2}", n), "9.9e8");
+    assert_eq!(format!("{:.4}", n), "9.877e8");
+    assert_eq!(format!("{:.8}", n), "9.8765432e8");
+    assert_eq!(format!("{:.9}", n), "987654321");
+    assert_eq!(format!("{:.20}", n), "987654321");
+    assert_eq!(format!("{:>8.3}", n), "  9.88e8");
+    assert_eq!(format!("{:+.3}", n), "+9.88e8");
+    assert_eq!(format!("{:.3}", BigUint::zero()), "0");
+    assert_eq!(format!("{:.3}", BigUint::from(99999u32)), "1.00e5");
+
+    let n = BigUint::from(7u32).pow(100u32);
+    assert_eq!(format!("{:.5}", n), "3.2345e84");
+    assert_eq!(format!("{:.1000}", n), n.to_string());
+
+    // the digits past the precision are divided away before rounding
+    for k in 10..200u32 {
+        let ten_k = BigUint::from(10u32).pow(k);
+        assert_eq!(format!("{:.3}", &ten_k - 1u32), format!("1.00e{}", k));
+        assert_eq!(
+            format!("{:.3}", &ten_k / 2u32 - 1u32),
+            format!("5.00e{}", k - 1)
+        );
+        assert_eq!(
+            format!("{:.3}", &ten_k * 1235u32),
+            format!("1.24e{}", k + 3)
+        );
+        assert_eq!(
+            format!("{:.3}", &ten_k * 1235u32 - 1u32),
+            format!("1.23e{}", k + 3)
+        );
+        assert_eq!(format!("{:.3}", &ten_k), format!("1.00e{}", k));
+    }
+}
+
 #[test]
 fn test_from_and_to_radix() {
     const GROUND_TRUTH: &'static [(&'static [u8], u32, &'static [u8])] = &[
@@ -1513,6 +2187,339 @@ fn test_from_str_radix() {
     assert_eq!(ff, Some(BigUint::from_slice(&[0xff])));
 }
 
+#[test]
+fn test_from_str_prefixed() {
+    let check = |s: &str, n: Option<u32>| {
+        assert_eq!(BigUint::from_str_prefixed(s).ok(), n.map(BigUint::from));
+    };
+    check("0", Some(0));
+    check("0123", Some(123));
+    check("+42", Some(42));
+    check("0x1F", Some(0x1f));
+    check("0XfF", Some(0xff));
+    check("0o777", Some(0o777));
+    check("0O10", Some(8));
+    check("0b1010_0101", Some(0xa5));
+    check("0B1", Some(1));
+    check("0x", None);
+    check("0b", None);
+    check("0x+1", None);
+    check("+0x-1", None);
+    check("++1", None);
+    check("-1", None);
+    check("0o8", None);
+    check("0b2", None);
+    check("0xg", None);
+    check("1f", None);
+    check("", None);
+
+    let n = BigUint::from_str_prefixed("0xffffffff_ffffffff_ffffffff").unwrap();
+    assert_eq!(n, (BigUint::one() << 96) - 1u32);
+}
+
+#[test]
+fn test_from_str_scientific() {
+    let check = |s: &str, n: Option<u32>| {
+        assert_eq!(BigUint::from_str_scientific(s).ok(), n.map(BigUint::from));
+    };
+    check("0", Some(0));
+    check("123", Some(123));
+    check("+123", Some(123));
+    check("1e0", Some(1));
+    check("1e3", Some(1000));
+    check("1E3", Some(1000));
+    check("1e+3", Some(1000));
+    check("1.5e1", Some(15));
+    check("1.50e1", Some(15));
+    check("1.25e2", Some(125));
+    check("12.0", Some(12));
+    check("1200e-2", Some(12));
+    check("0.0", Some(0));
+    check("0e-100", Some(0));
+    check("0.000e5", Some(0));
+    check("1.25e1", None);
+    check("0.5", None);
+    check("1e-1", None);
+    check("1e", None);
+    check("e3", None);
+    check(".5e1", None);
+    check("1.e", None);
+    check("1e1.5", None);
+    check("1_000e3", None);
+    check("-1e3", None);
+    check("1e3e3", None);
+    check("1e99999999999999999999999", None);
+    check("", None);
+
+    let n = BigUint::from_str_scientific("1e100").unwrap();
+    assert_eq!(n, BigUint::from(10u32).pow(100u32));
+    let n = BigUint::from_str_scientific("1.234567890123456789e20").unwrap();
+    assert_eq!(n.to_string(), "123456789012345678900");
+    assert!(BigUint::from_str_scientific("1.5")
+        .unwrap_err()
+        .to_string()
+        .contains("fractional"));
+}
+
+#[test]
+fn test_from_str_scientific_max_bits() {
+    use num_bigint::BigIntErrorKind::{Fractional, TooLarge};
+
+    let kind = |s: &str, max_bits: u64| {
+        BigUint::from_str_scientific_max_bits(s, max_bits).map_err(|e| e.kind())
+    };
+    // 10^3 has 10 bits and 10^4 has 14
+    assert_eq!(kind("1e3", 10), Ok(BigUint::from(1000u32)));
+    assert_eq!(kind("1e4", 10), Err(TooLarge));
+    assert_eq!(kind("1023", 10), Ok(BigUint::from(1023u32)));
+    assert_eq!(kind("1024", 10), Err(TooLarge));
+    assert_eq!(kind("0e999999", 0), Ok(BigUint::zero()));
+    for zeros in 1..200 {
+        let n = BigUint::from(7u32) * BigUint::from(10u32).pow(zeros as u32);
+        let s = format!("7e{}", zeros);
+        assert_eq!(kind(&s, n.bit_len()), Ok(n.clone()));
+        assert_eq!(kind(&s, n.bit_len() - 1), Err(TooLarge));
+    }
+
+    // rejected from the exponent alone, without computing the power
+    let err = BigUint::from_str_scientific("1e1000000000000").unwrap_err();
+    assert_eq!(err.kind(), TooLarge);
+    assert!(err.to_string().contains("too large"));
+
+    // an exponent beyond usize saturates instead of failing to parse
+    assert_eq!(kind("1e99999999999999999999", u64::MAX), Err(TooLarge));
+    assert_eq!(kind("1e-99999999999999999999", u64::MAX), Err(Fractional));
+    assert_eq!(kind("0e99999999999999999999", 0), Ok(BigUint::zero()));
+    assert_eq!(kind("0e-99999999999999999999", 0), Ok(BigUint::zero()));
+
+    // the default limit is 2^20 bits
+    assert!(BigUint::from_str_scientific("1e315000").is_ok());
+    assert!(BigUint::from_str_scientific("1e316000").is_err());
+}
+
+#[test]
+fn test_from_str_radix_separated() {
+    let check = |s: &str, radix: u32, separators: &[char], n: Option<u64>| {
+        assert_eq!(
+            BigUint::from_str_radix_separated(s, radix, separators).ok(),
+            n.map(BigUint::from)
+        );
+    };
+    check("1,234,567", 10, &[','], Some(1234567));
+    check(",1,,2,", 10, &[','], Some(12));
+    check("_1_2_", 10, &['_'], Some(12));
+    check("1 2,3_4", 10, &[' ', ','], Some(1234));
+    check("+ 12", 10, &[' '], Some(12));
+    check("ff ff\n\tff ff", 16, &[' ', '\n', '\t'], Some(0xffff_ffff));
+    check("1 2", 10, &[','], None);
+    check("1.2", 10, &[','], None);
+    check(" , ", 10, &[' ', ','], None);
+    check("", 10, &[','], None);
+
+    // Without `_` as a separator, it keeps its usual meaning.
+    check("1_2", 10, &[','], Some(12));
+    check("_12", 10, &[','], None);
+
+    let digits = "ffffffff ffffffff ffffffff ffffffff";
+    let n = BigUint::from_str_radix_separated(digits, 16, &[' ']).unwrap();
+    assert_eq!(n, (BigUint::one() << 128) - 1u32);
+}
+
+#[test]
+fn test_try_parse_bytes() {
+    let kind = |buf: &[u8], radix: u32| BigUint::try_parse_bytes(buf, radix).unwrap_err().kind();
+    assert_eq!(
+        BigUint::try_parse_bytes(b"1_000", 10),
+        Ok(BigUint::from(1000u32))
+    );
+    assert_eq!(kind(b"", 10), BigIntErrorKind::Empty);
+    assert_eq!(kind(b"+", 10), BigIntErrorKind::Empty);
+    assert_eq!(kind(b"12a", 10), BigIntErrorKind::InvalidDigit);
+    assert_eq!(kind(b"-1", 10), BigIntErrorKind::InvalidDigit);
+    assert_eq!(kind(b"\xff", 10), BigIntErrorKind::InvalidDigit);
+    assert_eq!(kind(b"1", 0), BigIntErrorKind::InvalidRadix);
+    assert_eq!(kind(b"1", 1), BigIntErrorKind::InvalidRadix);
+    assert_eq!(kind(b"1", 63), BigIntErrorKind::InvalidRadix);
+    assert_eq!(BigUint::parse_bytes(b"1", 63), None);
+}
+
+#[test]
+fn test_parse_error_position() {
+    let at = |r: Result<BigUint, ParseBigIntError>| {
+        let e = r.unwrap_err();
+        (e.position(), e.invalid_char())
+    };
+    assert_eq!(at("12x4".parse()), (Some(2), Some('x')));
+    assert_eq!(at("+12x4".parse()), (Some(3), Some('x')));
+    assert_eq!(at("++1".parse()), (Some(0), Some('+')));
+    assert_eq!(at("_1".parse()), (Some(0), Some('_')));
+    assert_eq!(at("+_1".parse()), (Some(1), Some('_')));
+    assert_eq!(at("12é".parse()), (Some(2), Some('é')));
+    assert_eq!(at("".parse()), (None, None));
+    assert_eq!(at(BigUint::from_str_radix("1012", 2)), (Some(3), Some('2')));
+    assert_eq!(at(BigUint::try_parse_bytes(b"12\xff", 10)), (Some(2), None));
+    assert_eq!(
+        at(BigUint::from_str_prefixed("+0x1g")),
+        (Some(4), Some('g'))
+    );
+    assert_eq!(at(BigUint::from_str_prefixed("0x-1")), (Some(2), Some('-')));
+    assert_eq!(
+        at(BigUint::from_str_scientific("1.2x")),
+        (Some(3), Some('x'))
+    );
+    assert_eq!(at(BigUint::from_str_scientific("+1e")), (Some(3), None));
+    assert_eq!(at(BigUint::from_str_scientific(".5")), (Some(0), Some('.')));
+    assert_eq!(
+        at(BigUint::from_str_scientific("1e+-3")),
+        (Some(3), Some('-'))
+    );
+    assert_eq!(
+        at(BigUint::from_str_radix_separated(
+            "1, 2, x",
+            10,
+            &[',', ' ']
+        )),
+        (Some(6), Some('x'))
+    );
+    assert_eq!(
+        at(BigUint::from_str_radix_separated("1–2x", 10, &['–'])),
+        (Some(5), Some('x'))
+    );
+
+    let e = "123x".parse::<BigUint>().unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit 'x' found at byte 3");
+    assert_eq!(e.kind(), BigIntErrorKind::InvalidDigit);
+    let e = BigUint::try_parse_bytes(b"\xff", 10).unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit found at byte 0");
+    let e = "".parse::<BigUint>().unwrap_err();
+    assert_eq!(e.to_string(), "cannot parse integer from empty string");
+}
+
+#[test]
+fn test_read_str_radix() {
+    use std::cmp;
+    use std::io::{self, Read};
+
+    // Returns at most three bytes from each read, to split the input unevenly.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = cmp::min(cmp::min(buf.len(), 3), self.0.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    let read = |s: &str, radix: u32| BigUint::read_str_radix(s.as_bytes(), radix);
+    assert_eq!(read("0", 10).unwrap(), BigUint::zero());
+    assert_eq!(read("+1_000", 10).unwrap(), BigUint::from(1000u32));
+    assert_eq!(read(" \t42\r\n", 10).unwrap(), BigUint::from(42u32));
+    assert_eq!(read("zz", 62).unwrap(), BigUint::from(3843u32));
+
+    for s in &["", " ", "+", "-1", "_1", "+_1", "1 2", "12a", "1\n\n+"] {
+        let e = read(s, 10).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+    let e = read("12 3", 10).unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit '3' found at byte 3");
+
+    for &(base, exp, radix) in &[(3u32, 50_000u32, 10u32), (7, 20_000, 16), (11, 9_000, 7)] {
+        let n = BigUint::from(base).pow(exp);
+        let s = n.to_str_radix(radix);
+        assert_eq!(read(&s, radix).unwrap(), n);
+        let r = BigUint::read_str_radix(Trickle(s.as_bytes()), radix);
+        assert_eq!(r.unwrap(), n);
+    }
+}
+
+#[test]
+fn test_read_str_radix_iter() {
+    let read = |s: &str, radix: u32| BigUint::read_str_radix_iter(s.bytes(), radix);
+    assert_eq!(read("0", 10), Ok(BigUint::zero()));
+    assert_eq!(read("+1_000", 10), Ok(BigUint::from(1000u32)));
+    assert_eq!(read(" \t42\r\n", 10), Ok(BigUint::from(42u32)));
+    assert_eq!(read("zz", 62), Ok(BigUint::from(3843u32)));
+
+    for s in &["", " ", "+", "-1", "_1", "+_1", "1 2", "12a", "1\n\n+"] {
+        assert!(read(s, 10).is_err());
+    }
+    let e = read("12 3", 10).unwrap_err();
+    assert_eq!(e.position(), Some(3));
+    assert_eq!(e.invalid_char(), Some('3'));
+
+    let n = BigUint::from(3u32).pow(50_000u32);
+    let s = n.to_str_radix(10);
+    assert_eq!(read(&s, 10), Ok(n.clone()));
+    // the digits are consumed as the iterator produces them
+    let chunks: Vec<&[u8]> = s.as_bytes().chunks(7).collect();
+    let bytes = chunks.into_iter().flat_map(|c| c.iter().cloned());
+    assert_eq!(BigUint::read_str_radix_iter(bytes, 10), Ok(n));
+}
+
+#[test]
+fn test_from_xof() {
+    use std::io;
+
+    let output: Vec<u8> = (1..41u8).collect();
+    let xof = |bits: u64| BigUint::from_xof(&mut &output[..], bits);
+    assert_eq!(xof(0).unwrap(), BigUint::zero());
+    assert_eq!(xof(8).unwrap(), BigUint::from(1u32));
+    assert_eq!(xof(16).unwrap(), BigUint::from(0x0102u32));
+    assert_eq!(xof(13).unwrap(), BigUint::from(0x0102u32));
+    assert_eq!(xof(12).unwrap(), BigUint::from(0x0102u32));
+    assert_eq!(xof(9).unwrap(), BigUint::from(0x0102u32 & 0x1ff));
+    assert_eq!(xof(320).unwrap(), BigUint::from_bytes_be(&output));
+    assert!(xof(300).unwrap().bit_len() <= 300);
+
+    let e = xof(321).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+
+    // Successive reads continue through the output.
+    let mut reader = &output[..];
+    assert_eq!(
+        BigUint::from_xof(&mut reader, 8).unwrap(),
+        BigUint::from(1u32)
+    );
+    assert_eq!(
+        BigUint::from_xof(&mut reader, 8).unwrap(),
+        BigUint::from(2u32)
+    );
+}
+
+#[test]
+fn test_write_str_radix() {
+    let write = |n: &BigUint, radix: u32| {
+        let mut out = Vec::new();
+        n.write_str_radix(radix, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    assert_eq!(write(&BigUint::zero(), 10), "0");
+    assert_eq!(write(&BigUint::from(255u32), 2), "11111111");
+    assert_eq!(write(&BigUint::from(3843u32), 62), "zz");
+
+    // Numbers around the chunk sizes, where the internal zeros must be kept.
+    let ten = BigUint::from(10u32);
+    for &exp in &[4095u32, 4096, 4097, 8191, 8192, 8193, 20000] {
+        let n = ten.pow(exp);
+        assert_eq!(write(&n, 10), n.to_string());
+        assert_eq!(write(&(&n - 1u32), 10), (&n - 1u32).to_string());
+        assert_eq!(write(&(&n + 1u32), 10), (&n + 1u32).to_string());
+    }
+
+    // Numbers around the bit length written without splitting into chunks.
+    for &(bits, radix) in &[(12288usize, 10u32), (4096, 2), (4096, 3), (24576, 62)] {
+        let n = BigUint::one() << bits;
+        assert_eq!(write(&n, radix), n.to_str_radix(radix));
+        assert_eq!(write(&(&n - 1u32), radix), (&n - 1u32).to_str_radix(radix));
+    }
+    for &(base, exp, radix) in &[(3u32, 50_000u32, 10u32), (7, 20_000, 16), (11, 9_000, 7)] {
+        let n = BigUint::from(base).pow(exp);
+        assert_eq!(write(&n, radix), n.to_str_radix(radix));
+    }
+}
+
 #[test]
 fn test_all_str_radix() {
     #[allow(deprecated, unused_imports)]
@@ -1528,6 +2535,35 @@ fn test_all_str_radix() {
         let x = BigUint::from_str_radix(&s, radix);
         assert_eq!(x.unwrap(), n);
     }
+    for radix in 37..63 {
+        let s = n.to_str_radix(radix);
+        assert_eq!(s, n.to_str_radix_upper(radix));
+        let x = BigUint::from_str_radix(&s, radix);
+        assert_eq!(x.unwrap(), n);
+    }
+}
+
+#[test]
+fn test_str_radix_62() {
+    let n = BigUint::from(61u32 * 62 * 62 + 36 * 62 + 35);
+    assert_eq!(n.to_str_radix(62), "zaZ");
+    assert_eq!(BigUint::from_str_radix("zaZ", 62), Ok(n));
+    assert_eq!(
+        BigUint::from_str_radix("ZAz", 62),
+        Ok(BigUint::from(35u32 * 62 * 62 + 10 * 62 + 61))
+    );
+    assert_eq!(BigUint::from(10u32).to_str_radix(37), "A");
+    assert_eq!(BigUint::from(36u32).to_str_radix(37), "a");
+    assert!(BigUint::from_str_radix("a", 36).is_ok());
+    assert!(BigUint::from_str_radix("z", 61).is_err());
+    assert_eq!(BigInt::from_str_radix("-10", 62), Ok(BigInt::from(-62)));
+    assert_eq!(BigInt::from(-3843).to_str_radix(62), "-zz");
+}
+
+#[test]
+#[should_panic]
+fn test_str_radix_63() {
+    BigUint::one().to_str_radix(63);
 }
 
 #[test]
@@ -1612,18 +2648,60 @@ fn test_factor() {
 }
 
 #[test]
-fn test_bits() {
-    assert_eq!(BigUint::new(vec![0, 0, 0, 0]).bits(), 0);
+fn test_bit_len() {
+    assert_eq!(BigUint::new(vec![0, 0, 0, 0]).bit_len(), 0);
     let n: BigUint = FromPrimitive::from_usize(0).unwrap();
-    assert_eq!(n.bits(), 0);
+    assert_eq!(n.bit_len(), 0);
     let n: BigUint = FromPrimitive::from_usize(1).unwrap();
-    assert_eq!(n.bits(), 1);
+    assert_eq!(n.bit_len(), 1);
     let n: BigUint = FromPrimitive::from_usize(3).unwrap();
-    assert_eq!(n.bits(), 2);
+    assert_eq!(n.bit_len(), 2);
     let n: BigUint = BigUint::from_str_radix("4000000000", 16).unwrap();
-    assert_eq!(n.bits(), 39);
+    assert_eq!(n.bit_len(), 39);
     let one: BigUint = One::one();
-    assert_eq!((one << 426).bits(), 427);
+    assert_eq!((&one << 426).bit_len(), 427);
+    assert_eq!((one << 100_000usize).bit_len(), 100_001);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_bits() {
+    assert_eq!(BigUint::zero().bits(), 0);
+    let n: BigUint = BigUint::from_str_radix("4000000000", 16).unwrap();
+    assert_eq!(n.bits(), 39);
+    assert_eq!(n.bits() as u64, n.bit_len());
+}
+
+#[test]
+fn test_heap_size_in_bytes() {
+    assert_eq!(BigUint::zero().heap_size_in_bytes(), 0);
+
+    let mut n = BigUint::from_slice(&[1, 2, 3, 4]);
+    assert!(n.heap_size_in_bytes() >= 16);
+
+    n >>= 96;
+    n.shrink_to_fit();
+    assert_eq!(n, BigUint::from(4u32));
+    assert!(n.heap_size_in_bytes() < 16);
+}
+
+#[test]
+fn test_digits_len() {
+    assert_eq!(BigUint::zero().digits_len(), 0);
+    assert_eq!(BigUint::from_slice(&[1, 2, 3, 0, 0]).digits_len(), 3);
+    for bits in 1..200usize {
+        let n = BigUint::one() << (bits - 1);
+        assert_eq!(n.digits_len(), (bits + 31) / 32);
+        assert_eq!(n.digits_len() as u64, (n.bit_len() + 31) / 32);
+    }
+
+    let n = BigUint::from_slice(&[0, 1]) - 1u32;
+    assert_eq!(n.digits_len(), 1);
+    #[cfg(debug_assertions)]
+    {
+        assert!(n.is_normalized());
+        assert!(BigUint::zero().is_normalized());
+    }
 }
 
 #[test]
@@ -1684,6 +2762,437 @@ fn test_iter_product_generic() {
     assert_eq!(result, data.into_iter().product());
 }
 
+#[test]
+fn test_product_tree() {
+    assert_eq!(BigUint::product_tree(Vec::<u32>::new()), BigUint::one());
+    assert_eq!(BigUint::product_tree(vec![7u32]), BigUint::from(7u32));
+    assert_eq!(BigUint::product_tree(vec![3u32, 0, 5]), BigUint::zero());
+
+    for n in 0..40u32 {
+        let expected: BigUint = (1..n + 1).map(BigUint::from).product();
+        assert_eq!(BigUint::product_tree(1..n + 1), expected);
+    }
+
+    let data = vec![
+        BigUint::from(10u32).pow(50u32),
+        BigUint::from(3u32),
+        BigUint::from(u64::MAX),
+    ];
+    let expected: BigUint = data.iter().product();
+    assert_eq!(BigUint::product_tree(data), expected);
+}
+
+#[test]
+fn test_range() {
+    use num_bigint::{range, range_inclusive, range_step, range_step_inclusive};
+
+    let big = |n: u32| BigUint::from(n);
+    let collect = |r: num_bigint::BigRange<BigUint>| r.collect::<Vec<_>>();
+    assert_eq!(collect(range(big(0), big(3))), [big(0), big(1), big(2)]);
+    assert_eq!(collect(range(big(3), big(3))), []);
+    assert_eq!(collect(range(big(4), big(3))), []);
+    assert_eq!(collect(range_inclusive(big(3), big(3))), [big(3)]);
+    assert_eq!(collect(range_inclusive(big(4), big(3))), []);
+    assert_eq!(
+        collect(range_step(big(1), big(7), big(3))),
+        [big(1), big(4)]
+    );
+    assert_eq!(
+        collect(range_step_inclusive(big(1), big(7), big(3))),
+        [big(1), big(4), big(7)]
+    );
+
+    let start = BigUint::one() << 200;
+    let end = &start + 1000u32;
+    assert_eq!(range(start.clone(), end.clone()).count(), 1000);
+    assert_eq!(
+        range_inclusive(start.clone(), end.clone()).last(),
+        Some(end)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_range_step_zero() {
+    num_bigint::range_step(BigUint::zero(), BigUint::one(), BigUint::zero());
+}
+
+#[test]
+fn test_rem_primitive() {
+    let n = BigUint::from(1u32) << 200;
+    assert_eq!(n.rem_u32(1000), 376);
+    assert_eq!(n.rem_u64(1000), 376);
+    assert_eq!(n.rem_u64(u64::MAX - 58), 52577024);
+    assert_eq!(
+        BigUint::from(3u32).pow(90u32).rem_u64((1 << 40) + 15),
+        1082071615266
+    );
+    assert_eq!(BigUint::zero().rem_u64(7), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_rem_primitive_zero() {
+    BigUint::zero().rem_u32(0);
+}
+
+#[test]
+fn test_cmp_primitive() {
+    let n = BigUint::from(1_000_000u32);
+    assert_eq!(n.cmp_u32(1_000_000), Equal);
+    assert_eq!(n.cmp_i64(1_000_000), Equal);
+    assert_eq!(n.cmp_u64(999_999), Greater);
+    assert_eq!(n.cmp_usize(1_000_001), Less);
+    assert_eq!(n.cmp_i8(-1), Greater);
+    assert_eq!(n.cmp_u16(u16::MAX), Greater);
+    assert_eq!(BigUint::zero().cmp_u8(0), Equal);
+
+    let big = BigUint::from(1u32) << 100;
+    assert_eq!(big.cmp_u64(u64::MAX), Greater);
+    assert_eq!(big.cmp_i64(i64::MAX), Greater);
+    assert_eq!(big.cmp_i64(i64::MIN), Greater);
+    #[cfg(has_i128)]
+    assert_eq!(big.cmp_u128(u128::MAX), Less);
+    #[cfg(has_i128)]
+    assert_eq!(big.cmp_u128(1 << 100), Equal);
+}
+
+#[test]
+fn test_cmp_float() {
+    let n = BigUint::from(1u32) << 64;
+    assert_eq!(n.partial_cmp_f64(18446744073709551616.0), Some(Equal));
+    assert_eq!(n.partial_cmp_f32(18446744073709551616.0), Some(Equal));
+    assert_eq!(
+        (&n - 1u32).partial_cmp_f64(18446744073709551616.0),
+        Some(Less)
+    );
+    assert_eq!(
+        (&n + 1u32).partial_cmp_f64(18446744073709551616.0),
+        Some(Greater)
+    );
+
+    let three = BigUint::from(3u32);
+    assert_eq!(three.partial_cmp_f64(2.5), Some(Greater));
+    assert_eq!(three.partial_cmp_f32(3.5), Some(Less));
+    assert_eq!(three.partial_cmp_f64(3.000001), Some(Less));
+    assert_eq!(three.partial_cmp_f64(-0.5), Some(Greater));
+    assert_eq!(BigUint::zero().partial_cmp_f64(-0.0), Some(Equal));
+    assert_eq!(
+        BigUint::zero().partial_cmp_f64(-f64::INFINITY),
+        Some(Greater)
+    );
+
+    let huge = BigUint::from(1u32) << 2000;
+    assert_eq!(huge.partial_cmp_f64(f64::MAX), Some(Greater));
+    assert_eq!(huge.partial_cmp_f64(f64::INFINITY), Some(Less));
+    assert_eq!(huge.partial_cmp_f64(f64::NAN), None);
+}
+
+#[test]
+fn test_conditional_select_swap() {
+    let values = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(u64::MAX),
+        BigUint::from_slice(&[1, 2, N1, 3, N2]),
+    ];
+    for a in &values {
+        for b in &values {
+            assert_eq!(BigUint::conditional_select(a, b, false), *a);
+            assert_eq!(BigUint::conditional_select(a, b, true), *b);
+
+            let (mut x, mut y) = (a.clone(), b.clone());
+            BigUint::conditional_swap(&mut x, &mut y, false);
+            assert_eq!((&x, &y), (a, b));
+            BigUint::conditional_swap(&mut x, &mut y, true);
+            assert_eq!((&x, &y), (b, a));
+        }
+    }
+}
+
+#[test]
+fn test_div_ceil_next_multiple_of() {
+    for a in 0u64..30 {
+        for b in 1u64..8 {
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            let next = (a + b - 1) / b * b;
+            assert_eq!(x.div_ceil(&y), BigUint::from((a + b - 1) / b));
+            assert_eq!(x.next_multiple_of(&y), BigUint::from(next));
+        }
+    }
+
+    let x = (BigUint::one() << 100usize) + 1u32;
+    let y = BigUint::one() << 64usize;
+    assert_eq!(x.div_ceil(&y), (BigUint::one() << 36usize) + 1u32);
+    assert_eq!(x.next_multiple_of(&y), (BigUint::one() << 100usize) + &y);
+}
+
+#[test]
+fn test_ilog() {
+    assert_eq!(BigUint::zero().checked_ilog2(), None);
+    assert_eq!(BigUint::zero().checked_ilog10(), None);
+    assert_eq!(BigUint::from(5u32).checked_ilog(1), None);
+    assert_eq!(BigUint::from(5u32).checked_ilog(0), None);
+
+    for n in 1u64..2000 {
+        let x = BigUint::from(n);
+        assert_eq!(x.ilog2(), 63 - u64::from(n.leading_zeros()));
+        assert_eq!(x.ilog10(), n.to_string().len() as u64 - 1);
+        for base in 2u32..40 {
+            let mut log = 0;
+            while u64::from(base).pow(log + 1) <= n {
+                log += 1;
+            }
+            assert_eq!(x.ilog(base), u64::from(log));
+        }
+    }
+
+    for &base in &[2u32, 3, 7, 10, 16, 255, 1000, u32::MAX] {
+        for &e in &[1u32, 20, 64, 65, 333] {
+            let p = BigUint::from(base).pow(e);
+            assert_eq!(p.ilog(base), u64::from(e));
+            assert_eq!((&p - 1u32).ilog(base), u64::from(e) - 1);
+            assert_eq!((&p + 1u32).ilog(base), u64::from(e));
+        }
+    }
+
+    let x = BigInt::from(1000);
+    assert_eq!(x.checked_ilog10(), Some(3));
+    assert_eq!(x.ilog2(), 9);
+    assert_eq!(x.ilog(7), 3);
+    assert_eq!((-x).checked_ilog10(), None);
+    assert_eq!(BigInt::zero().checked_ilog2(), None);
+}
+
+#[test]
+#[should_panic(expected = "must be positive")]
+fn test_ilog_zero() {
+    BigUint::zero().ilog10();
+}
+
+#[test]
+fn test_count_ones() {
+    for &n in &[
+        0u64,
+        1,
+        2,
+        3,
+        0xff,
+        0x8000_0001,
+        0xdead_beef_0bad_f00d,
+        u64::MAX,
+    ] {
+        assert_eq!(BigUint::from(n).count_ones(), u64::from(n.count_ones()));
+        assert_eq!(BigInt::from(n).count_ones(), u64::from(n.count_ones()));
+        let i = n as i64;
+        for &width in &[1u64, 7, 8, 32, 33, 63] {
+            let mask = (1u64 << width) - 1;
+            assert_eq!(
+                BigInt::from(i).count_ones_in_width(width),
+                u64::from((i as u64 & mask).count_ones())
+            );
+        }
+        assert_eq!(
+            BigInt::from(i).count_ones_in_width(64),
+            u64::from(i.count_ones())
+        );
+        assert_eq!(
+            BigInt::from(i).count_ones_in_width(100),
+            u64::from(i.count_ones()) + if i < 0 { 36 } else { 0 }
+        );
+    }
+
+    let n = BigUint::from_slice(&[!0, 0, 0x1010, 1]);
+    assert_eq!(n.count_ones(), 35);
+}
+
+#[test]
+fn test_bit_accessors() {
+    let mut n = BigUint::zero();
+    let mut expected = BigUint::zero();
+    for &bit in &[0u64, 5, 31, 32, 33, 64, 200, 31] {
+        assert_eq!(n.bit(bit), !(&expected >> bit as usize).is_even());
+        n.toggle_bit(bit);
+        expected = expected ^ (BigUint::one() << bit as usize);
+        assert_eq!(n, expected);
+        assert!(n.bit(bit) != (&expected >> bit as usize).is_even());
+    }
+
+    n.set_bit(200, true);
+    assert_eq!(n, expected);
+    for bit in 0..300 {
+        n.set_bit(bit, false);
+    }
+    assert!(n.is_zero());
+    assert_eq!(n.to_u32_digits(), Vec::<u32>::new());
+
+    n.set_bit(300, false);
+    assert!(n.is_zero());
+    assert!(!n.bit(u64::MAX));
+}
+
+#[test]
+fn test_iter_bits() {
+    let n = BigUint::from_slice(&[0x8000_0001, 0, 5]);
+    let bits: Vec<bool> = n.iter_bits().collect();
+    assert_eq!(bits.len() as u64, n.bit_len());
+    assert_eq!(n.iter_bits().len() as u64, n.bit_len());
+    for (i, &bit) in bits.iter().enumerate() {
+        assert_eq!(bit, n.bit(i as u64));
+    }
+    let ones: Vec<usize> = (0..bits.len()).filter(|&i| bits[i]).collect();
+    assert_eq!(ones, vec![0, 31, 64, 66]);
+
+    let mut be: Vec<bool> = n.iter_bits_be().collect();
+    be.reverse();
+    assert_eq!(be, bits);
+
+    let mut it = n.iter_bits();
+    assert_eq!(it.next(), Some(true));
+    assert_eq!(it.next_back(), Some(true));
+    assert_eq!(it.next_back(), Some(false));
+    assert_eq!(it.len(), 64);
+    assert_eq!(it.filter(|&b| b).count(), 2);
+
+    assert_eq!(BigUint::zero().iter_bits().next(), None);
+    assert_eq!(BigUint::zero().iter_bits_be().next(), None);
+}
+
+#[test]
+fn test_wnaf() {
+    let mut values: Vec<BigUint> = (0u32..300).map(BigUint::from).collect();
+    values.push(BigUint::from_slice(&[!0, !0, !0]));
+    values.push(BigUint::from_slice(&[
+        0x5555_5555,
+        0xaaaa_aaaa,
+        0xdead_beef,
+        1,
+    ]));
+    values.push(BigUint::from_slice(&[0x8000_0000; 3]));
+
+    for n in &values {
+        assert_eq!(n.to_naf(), n.to_wnaf(2));
+        for w in 2..9 {
+            let naf = n.to_wnaf(w);
+            assert!(naf.len() as u64 <= n.bit_len() + 1);
+            assert_ne!(naf.last(), Some(&0));
+
+            let mut sum = BigInt::zero();
+            for (i, &d) in naf.iter().enumerate() {
+                sum = sum + (BigInt::from(d) << i);
+                assert!(d == 0 || d % 2 != 0);
+                assert!(i32::from(d).abs() < 1 << (w - 1));
+                if d != 0 {
+                    let next = &naf[i + 1..std::cmp::min(naf.len(), i + w as usize)];
+                    assert!(next.iter().all(|&d| d == 0));
+                }
+            }
+            assert_eq!(sum, BigInt::from(n.clone()));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "2...8")]
+fn test_wnaf_width() {
+    BigUint::from(5u32).to_wnaf(9);
+}
+
+#[test]
+fn test_reverse_bits() {
+    for &n in &[0u32, 1, 2, 0x8000_0000, 0x1234_5678, 0xffff_ffff] {
+        let x = BigUint::from(n);
+        let reversed = (0..32).fold(0u32, |r, i| r | (n >> i & 1) << (31 - i));
+        assert_eq!(x.reverse_bits(32), Some(BigUint::from(reversed)));
+        assert_eq!(
+            x.reverse_bits(64),
+            Some(BigUint::from(u64::from(reversed) << 32))
+        );
+    }
+
+    let n = BigUint::from_slice(&[0x1234_5678, 0x9abc_def0, 0x5]);
+    for width in n.bit_len()..200 {
+        let r = n.reverse_bits(width as u64).unwrap();
+        assert!(r.bit_len() <= width);
+        for i in 0..width as u64 {
+            assert_eq!(r.bit(width as u64 - 1 - i), n.bit(i));
+        }
+        assert_eq!(r.reverse_bits(width as u64).unwrap(), n);
+    }
+    assert_eq!(n.reverse_bits(n.bit_len() - 1), None);
+    assert_eq!(BigUint::zero().reverse_bits(0), Some(BigUint::zero()));
+}
+
+#[test]
+fn test_keep_low_bits() {
+    let n = BigUint::from_slice(&[0x1234_5678, 0x9abc_def0, 0x0fed_cba9, 0x8765_4321]);
+    for bits in 0..140u64 {
+        let expected = &n % (BigUint::one() << bits as usize);
+        let mut m = n.clone();
+        m.keep_low_bits(bits);
+        assert_eq!(m, expected);
+        assert_eq!(n.clone().into_low_bits(bits), expected);
+    }
+
+    // Clearing the whole top digit must normalize.
+    let mut m = BigUint::from_slice(&[1, 0, 4]);
+    m.keep_low_bits(66);
+    assert_eq!(m, BigUint::one());
+    assert_eq!(m.to_u32_digits(), vec![1]);
+    assert_eq!(BigUint::zero().into_low_bits(10), BigUint::zero());
+}
+
+#[test]
+fn test_div_rem_power_of_two() {
+    let n = BigUint::from_slice(&[0x1234_5678, 0x9abc_def0, 0x0fed_cba9, 0x8765_4321]);
+    for k in 0..140usize {
+        let d = BigUint::one() << k;
+        let (q, r) = n.div_rem(&d);
+        assert_eq!(q, &n >> k);
+        assert_eq!(&q * &d + &r, n);
+        assert!(r < d);
+        assert_eq!(n.clone() / d.clone(), q);
+        assert_eq!(n.clone() % d.clone(), r);
+        assert_eq!(&n / &d, q);
+        assert_eq!(&n % &d, r);
+
+        let mut m = n.clone();
+        assert_eq!(m.div_rem_assign(&d), r);
+        assert_eq!(m, q);
+
+        if k < 32 {
+            assert_eq!(&n / (1u32 << k), q);
+            assert_eq!(&n % (1u32 << k), r);
+        }
+        if k < 64 {
+            assert_eq!(&n / (1u64 << k), q);
+            assert_eq!(&n % (1u64 << k), r);
+        }
+    }
+}
+
+#[test]
+fn test_nonzero() {
+    use num_bigint::NonZeroBigUint;
+
+    assert!(NonZeroBigUint::new(BigUint::zero()).is_none());
+
+    let big = BigUint::from_slice(&[1, 2, 3]);
+    let d = NonZeroBigUint::new(BigUint::from(12345u32)).unwrap();
+    assert_eq!(d.get(), &BigUint::from(12345u32));
+    assert_eq!(d.to_string(), "12345");
+
+    let (q, r) = big.div_rem(d.get());
+    assert_eq!(&big / &d, q);
+    assert_eq!(&big / d.clone(), q);
+    assert_eq!(big.clone() / &d, q);
+    assert_eq!(big.clone() % d.clone(), r);
+    assert_eq!(&big % &d, r);
+    assert_eq!(big.div_rem_nonzero(&d), (q, r));
+
+    assert_eq!(BigUint::from(d), BigUint::from(12345u32));
+}
+
 #[test]
 fn test_pow() {
     let one = BigUint::from(1u32);
@@ -4,6 +4,10 @@ extern crate num_traits;
 use num_bigint::BigUint;
 use num_traits::{ToPrimitive, Zero};
 
+#[cfg(has_i128)]
+use std::i128;
+use std::{i32, i64};
+
 mod consts;
 use consts::*;
 
@@ -107,3 +111,85 @@ fn test_scalar_div_rem() {
         }
     }
 }
+
+#[test]
+fn test_scalar_bitwise() {
+    fn check(x: &BigUint, y: &BigUint) {
+        let (x, y) = (x.clone(), y.clone());
+        assert_unsigned_scalar_op!(x & y == &x & &y);
+        assert_unsigned_scalar_op!(x | y == &x | &y);
+        assert_unsigned_scalar_op!(x ^ y == &x ^ &y);
+    }
+
+    for elm in SUM_TRIPLES.iter() {
+        let (a_vec, b_vec, c_vec) = *elm;
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+
+        check(&a, &b);
+        check(&b, &c);
+        check(&c, &a);
+    }
+}
+
+#[test]
+fn test_scalar_bitwise_assign() {
+    let big: BigUint = (BigUint::from(1u32) << 100) + 0x1234_5678_9abc_def0u64;
+
+    let mut x = big.clone();
+    x &= 0xffffu32;
+    assert_eq!(x, BigUint::from(0xdef0u32));
+    let mut x = big.clone();
+    x &= 0xffff_0000_ffff_0000u64;
+    assert_eq!(x, BigUint::from(0x1234_0000_9abc_0000u64));
+
+    let mut x = BigUint::zero();
+    x |= 0x1_0000_0000u64;
+    assert_eq!(x, BigUint::from(1u32) << 32);
+    x |= 1u8;
+    assert_eq!(x, BigUint::from(0x1_0000_0001u64));
+
+    let mut x = big.clone();
+    x ^= 0x1234_5678_9abc_def0u64;
+    assert_eq!(x, BigUint::from(1u32) << 100);
+    x ^= &x.clone();
+    assert!(x.is_zero());
+}
+
+#[test]
+fn test_scalar_signed() {
+    let x = BigUint::from(100u32);
+    let big = BigUint::from(1u32) << 200;
+
+    assert_eq!(&x + -30i8, BigUint::from(70u32));
+    assert_eq!(-30i32 + x.clone(), BigUint::from(70u32));
+    assert_eq!(&x + &30i64, BigUint::from(130u32));
+    assert_eq!(x.clone() - -30isize, BigUint::from(130u32));
+    assert_eq!(&x - &100i16, BigUint::zero());
+    assert_eq!(&x % -7i32, BigUint::from(2u32));
+    assert_eq!(x.clone() % &7i64, BigUint::from(2u32));
+    assert_eq!(&big + i64::MIN - i64::MIN, big);
+    assert_eq!(&big - i32::MIN + i32::MIN, big);
+
+    let mut y = x.clone();
+    y += -1i64;
+    y -= -2isize;
+    assert_eq!(y, BigUint::from(101u32));
+    y %= -7i8;
+    assert_eq!(y, BigUint::from(3u32));
+}
+
+#[test]
+#[cfg(has_i128)]
+fn test_scalar_signed_i128() {
+    let big = BigUint::from(1u32) << 200;
+    assert_eq!(&big + i128::MIN - i128::MIN, big);
+    assert_eq!(&big % -3i128, BigUint::from(1u32));
+}
+
+#[test]
+#[should_panic]
+fn test_scalar_signed_underflow() {
+    let _ = BigUint::from(1u32) + -2i32;
+}
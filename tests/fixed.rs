@@ -0,0 +1,104 @@
+#![cfg(has_const_generics)]
+
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
+
+use num_bigint::{BigUint, FixedBigUint};
+use num_integer::Integer;
+use num_traits::{Num, One, ToPrimitive};
+
+type U64 = FixedBigUint<2>;
+type U256 = FixedBigUint<8>;
+
+const SMALL: [u64; 8] = [0, 1, 2, 7, 0x8000_0000, 0xffff_ffff, 1 << 63, u64::MAX];
+
+fn big_values() -> Vec<BigUint> {
+    let mut values: Vec<BigUint> = SMALL.iter().map(|&x| BigUint::from(x)).collect();
+    for s in &[
+        "10000000000000000",
+        "fedcba9876543210fedcba9876543210",
+        "123456789abcdef0123456789abcdef0123456789abcdef",
+        "8000000000000000000000000000000000000000000000000000000000000000",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    ] {
+        values.push(BigUint::from_str_radix(s, 16).unwrap());
+    }
+    values
+}
+
+#[test]
+fn test_matches_u64() {
+    for &x in &SMALL {
+        for &y in &SMALL {
+            let (fx, fy) = (U64::from(x), U64::from(y));
+            let wrap = |(v, o): (U64, bool)| (v.to_biguint().to_u64().unwrap(), o);
+            assert_eq!(wrap(fx.overflowing_add(&fy)), x.overflowing_add(y));
+            assert_eq!(wrap(fx.overflowing_sub(&fy)), x.overflowing_sub(y));
+            assert_eq!(wrap(fx.overflowing_mul(&fy)), x.overflowing_mul(y));
+            assert_eq!(fx.wrapping_neg(), U64::from(x.wrapping_neg()));
+            assert_eq!(fx.checked_add(&fy), x.checked_add(y).map(U64::from));
+            assert_eq!(fx.checked_sub(&fy), x.checked_sub(y).map(U64::from));
+            assert_eq!(fx.checked_mul(&fy), x.checked_mul(y).map(U64::from));
+            assert_eq!(fx.checked_div(&fy), x.checked_div(y).map(U64::from));
+            assert_eq!(fx.checked_rem(&fy), x.checked_rem(y).map(U64::from));
+            assert_eq!(fx.cmp(&fy), x.cmp(&y));
+        }
+    }
+}
+
+#[test]
+fn test_matches_biguint() {
+    let modulus = BigUint::one() << 256usize;
+    let f = |x: &BigUint| U256::from_biguint(x).unwrap();
+    for x in big_values() {
+        for y in big_values() {
+            let (fx, fy) = (f(&x), f(&y));
+            assert_eq!(fx.wrapping_add(&fy), f(&((&x + &y) % &modulus)));
+            assert_eq!(fx.wrapping_sub(&fy), f(&((&x + &modulus - &y) % &modulus)));
+            assert_eq!(fx.wrapping_mul(&fy), f(&((&x * &y) % &modulus)));
+            assert_eq!(fx.checked_add(&fy).is_none(), &x + &y >= modulus);
+            assert_eq!(fx.checked_mul(&fy).is_none(), &x * &y >= modulus);
+            assert_eq!(fx.checked_sub(&fy).is_none(), x < y);
+            if y.bit_len() != 0 {
+                let (q, r) = x.div_rem(&y);
+                assert_eq!(fx.checked_div_rem(&fy), Some((f(&q), f(&r))));
+            }
+            assert_eq!(fx.cmp(&fy), x.cmp(&y));
+        }
+    }
+}
+
+#[test]
+fn test_conversions() {
+    for x in big_values() {
+        let fx = U256::from_biguint(&x).unwrap();
+        assert_eq!(fx.to_biguint(), x);
+        assert_eq!(BigUint::from(fx), x);
+        assert_eq!(fx.bits() as u64, x.bit_len());
+        assert_eq!(fx.is_zero(), x.bit_len() == 0);
+        assert_eq!(
+            format!("{} {:?} {:x}", fx, fx, fx),
+            format!("{} {:?} {:x}", x, x, x)
+        );
+    }
+
+    let x = BigUint::from_slice_u64(&[1, 2, 3, 4, 5]);
+    assert_eq!(U256::from_biguint(&x), None);
+    assert_eq!(
+        U256::from_biguint_wrapping(&x),
+        U256::from_limbs([1, 0, 2, 0, 3, 0, 4, 0])
+    );
+    assert_eq!(
+        U256::from_limbs([1, 2, 3, 4, 5, 6, 7, 8]).as_limbs(),
+        &[1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(U256::MAX.to_biguint(), (BigUint::one() << 256usize) - 1u32);
+    assert_eq!(U256::default(), U256::ZERO);
+    assert_eq!(FixedBigUint::<0>::from_u64(5), FixedBigUint::<0>::ZERO);
+    assert_eq!(
+        FixedBigUint::<1>::from_u64(0x1_0000_0005),
+        FixedBigUint::<1>::from_u64(5)
+    );
+}
@@ -0,0 +1,86 @@
+#![cfg(has_const_generics)]
+
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
+
+use num_bigint::{BigUint, HeaplessBigUint};
+use num_integer::Integer;
+use num_traits::Num;
+
+type U128 = HeaplessBigUint<4>;
+
+const N1: u32 = !0;
+
+fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::from(0u32),
+        BigUint::from(1u32),
+        BigUint::from(7u32),
+        BigUint::from(N1),
+        BigUint::from_slice(&[0, 1]),
+        BigUint::from_slice(&[N1, N1]),
+        BigUint::from_slice(&[1, 2, 3]),
+        BigUint::from_str_radix("fedcba9876543210fedcba98", 16).unwrap(),
+        BigUint::from_slice(&[5, 0, 0, 1]),
+        BigUint::from_slice(&[N1, N1, N1, N1]),
+    ]
+}
+
+fn h(n: &BigUint) -> Option<U128> {
+    U128::from_biguint(n)
+}
+
+// The expected result of an operation, if it fits.
+fn fit(n: BigUint) -> Option<U128> {
+    h(&n)
+}
+
+#[test]
+fn test_conversions() {
+    for x in values() {
+        let hx = h(&x).unwrap();
+        assert_eq!(hx.to_biguint(), x);
+        assert_eq!(BigUint::from(hx), x);
+        assert_eq!(hx.as_slice(), &x.to_u32_digits()[..]);
+        assert_eq!(hx.bits() as u64, x.bit_len());
+        assert_eq!(hx.is_zero(), x.bit_len() == 0);
+        assert_eq!(format!("{} {:?}", hx, hx), format!("{} {:?}", x, x));
+    }
+    assert_eq!(U128::capacity(), 4);
+    assert_eq!(
+        U128::from_slice(&[1, 2, 0, 0, 0, 0]),
+        U128::from_slice(&[1, 2])
+    );
+    assert_eq!(U128::from_slice(&[1, 2, 3, 4, 5]), None);
+    assert_eq!(h(&(BigUint::from(1u32) << 128usize)), None);
+    assert_eq!(U128::default(), U128::zero());
+}
+
+#[test]
+fn test_arithmetic() {
+    for x in values() {
+        for y in values() {
+            let (hx, hy) = (h(&x).unwrap(), h(&y).unwrap());
+            assert_eq!(hx.cmp(&hy), x.cmp(&y));
+            assert_eq!(hx.checked_add(&hy), fit(&x + &y));
+            assert_eq!(hx.checked_mul(&hy), fit(&x * &y));
+            if x >= y {
+                assert_eq!(hx.checked_sub(&hy), fit(&x - &y));
+            } else {
+                assert_eq!(hx.checked_sub(&hy), None);
+            }
+            if y.bit_len() == 0 {
+                assert_eq!(hx.checked_div_rem(&hy), None);
+            } else {
+                let (q, r) = x.div_rem(&y);
+                assert_eq!(
+                    hx.checked_div_rem(&hy),
+                    Some((fit(q).unwrap(), fit(r).unwrap()))
+                );
+                assert_eq!(hx.checked_div(&hy), fit(&x / &y));
+                assert_eq!(hx.checked_rem(&hy), fit(&x % &y));
+            }
+        }
+    }
+}
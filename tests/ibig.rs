@@ -0,0 +1,63 @@
+//! Test conversions with `ibig`'s `UBig` and `IBig`.
+
+#![cfg(feature = "ibig")]
+
+extern crate ibig;
+extern crate num_bigint;
+
+use ibig::{IBig, UBig};
+use num_bigint::{BigInt, BigUint};
+use std::convert::TryFrom;
+
+const VALUES: [&str; 7] = [
+    "0",
+    "1",
+    "-1",
+    "4294967296",
+    "-18446744073709551616",
+    "340282366920938463463374607431768211455",
+    "-343412345678910111213141516171819202122232425262728293031",
+];
+
+#[test]
+fn test_ibig_round_trip() {
+    for s in &VALUES {
+        let n: BigInt = s.parse().unwrap();
+        let i: IBig = s.parse().unwrap();
+        assert_eq!(IBig::from(&n), i);
+        assert_eq!(BigInt::from(&i), n);
+        assert_eq!(BigInt::from(IBig::from(n.clone())), n);
+    }
+}
+
+#[test]
+fn test_ubig_round_trip() {
+    for s in VALUES.iter().filter(|s| !s.starts_with('-')) {
+        let n: BigUint = s.parse().unwrap();
+        let u: UBig = s.parse().unwrap();
+        assert_eq!(UBig::from(&n), u);
+        assert_eq!(BigUint::from(&u), n);
+        assert_eq!(BigUint::from(UBig::from(n.clone())), n);
+    }
+}
+
+#[test]
+fn test_try_from_signed() {
+    for s in &VALUES {
+        let n: BigInt = s.parse().unwrap();
+        let i: IBig = s.parse().unwrap();
+        if s.starts_with('-') {
+            assert!(BigUint::try_from(&i).is_err());
+            assert_eq!(BigUint::try_from(i.clone()).unwrap_err().into_original(), i);
+            assert!(UBig::try_from(&n).is_err());
+            assert_eq!(UBig::try_from(n.clone()).unwrap_err().into_original(), n);
+        } else {
+            let u: UBig = s.parse().unwrap();
+            let m: BigUint = s.parse().unwrap();
+            assert_eq!(BigUint::try_from(&i).unwrap(), m);
+            assert_eq!(BigUint::try_from(i).unwrap(), m);
+            assert_eq!(UBig::try_from(&n).unwrap(), u);
+            assert_eq!(UBig::try_from(n).unwrap(), u);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Test conversions with `serde_json::Number`.
+
+#![cfg(feature = "serde_json")]
+
+extern crate num_bigint;
+extern crate serde_json;
+
+use num_bigint::{BigInt, BigIntErrorKind, BigUint};
+use serde_json::{Number, Value};
+use std::convert::TryFrom;
+
+const HUGE: &str = "-343412345678910111213141516171819202122232425262728293031";
+
+#[test]
+fn test_number_from_bigint() {
+    let n: BigInt = HUGE.parse().unwrap();
+    let json = serde_json::to_string(&Value::Number(Number::from(&n))).unwrap();
+    assert_eq!(json, HUGE);
+
+    let n: BigUint = HUGE[1..].parse().unwrap();
+    let json = serde_json::to_string(&Value::Number(Number::from(n))).unwrap();
+    assert_eq!(json, &HUGE[1..]);
+}
+
+#[test]
+fn test_bigint_from_number() {
+    let number: Number = serde_json::from_str(HUGE).unwrap();
+    let n = BigInt::try_from(&number).unwrap();
+    assert_eq!(n.to_string(), HUGE);
+    let err = BigUint::try_from(&number).unwrap_err();
+    assert_eq!(err.kind(), BigIntErrorKind::InvalidDigit);
+    assert_eq!(err.position(), Some(0));
+    assert_eq!(err.invalid_char(), Some('-'));
+
+    let number: Number = serde_json::from_str(&HUGE[1..]).unwrap();
+    let n = BigUint::try_from(number).unwrap();
+    assert_eq!(n.to_string(), &HUGE[1..]);
+
+    let number: Number = serde_json::from_str("-0").unwrap();
+    assert_eq!(BigInt::try_from(&number).unwrap(), BigInt::from(0));
+    assert_eq!(BigUint::try_from(&number).unwrap(), BigUint::from(0u32));
+}
+
+#[test]
+fn test_reject_non_integers() {
+    for s in &[
+        "1.5",
+        "1.0",
+        "1e3",
+        "1E3",
+        "-2.5e-3",
+        "10000000000000000000000.0",
+    ] {
+        let number: Number = serde_json::from_str(s).unwrap();
+        let err = BigInt::try_from(&number).unwrap_err();
+        assert_eq!(err.kind(), BigIntErrorKind::Fractional, "{}", s);
+        let err = BigUint::try_from(&number).unwrap_err();
+        assert_eq!(err.kind(), BigIntErrorKind::Fractional, "{}", s);
+    }
+}
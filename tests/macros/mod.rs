@@ -68,3 +68,32 @@ macro_rules! assert_signed_scalar_op {
                           $left $op $right == $expected);
     };
 }
+
+/// Assert that `mul_add` and `mul_add_assign` work for all val/ref combinations
+macro_rules! assert_mul_add {
+    ($x:ident * $y:ident + $z:ident == $expected:expr) => {{
+        use num_traits::{MulAdd, MulAddAssign};
+
+        assert_eq!($x.clone().mul_add($y.clone(), $z.clone()), $expected);
+        assert_eq!($x.clone().mul_add($y.clone(), &$z), $expected);
+        assert_eq!($x.clone().mul_add(&$y, $z.clone()), $expected);
+        assert_eq!($x.clone().mul_add(&$y, &$z), $expected);
+        assert_eq!((&$x).mul_add($y.clone(), $z.clone()), $expected);
+        assert_eq!((&$x).mul_add($y.clone(), &$z), $expected);
+        assert_eq!((&$x).mul_add(&$y, $z.clone()), $expected);
+        assert_eq!((&$x).mul_add(&$y, &$z), $expected);
+
+        let mut x = $x.clone();
+        x.mul_add_assign($y.clone(), $z.clone());
+        assert_eq!(x, $expected);
+        let mut x = $x.clone();
+        x.mul_add_assign($y.clone(), &$z);
+        assert_eq!(x, $expected);
+        let mut x = $x.clone();
+        x.mul_add_assign(&$y, $z.clone());
+        assert_eq!(x, $expected);
+        let mut x = $x.clone();
+        x.mul_add_assign(&$y, &$z);
+        assert_eq!(x, $expected);
+    }};
+}
@@ -0,0 +1,92 @@
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
+
+use num_bigint::{BigUint, ModInt};
+use num_integer::Integer;
+use num_traits::{Num, One, Zero};
+use std::sync::Arc;
+
+fn moduli() -> Vec<BigUint> {
+    vec![
+        BigUint::from(1u32),
+        BigUint::from(2u32),
+        BigUint::from(12u32),
+        BigUint::from(1_000_000_007u32),
+        BigUint::from_str_radix("fffffffffffffffffffffffffffffffeffffffffffffffff", 16).unwrap(),
+        BigUint::from(1u32) << 96usize,
+    ]
+}
+
+fn values(m: &BigUint) -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(5u32),
+        m - 1u32,
+        m + 3u32,
+        m * m * 7u32 + 11u32,
+    ]
+}
+
+#[test]
+fn test_arithmetic() {
+    for m in moduli() {
+        let shared = Arc::new(m.clone());
+        for x in values(&m) {
+            let a = ModInt::new(x.clone(), shared.clone());
+            assert_eq!(*a.value(), &x % &m);
+            assert_eq!(a.modulus(), &m);
+            assert_eq!(*(-&a).value(), (&m - &x % &m) % &m);
+            for y in values(&m) {
+                let b = a.with_value(y.clone());
+                assert_eq!(*(&a + &b).value(), (&x + &y) % &m);
+                assert_eq!(*(&a * &b).value(), (&x * &y) % &m);
+                assert_eq!(&a - &b + &b, a);
+                assert_eq!(
+                    (a.clone() - b.clone()).into_value(),
+                    (&x + &m * &y - &y) % &m
+                );
+
+                let mut c = a.clone();
+                c += &b;
+                c *= b.clone();
+                c -= &a;
+                assert_eq!(c, (&a + &b) * &b - &a);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pow_inverse() {
+    for m in moduli() {
+        let shared = Arc::new(m.clone());
+        for x in values(&m) {
+            let a = ModInt::new(x.clone(), shared.clone());
+            let e = BigUint::from(65537u32);
+            assert_eq!(*a.pow(&e).value(), x.modpow(&e, &m));
+            match a.inverse() {
+                Some(inv) => {
+                    assert!((&a * &inv).value() == &(BigUint::one() % &m));
+                    assert!(inv.value() < &m);
+                }
+                None => assert!(!x.gcd(&m).is_one()),
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "different moduli")]
+fn test_mismatched_moduli() {
+    let a = ModInt::new(BigUint::from(1u32), Arc::new(BigUint::from(5u32)));
+    let b = ModInt::new(BigUint::from(1u32), Arc::new(BigUint::from(7u32)));
+    let _ = a + b;
+}
+
+#[test]
+#[should_panic(expected = "must not be zero")]
+fn test_zero_modulus() {
+    ModInt::new(BigUint::from(1u32), Arc::new(BigUint::zero()));
+}
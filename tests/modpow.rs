@@ -57,7 +57,7 @@ static BIG_R: &'static str = "\
                               109c4735_6e7db425_7b5d74c7_0b709508";
 
 mod biguint {
-    use num_bigint::BigUint;
+    use num_bigint::{BigUint, OddBigUint};
     use num_integer::Integer;
     use num_traits::Num;
 
@@ -69,7 +69,12 @@ mod biguint {
 
         assert_eq!(b.modpow(&e, &m), r);
 
+        let odd_m = OddBigUint::new(m.clone()).unwrap();
+        assert_eq!(b.modpow_odd(&e, &odd_m), r);
+        assert_eq!(b.modpow_ct(&e, &odd_m), r);
+
         let even_m = &m << 1;
+        assert!(OddBigUint::new(even_m.clone()).is_none());
         let even_modpow = b.modpow(&e, &even_m);
         assert!(even_modpow < even_m);
         assert_eq!(even_modpow.mod_floor(&m), r);
@@ -83,6 +88,22 @@ mod biguint {
         check_modpow::<u32>(5, 117, 19, 1);
     }
 
+    #[test]
+    fn test_modpow_ct() {
+        let m = OddBigUint::new(BigUint::from(1u32)).unwrap();
+        assert_eq!(
+            BigUint::from(5u32).modpow_ct(&BigUint::from(3u32), &m),
+            BigUint::from(0u32)
+        );
+
+        let m = OddBigUint::new(BigUint::from_slice(&[1, 0, 0, 1])).unwrap();
+        let b = BigUint::from_slice(&[7, 8, 9, 10, 11]);
+        for e in &[0u32, 1, 2, 15, 16, 17, 0xffff_ffff] {
+            let e = BigUint::from(*e);
+            assert_eq!(b.modpow_ct(&e, &m), b.modpow(&e, m.get()));
+        }
+    }
+
     #[test]
     fn test_modpow_big() {
         let b = BigUint::from_str_radix(super::BIG_B, 16).unwrap();
@@ -92,6 +113,11 @@ mod biguint {
 
         assert_eq!(b.modpow(&e, &m), r);
 
+        let odd_m = OddBigUint::new(m.clone()).unwrap();
+        assert_eq!(b.modpow_odd(&e, &odd_m), r);
+        assert_eq!(b.modpow_ct(&e, &odd_m), r);
+        assert_eq!((&b + &m).modpow_ct(&e, &odd_m), r);
+
         let even_m = &m << 1;
         let even_modpow = b.modpow(&e, &even_m);
         assert!(even_modpow < even_m);
@@ -148,3 +174,64 @@ mod bigint {
         check_modpow(b, e, m, r);
     }
 }
+
+#[cfg(has_const_generics)]
+mod heapless {
+    use num_bigint::{BigUint, HeaplessBigUint};
+    use num_traits::Num;
+
+    type U2112 = HeaplessBigUint<66>;
+
+    fn h(n: &BigUint) -> U2112 {
+        U2112::from_biguint(n).unwrap()
+    }
+
+    #[test]
+    fn test_modpow() {
+        for &(b, e, m, r) in &[
+            (1u32, 0u32, 11u32, 1u32),
+            (0, 15, 11, 0),
+            (3, 7, 11, 9),
+            (5, 117, 19, 1),
+        ] {
+            let (b, e, m, r) = (
+                BigUint::from(b),
+                BigUint::from(e),
+                BigUint::from(m),
+                BigUint::from(r),
+            );
+            assert_eq!(h(&b).modpow(&h(&e), &h(&m)), Some(h(&r)));
+
+            let even_m = &m << 1usize;
+            assert_eq!(
+                h(&b).modpow(&h(&e), &h(&even_m)),
+                Some(h(&b.modpow(&e, &even_m)))
+            );
+        }
+        assert_eq!(
+            U2112::from_slice(&[3])
+                .unwrap()
+                .modpow(&U2112::zero(), &U2112::zero()),
+            None
+        );
+        let one = U2112::from_slice(&[1]).unwrap();
+        assert_eq!(one.modpow(&one, &one), Some(U2112::zero()));
+    }
+
+    #[test]
+    fn test_modpow_big() {
+        let b = BigUint::from_str_radix(super::BIG_B, 16).unwrap();
+        let e = BigUint::from_str_radix(super::BIG_E, 16).unwrap();
+        let m = BigUint::from_str_radix(super::BIG_M, 16).unwrap();
+        let r = BigUint::from_str_radix(super::BIG_R, 16).unwrap();
+
+        assert_eq!(h(&b).modpow(&h(&e), &h(&m)), Some(h(&r)));
+
+        let short_e = BigUint::from_str_radix("1234567890abcdef", 16).unwrap();
+        let even_m = &m << 1usize;
+        assert_eq!(
+            h(&b).modpow(&h(&short_e), &h(&even_m)),
+            Some(h(&b.modpow(&short_e, &even_m)))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Check that `BigInt` and `BigUint` can be used with `diesel` and `sqlx`.
+//! The wire format itself is tested inside the crate.
+
+extern crate num_bigint;
+
+#[cfg(feature = "diesel")]
+mod diesel_tests {
+    extern crate diesel;
+
+    use self::diesel::deserialize::{FromSql, FromSqlRow};
+    use self::diesel::dsl::sql;
+    use self::diesel::expression::AsExpression;
+    use self::diesel::pg::Pg;
+    use self::diesel::serialize::ToSql;
+    use self::diesel::sql_types::{Nullable, Numeric};
+    use num_bigint::{BigInt, BigUint};
+
+    fn assert_numeric<T>()
+    where
+        T: ToSql<Numeric, Pg>
+            + FromSql<Numeric, Pg>
+            + FromSqlRow<Numeric, Pg>
+            + AsExpression<Numeric>
+            + AsExpression<Nullable<Numeric>>,
+        for<'a> &'a T: AsExpression<Numeric>,
+    {
+    }
+
+    #[test]
+    fn test_diesel_traits() {
+        assert_numeric::<BigInt>();
+        assert_numeric::<BigUint>();
+
+        let query = diesel::select(sql::<Numeric>("").bind::<Numeric, _>(BigInt::from(-7)));
+        let debug = diesel::debug_query::<Pg, _>(&query).to_string();
+        assert!(debug.contains("binds: [BigInt"), "{}", debug);
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_tests {
+    extern crate sqlx;
+
+    use self::sqlx::postgres::types::Oid;
+    use self::sqlx::postgres::{PgHasArrayType, Postgres};
+    use self::sqlx::{Decode, Encode, Type};
+    use num_bigint::{BigInt, BigUint};
+
+    fn assert_numeric<T>()
+    where
+        T: Type<Postgres> + PgHasArrayType + for<'a> Encode<'a, Postgres>,
+        T: for<'a> Decode<'a, Postgres>,
+    {
+        assert_eq!(T::type_info().oid(), Some(Oid(1700)));
+        assert_eq!(T::array_type_info().oid(), Some(Oid(1231)));
+    }
+
+    #[test]
+    fn test_sqlx_traits() {
+        assert_numeric::<BigInt>();
+        assert_numeric::<BigUint>();
+    }
+}
@@ -0,0 +1,45 @@
+//! Test the `proptest` strategies for `BigUint` and `BigInt`.
+
+#![cfg(feature = "proptest")]
+
+extern crate num_bigint;
+#[macro_use]
+extern crate proptest;
+
+use num_bigint::proptest_strategies::{bigint, biguint};
+use num_bigint::{BigInt, BigUint, Sign};
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+proptest! {
+    #[test]
+    fn biguint_is_bounded(n in biguint(4)) {
+        prop_assert!(n.bit_len() <= 128);
+    }
+
+    #[test]
+    fn bigint_is_bounded(i in bigint(4)) {
+        prop_assert!(i.bit_len() <= 128);
+    }
+
+    #[test]
+    fn any_roundtrips(n in any::<BigUint>(), i in any::<BigInt>()) {
+        prop_assert_eq!(BigUint::from_bytes_le(&n.to_bytes_le()), n);
+        prop_assert_eq!(BigInt::from_signed_bytes_le(&i.to_signed_bytes_le()), i);
+    }
+}
+
+#[test]
+fn shrinks_to_zero() {
+    let mut runner = TestRunner::deterministic();
+    for _ in 0..16 {
+        let mut tree = biguint(8).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), BigUint::from(0u32));
+
+        let mut tree = bigint(8).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current().sign(), Sign::NoSign);
+    }
+}
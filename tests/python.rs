@@ -0,0 +1,76 @@
+//! Test conversions with Python's `int` through `pyo3`.
+
+#![cfg(feature = "pyo3")]
+
+extern crate num_bigint;
+extern crate pyo3;
+
+use num_bigint::{BigInt, BigUint};
+use pyo3::exceptions::{PyOverflowError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+use std::ffi::CString;
+
+const VALUES: [&str; 9] = [
+    "0",
+    "1",
+    "-1",
+    "127",
+    "128",
+    "-128",
+    "-129",
+    "18446744073709551616",
+    "-343412345678910111213141516171819202122232425262728293031",
+];
+
+fn with_python<F: FnOnce(Python)>(f: F) {
+    Python::initialize();
+    Python::attach(f)
+}
+
+#[test]
+fn test_bigint_round_trip() {
+    with_python(|py| {
+        for s in &VALUES {
+            let n: BigInt = s.parse().unwrap();
+            let int = (&n).into_pyobject(py).unwrap();
+            assert_eq!(int.str().unwrap().to_string(), *s);
+            assert_eq!(int.extract::<BigInt>().unwrap(), n);
+
+            let code = CString::new(format!("int('{}')", s)).unwrap();
+            let int = py.eval(&code, None, None).unwrap();
+            assert_eq!(int.extract::<BigInt>().unwrap(), n);
+        }
+    });
+}
+
+#[test]
+fn test_biguint_round_trip() {
+    with_python(|py| {
+        for s in VALUES.iter().filter(|s| !s.starts_with('-')) {
+            let n: BigUint = s.parse().unwrap();
+            let int = n.clone().into_pyobject(py).unwrap();
+            assert_eq!(int.str().unwrap().to_string(), *s);
+            assert_eq!(int.extract::<BigUint>().unwrap(), n);
+        }
+    });
+}
+
+#[test]
+fn test_extract_errors() {
+    with_python(|py| {
+        let neg = (-1i32).into_pyobject(py).unwrap();
+        let err = neg.extract::<BigUint>().unwrap_err();
+        assert!(err.is_instance_of::<PyOverflowError>(py));
+
+        let float = 1.5f64.into_pyobject(py).unwrap();
+        let err = float.extract::<BigInt>().unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+
+        // `__index__` is honored, as with Python's own integer conversions
+        let t = true.into_pyobject(py).unwrap();
+        assert_eq!(t.extract::<BigInt>().unwrap(), BigInt::from(1));
+        let int: Bound<PyInt> = BigInt::from(5).into_pyobject(py).unwrap();
+        assert_eq!(int.extract::<i32>().unwrap(), 5);
+    });
+}
@@ -315,3 +315,16 @@ fn quicktest_shift() {
     qc.quickcheck(test_shl_unsigned as fn(u32, u8) -> TestResult);
     qc.quickcheck(test_shl_signed as fn(i32, u8) -> TestResult);
 }
+
+#[test]
+fn quickcheck_shrink_toward_zero() {
+    use quickcheck::Arbitrary;
+
+    let n = BigUint::new(vec![1, 2, 3]);
+    assert!(n.shrink().all(|x| x < n));
+
+    let i = BigInt::from(-12345);
+    let shrunk: Vec<BigInt> = i.shrink().collect();
+    assert_eq!(shrunk[0], BigInt::from(12345));
+    assert!(shrunk.iter().all(|x| x.bit_len() <= i.bit_len()));
+}
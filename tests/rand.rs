@@ -1,12 +1,14 @@
 #![cfg(feature = "rand")]
 
 extern crate num_bigint;
+extern crate num_integer;
 extern crate num_traits;
 extern crate rand;
 
 mod biguint {
     use num_bigint::{BigUint, RandBigInt, RandomBits};
-    use num_traits::Zero;
+    use num_integer::Integer;
+    use num_traits::{ToPrimitive, Zero};
     use rand::distributions::Uniform;
     use rand::thread_rng;
     use rand::{Rng, SeedableRng};
@@ -15,15 +17,91 @@ mod biguint {
     fn test_rand() {
         let mut rng = thread_rng();
         let n: BigUint = rng.gen_biguint(137);
-        assert!(n.bits() <= 137);
+        assert!(n.bit_len() <= 137);
         assert!(rng.gen_biguint(0).is_zero());
     }
 
+    #[test]
+    fn test_rand_exact_bits() {
+        let mut rng = thread_rng();
+        for &bits in &[1, 2, 31, 32, 33, 64, 137] {
+            for _ in 0..100 {
+                assert_eq!(rng.gen_biguint_exact_bits(bits).bit_len(), bits as u64);
+            }
+        }
+        assert_eq!(rng.gen_biguint_exact_bits(1), BigUint::from(1u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rand_exact_zero_bits() {
+        thread_rng().gen_biguint_exact_bits(0);
+    }
+
+    #[test]
+    fn test_rand_odd() {
+        let mut rng = thread_rng();
+        assert_eq!(rng.gen_biguint_odd(1), BigUint::from(1u32));
+        for _ in 0..100 {
+            let n = rng.gen_biguint_odd(137);
+            assert!(n.is_odd());
+            assert!(n.bit_len() <= 137);
+        }
+    }
+
+    #[test]
+    fn test_rand_congruent() {
+        let mut rng = thread_rng();
+        let m = BigUint::from(1_000_003u32);
+        let r = BigUint::from(12_345u32);
+        for _ in 0..100 {
+            let n = rng.gen_biguint_congruent(137, &r, &m);
+            assert_eq!(&n % &m, r);
+            assert!(n.bit_len() <= 137);
+
+            // A residue given larger than the modulus is reduced first.
+            let n = rng.gen_biguint_congruent(40, &(&r + &m * 7u32), &m);
+            assert_eq!(&n % &m, r);
+        }
+
+        // Only 5 and 12 are below 16 and congruent to 5 mod 7.
+        let mut seen = [false; 16];
+        for _ in 0..100 {
+            let n = rng.gen_biguint_congruent(4, &BigUint::from(5u32), &BigUint::from(7u32));
+            seen[n.to_usize().unwrap()] = true;
+        }
+        let hits: Vec<_> = (0..16).filter(|&i| seen[i]).collect();
+        assert_eq!(hits, [5, 12]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rand_congruent_too_small() {
+        let m = BigUint::from(100u32);
+        thread_rng().gen_biguint_congruent(4, &BigUint::from(20u32), &m);
+    }
+
+    #[test]
+    fn test_rand_dyn() {
+        use rand::RngCore;
+
+        fn below(rng: &mut RngCore, bound: &BigUint) -> BigUint {
+            rng.gen_biguint_below(bound)
+        }
+
+        let mut rng = thread_rng();
+        let bound = BigUint::from(1000u32);
+        assert!(below(&mut rng, &bound) < bound);
+
+        let rng: &mut RngCore = &mut rng;
+        assert!(rng.gen_biguint(137).bit_len() <= 137);
+    }
+
     #[test]
     fn test_rand_bits() {
         let mut rng = thread_rng();
         let n: BigUint = rng.sample(&RandomBits::new(137));
-        assert!(n.bits() <= 137);
+        assert!(n.bit_len() <= 137);
         let z: BigUint = rng.sample(&RandomBits::new(0));
         assert!(z.is_zero());
     }
@@ -51,6 +129,30 @@ mod biguint {
         }
     }
 
+    #[test]
+    fn test_rand_range_inclusive() {
+        let mut rng = thread_rng();
+        let seven = BigUint::from(7u32);
+        for _ in 0..10 {
+            assert_eq!(rng.gen_biguint_range_inclusive(&seven, &seven), seven);
+        }
+
+        let (l, u) = (BigUint::from(5u32), BigUint::from(8u32));
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            let n = rng.gen_biguint_range_inclusive(&l, &u);
+            assert!(l <= n && n <= u);
+            seen[(n - &l).to_usize().unwrap()] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_rand_range_inclusive() {
+        thread_rng().gen_biguint_range_inclusive(&BigUint::from(8u32), &BigUint::from(7u32));
+    }
+
     #[test]
     #[should_panic]
     fn test_zero_rand_range() {
@@ -163,7 +265,7 @@ mod biguint {
 
 mod bigint {
     use num_bigint::{BigInt, RandBigInt, RandomBits};
-    use num_traits::Zero;
+    use num_traits::{ToPrimitive, Zero};
     use rand::distributions::Uniform;
     use rand::thread_rng;
     use rand::{Rng, SeedableRng};
@@ -172,7 +274,7 @@ mod bigint {
     fn test_rand() {
         let mut rng = thread_rng();
         let n: BigInt = rng.gen_bigint(137);
-        assert!(n.bits() <= 137);
+        assert!(n.bit_len() <= 137);
         assert!(rng.gen_bigint(0).is_zero());
     }
 
@@ -180,7 +282,7 @@ mod bigint {
     fn test_rand_bits() {
         let mut rng = thread_rng();
         let n: BigInt = rng.sample(&RandomBits::new(137));
-        assert!(n.bits() <= 137);
+        assert!(n.bit_len() <= 137);
         let z: BigInt = rng.sample(&RandomBits::new(0));
         assert!(z.is_zero());
     }
@@ -211,6 +313,24 @@ mod bigint {
         check(-u.clone(), -l.clone());
     }
 
+    #[test]
+    fn test_rand_range_inclusive() {
+        let mut rng = thread_rng();
+        let x = BigInt::from(-7);
+        for _ in 0..10 {
+            assert_eq!(rng.gen_bigint_range_inclusive(&x, &x), x);
+        }
+
+        let (l, u) = (BigInt::from(-2), BigInt::from(1));
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            let n = rng.gen_bigint_range_inclusive(&l, &u);
+            assert!(l <= n && n <= u);
+            seen[(n - &l).to_usize().unwrap()] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+    }
+
     #[test]
     #[should_panic]
     fn test_zero_rand_range() {
@@ -322,3 +442,91 @@ mod bigint {
         seeded_value_stability::<XorShiftRng>(EXPECTED);
     }
 }
+
+#[cfg(feature = "rand-08")]
+mod compat08 {
+    extern crate rand_core_06;
+
+    use self::rand_core_06::{impls, Error, RngCore};
+    use num_bigint::{BigUint, RandBigInt, RngCompat08};
+
+    struct SplitMix(u64);
+
+    impl RngCore for SplitMix {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_compat() {
+        let mut inner = SplitMix(42);
+        let mut rng = RngCompat08(&mut inner);
+        assert!(rng.gen_biguint(137).bit_len() <= 137);
+        let bound = BigUint::from(1000u32);
+        assert!(rng.gen_biguint_below(&bound) < bound);
+
+        // The same seed gives the same values through the adapter.
+        let a = RngCompat08(SplitMix(7)).gen_biguint(200);
+        let b = RngCompat08(SplitMix(7)).gen_biguint(200);
+        assert_eq!(a, b);
+    }
+}
+
+#[cfg(feature = "rand-09")]
+mod compat09 {
+    extern crate rand_core_09;
+
+    use self::rand_core_09::{impls, RngCore};
+    use num_bigint::{BigUint, RandBigInt, RngCompat09};
+
+    struct SplitMix(u64);
+
+    impl RngCore for SplitMix {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+    }
+
+    #[test]
+    fn test_compat() {
+        let mut inner = SplitMix(42);
+        let mut rng = RngCompat09(&mut inner);
+        assert!(rng.gen_biguint(137).bit_len() <= 137);
+        let bound = BigUint::from(1000u32);
+        assert!(rng.gen_biguint_below(&bound) < bound);
+
+        let a = RngCompat09(SplitMix(7)).gen_biguint(200);
+        let b = RngCompat09(SplitMix(7)).gen_biguint(200);
+        assert_eq!(a, b);
+    }
+}
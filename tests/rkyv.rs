@@ -0,0 +1,95 @@
+//! Test zero-copy archiving of `BigUint` and `BigInt` with `rkyv`.
+
+#![cfg(feature = "rkyv")]
+
+extern crate num_bigint;
+extern crate num_traits;
+extern crate rkyv;
+
+use num_bigint::{ArchivedBigInt, ArchivedBigUint, BigInt, BigUint, Sign};
+use num_traits::{One, Zero};
+use rkyv::rancor::Error;
+
+fn values() -> Vec<BigInt> {
+    let f100: BigInt = (1i8..101).product();
+    vec![
+        BigInt::zero(),
+        BigInt::one(),
+        -BigInt::one(),
+        BigInt::from(u32::max_value()),
+        BigInt::one() << 64,
+        -(BigInt::one() << 64),
+        f100.clone(),
+        -f100,
+    ]
+}
+
+#[test]
+fn biguint_round_trip() {
+    for x in values() {
+        let n = match x.to_biguint() {
+            Some(n) => n,
+            None => continue,
+        };
+        let bytes = rkyv::to_bytes::<Error>(&n).unwrap();
+        let archived = rkyv::access::<ArchivedBigUint, Error>(&bytes).unwrap();
+        assert_eq!(*archived, n);
+        assert_eq!(archived.to_biguint(), n);
+        assert_eq!(archived.bits() as u64, n.bit_len());
+        assert_eq!(archived.is_zero(), n.is_zero());
+        assert_eq!(archived.to_string(), n.to_string());
+
+        let de: BigUint = rkyv::deserialize::<BigUint, Error>(archived).unwrap();
+        assert_eq!(de, n);
+    }
+}
+
+#[test]
+fn bigint_round_trip() {
+    for n in values() {
+        let bytes = rkyv::to_bytes::<Error>(&n).unwrap();
+        let archived = rkyv::access::<ArchivedBigInt, Error>(&bytes).unwrap();
+        assert_eq!(*archived, n);
+        assert_eq!(archived.sign(), n.sign());
+        assert_eq!(archived.to_bigint(), n);
+        assert_eq!(archived.to_string(), n.to_string());
+
+        let de: BigInt = rkyv::deserialize::<BigInt, Error>(archived).unwrap();
+        assert_eq!(de, n);
+    }
+}
+
+#[test]
+fn archived_ordering() {
+    let values = values();
+    let archives: Vec<_> = values
+        .iter()
+        .map(|n| rkyv::to_bytes::<Error>(n).unwrap())
+        .collect();
+    for (a, abytes) in values.iter().zip(&archives) {
+        let aa = rkyv::access::<ArchivedBigInt, Error>(abytes).unwrap();
+        for (b, bbytes) in values.iter().zip(&archives) {
+            let ab = rkyv::access::<ArchivedBigInt, Error>(bbytes).unwrap();
+            assert_eq!(aa.cmp(ab), a.cmp(b));
+            assert_eq!(aa.partial_cmp(b), Some(a.cmp(b)));
+        }
+    }
+}
+
+#[test]
+fn reject_invalid_archives() {
+    // Same layout as an archived `BigUint`, but not normalized.
+    let digits: Vec<u32> = vec![1, 0];
+    let bytes = rkyv::to_bytes::<Error>(&digits).unwrap();
+    assert!(rkyv::access::<ArchivedBigUint, Error>(&bytes).is_err());
+
+    // Same layout as an archived `BigInt`, but with inconsistent signs.
+    let bytes = rkyv::to_bytes::<Error>(&(2i8, vec![1u32])).unwrap();
+    assert!(rkyv::access::<ArchivedBigInt, Error>(&bytes).is_err());
+    let bytes = rkyv::to_bytes::<Error>(&(1i8, Vec::<u32>::new())).unwrap();
+    assert!(rkyv::access::<ArchivedBigInt, Error>(&bytes).is_err());
+    let bytes = rkyv::to_bytes::<Error>(&(-1i8, vec![1u32])).unwrap();
+    let archived = rkyv::access::<ArchivedBigInt, Error>(&bytes).unwrap();
+    assert_eq!(archived.sign(), Sign::Minus);
+    assert_eq!(*archived, -BigInt::one());
+}
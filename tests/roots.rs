@@ -96,7 +96,7 @@ mod biguint {
         }
 
         // degenerate cases should return quickly
-        assert!(x.nth_root(x.bits() as u32).is_one());
+        assert!(x.nth_root(x.bit_len() as u32).is_one());
         assert!(x.nth_root(i32::MAX as u32).is_one());
         assert!(x.nth_root(u32::MAX).is_one());
     }
@@ -184,3 +184,31 @@ mod bigint {
         check(-8, 3);
     }
 }
+
+mod generic {
+    use num_bigint::{BigInt, BigUint};
+    use num_integer::Roots;
+    use std::{i64, u64};
+
+    fn roots<T: Roots>(x: &T) -> (T, T, T) {
+        (x.sqrt(), x.cbrt(), x.nth_root(5))
+    }
+
+    #[test]
+    fn test_roots_trait() {
+        for &x in &[0u64, 1, 31, 32, 1 << 40, u64::MAX] {
+            let (s, c, r) = roots(&x);
+            let expected = (BigUint::from(s), BigUint::from(c), BigUint::from(r));
+            assert_eq!(roots(&BigUint::from(x)), expected);
+        }
+        for &x in &[0i64, 1, 31, 32, 1 << 40, i64::MAX] {
+            let (s, c, r) = roots(&x);
+            let expected = (BigInt::from(s), BigInt::from(c), BigInt::from(r));
+            assert_eq!(roots(&BigInt::from(x)), expected);
+
+            let neg = BigInt::from(-x);
+            assert_eq!(Roots::cbrt(&neg), BigInt::from((-x).cbrt()));
+            assert_eq!(Roots::nth_root(&neg, 5), BigInt::from((-x).nth_root(5)));
+        }
+    }
+}
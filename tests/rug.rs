@@ -0,0 +1,51 @@
+//! Test conversions with `rug`'s `Integer`.
+
+#![cfg(feature = "rug")]
+
+extern crate num_bigint;
+extern crate rug;
+
+use num_bigint::{BigInt, BigUint};
+use rug::Integer;
+use std::convert::TryFrom;
+
+const VALUES: [&str; 7] = [
+    "0",
+    "1",
+    "-1",
+    "4294967296",
+    "-18446744073709551616",
+    "340282366920938463463374607431768211455",
+    "-343412345678910111213141516171819202122232425262728293031",
+];
+
+#[test]
+fn test_integer_round_trip() {
+    for s in &VALUES {
+        let n: BigInt = s.parse().unwrap();
+        let i: Integer = s.parse().unwrap();
+        assert_eq!(Integer::from(&n), i);
+        assert_eq!(BigInt::from(&i), n);
+        assert_eq!(BigInt::from(Integer::from(n.clone())), n);
+    }
+}
+
+#[test]
+fn test_unsigned_round_trip() {
+    for s in VALUES.iter().filter(|s| !s.starts_with('-')) {
+        let n: BigUint = s.parse().unwrap();
+        let i: Integer = s.parse().unwrap();
+        assert_eq!(Integer::from(&n), i);
+        assert_eq!(BigUint::try_from(&i).unwrap(), n);
+        assert_eq!(BigUint::try_from(Integer::from(n.clone())).unwrap(), n);
+    }
+}
+
+#[test]
+fn test_try_from_negative() {
+    for s in VALUES.iter().filter(|s| s.starts_with('-')) {
+        let i: Integer = s.parse().unwrap();
+        assert!(BigUint::try_from(&i).is_err());
+        assert_eq!(BigUint::try_from(i.clone()).unwrap_err().into_original(), i);
+    }
+}
@@ -8,11 +8,13 @@
 
 extern crate num_bigint;
 extern crate num_traits;
+extern crate serde;
 extern crate serde_test;
 
+use num_bigint::serde_helpers;
 use num_bigint::{BigInt, BigUint};
 use num_traits::{One, Zero};
-use serde_test::{assert_tokens, Token};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
 
 #[test]
 fn biguint_zero() {
@@ -101,3 +103,125 @@ fn bigint_factorial_100() {
 
     assert_tokens(&n, &tokens);
 }
+
+#[test]
+fn biguint_odd_digit_count() {
+    // 2^64 takes an odd number of `u32` digits, so it would be padded if the
+    // format followed a 64-bit internal representation.
+    let n = BigUint::one() << 64;
+    let tokens = [
+        Token::Seq { len: Some(3) },
+        Token::U32(0),
+        Token::U32(0),
+        Token::U32(1),
+        Token::SeqEnd,
+    ];
+    assert_tokens(&n, &tokens);
+}
+
+#[test]
+fn biguint_trailing_zeros() {
+    let tokens = [
+        Token::Seq { len: Some(4) },
+        Token::U32(7),
+        Token::U32(1),
+        Token::U32(0),
+        Token::U32(0),
+        Token::SeqEnd,
+    ];
+    assert_de_tokens(&BigUint::from(0x1_0000_0007u64), &tokens);
+}
+
+#[test]
+fn bigint_negative_odd_digit_count() {
+    let n = -(BigInt::one() << 64);
+    let tokens = [
+        Token::Tuple { len: 2 },
+        Token::I8(-1),
+        Token::Seq { len: Some(3) },
+        Token::U32(0),
+        Token::U32(0),
+        Token::U32(1),
+        Token::SeqEnd,
+        Token::TupleEnd,
+    ];
+    assert_tokens(&n, &tokens);
+}
+
+#[test]
+fn bigint_zero_with_sign() {
+    let tokens = [
+        Token::Tuple { len: 2 },
+        Token::I8(1),
+        Token::Seq { len: Some(1) },
+        Token::U32(0),
+        Token::SeqEnd,
+        Token::TupleEnd,
+    ];
+    assert_de_tokens(&BigInt::zero(), &tokens);
+}
+
+macro_rules! with_wrapper {
+    ($name:ident, $T:ty, $module:path) => {
+        #[derive(Debug, PartialEq)]
+        struct $name($T);
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use $module as m;
+                m::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use $module as m;
+                m::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+with_wrapper!(DecimalUint, BigUint, serde_helpers::decimal);
+with_wrapper!(DecimalInt, BigInt, serde_helpers::decimal);
+with_wrapper!(BytesUint, BigUint, serde_helpers::bytes);
+with_wrapper!(BytesInt, BigInt, serde_helpers::bytes);
+
+#[test]
+fn decimal_helpers() {
+    assert_tokens(&DecimalUint(BigUint::zero()), &[Token::Str("0")]);
+    assert_tokens(&DecimalInt(-BigInt::one()), &[Token::Str("-1")]);
+
+    let n = BigUint::one() << 100;
+    let s = "1267650600228229401496703205376";
+    assert_tokens(&DecimalUint(n.clone()), &[Token::Str(s)]);
+    assert_de_tokens(&DecimalUint(n), &[Token::String(s)]);
+
+    assert_de_tokens_error::<DecimalUint>(
+        &[Token::Str("-1")],
+        "invalid value: string \"-1\", expected a decimal integer string",
+    );
+}
+
+#[test]
+fn bytes_helpers() {
+    assert_tokens(&BytesUint(BigUint::zero()), &[Token::Bytes(&[0])]);
+    assert_tokens(
+        &BytesUint(BigUint::from(0x1234u32)),
+        &[Token::Bytes(&[0x34, 0x12])],
+    );
+    assert_tokens(
+        &BytesInt(BigInt::from(-129)),
+        &[Token::Bytes(&[0x7f, 0xff])],
+    );
+    assert_tokens(&BytesInt(BigInt::from(128)), &[Token::Bytes(&[0x80, 0x00])]);
+
+    assert_de_tokens(
+        &BytesUint(BigUint::from(0x1234u32)),
+        &[Token::ByteBuf(&[0x34, 0x12])],
+    );
+    assert_de_tokens(
+        &BytesInt(BigInt::from(-1)),
+        &[Token::Seq { len: Some(1) }, Token::U8(0xff), Token::SeqEnd],
+    );
+}
@@ -0,0 +1,72 @@
+extern crate num_bigint;
+extern crate num_traits;
+
+use num_bigint::{BigInt, BigUint, WrappingBigUint};
+use num_traits::{One, Zero};
+
+const WIDTHS: [usize; 6] = [0, 1, 7, 32, 65, 256];
+
+fn values(bits: usize) -> Vec<BigUint> {
+    let one = BigUint::one();
+    vec![
+        BigUint::zero(),
+        one.clone(),
+        BigUint::from(0x1234_5678u32),
+        (&one << bits) - 1u32,
+        &one << bits,
+        (&one << (bits + 40)) + 99u32,
+    ]
+}
+
+#[test]
+fn test_matches_u8() {
+    for x in 0..256u32 {
+        for &y in &[0u8, 1, 2, 7, 0x80, 0xff] {
+            let (a, b) = (x as u8, y);
+            let wa = WrappingBigUint::new(BigUint::from(x), 8);
+            let wb = wa.with_value(BigUint::from(y));
+            let v = |w: WrappingBigUint| w.into_value();
+            assert_eq!(v(&wa + &wb), BigUint::from(a.wrapping_add(b)));
+            assert_eq!(v(&wa - &wb), BigUint::from(a.wrapping_sub(b)));
+            assert_eq!(v(&wa * &wb), BigUint::from(a.wrapping_mul(b)));
+            assert_eq!(v(&wa & &wb), BigUint::from(a & b));
+            assert_eq!(v(&wa | &wb), BigUint::from(a | b));
+            assert_eq!(v(&wa ^ &wb), BigUint::from(a ^ b));
+            assert_eq!(v(-&wa), BigUint::from(a.wrapping_neg()));
+            assert_eq!(v(!&wa), BigUint::from(!a));
+            assert_eq!(v(wa.clone() << 3), BigUint::from(a << 3));
+            assert_eq!(v(wa.clone() >> 3), BigUint::from(a >> 3));
+            assert_eq!(v(wa.clone() << 8), BigUint::zero());
+            assert_eq!(wa.to_bigint_signed(), BigInt::from(a as i8));
+            assert_eq!(WrappingBigUint::from_bigint(&BigInt::from(a as i8), 8), wa);
+        }
+    }
+}
+
+#[test]
+fn test_widths() {
+    for &bits in &WIDTHS {
+        let modulus = BigUint::one() << bits;
+        for x in values(bits) {
+            let wx = WrappingBigUint::new(x.clone(), bits);
+            assert_eq!(wx.bits(), bits);
+            assert_eq!(*wx.value(), &x % &modulus);
+            assert_eq!((&wx + &(-&wx)).into_value(), BigUint::zero());
+            assert_eq!((&wx ^ &!&wx).into_value(), &modulus - 1u32);
+            for y in values(bits) {
+                let wy = wx.with_value(y.clone());
+                assert_eq!(*(&wx + &wy).value(), (&x + &y) % &modulus);
+                assert_eq!(*(&wx * &wy).value(), (&x * &y) % &modulus);
+                assert_eq!(&wx - &wy + &wy, wx);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "different widths")]
+fn test_mismatched_widths() {
+    let a = WrappingBigUint::new(BigUint::one(), 8);
+    let b = WrappingBigUint::new(BigUint::one(), 16);
+    let _ = a + b;
+}